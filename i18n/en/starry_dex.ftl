@@ -1,16 +1,58 @@
 app-title = StarryDex
 about = About
 settings = Settings
+report-problem = Report a Problem
 view = View
 back = Back
 generic-error = Oops, something has gone wrong...
 loading = Loading...
+loading-downloading-sprites = Downloading sprites, { $downloaded } of { $total }...
+loading-fetching-pokemon = Downloading Pokémon data, { $fetched } of { $total }...
+loading-saving-cache = Saving the Pokédex...
+loading-failed = The Pokédex could not be loaded
+retry = Retry
+delete-cache-and-retry = Delete Cache and Retry
 repository = Repository
 support = Support
+missing-translation = StarryDex isn't translated to { $language } yet, it's shown in English instead
+translation-failed = The translations couldn't be loaded, StarryDex is shown in English instead
 
 <#-- Appearance -->
 appearance = Appearance
 theme = Theme
+sprite-set = Sprites
+sprite-set-description = Pokémon missing from a set keep their default sprite
+sprite-set-default = Default
+sprite-set-emerald = Emerald (Generation III)
+sprite-set-home = Pokémon HOME
+sprite-set-official-artwork = Official Artwork
+sprite-overrides = Custom Sprites
+sprite-overrides-description = Sprites named like pikachu_front.png in this folder replace the others ({ $count } in use)
+sprite-overrides-reload = Reload
+sprite-overrides-open = Open Folder
+type-accents = Type Colors
+type-accents-description = Tint the details page with the color of the Pokémon's primary type
+reduce-motion = Reduce Motion
+reduce-motion-description = Keep the placeholders shown while loading still instead of shimmering
+generation-headers = Generation Headers
+generation-headers-description = Show a header for each generation when the Pokémon are sorted by number
+card-size = Card size
+card-size-small = Small
+card-size-medium = Medium
+card-size-large = Large
+text-scale = Text Size
+text-scale-description = { $percent }% of the size of the grid, the details page and the type chart
+color-palette = Colors
+color-palette-description = Colors of the raised and lowered stats, the type chart and the types
+color-palette-default = Default
+color-palette-color-blind = Color-Blind Safe
+color-palette-high-contrast = High Contrast
+measurement-units = Units
+measurement-units-metric = Metric
+measurement-units-imperial = Imperial
+measurement-units-both = Metric and Imperial
+infinite-scroll = Infinite Scroll
+infinite-scroll-description = Load the grid while scrolling instead of building the whole list at once
 pokemon-per-row = Pokémon per row
 match-desktop = Match desktop
 dark = Dark
@@ -31,22 +73,261 @@ other = Other
 type-filter-mode = Type filtering mode
 inclusive = Inclusive
 exclusive = Exclusive
-renew-cache = Renew Cache
+keyboard-scheme = Keyboard scheme
+keyboard-scheme-description = Vim: h/l and j/k move the selection, / searches, gg/G jump to the first or last Pokémon
+keyboard-scheme-default = Default
+keyboard-scheme-vim = Vim
+repair-sprites = Repair Sprites
+repair-sprites-button = Repair
+repair-sprites-checking = Checking { $checked } of { $total } sprites...
+repair-sprites-finished = { $repaired } sprites repaired, { $failed } failed
+refresh-data = Check for Data Updates
+refresh-data-description = Downloads the Pokémon added or renamed since the data was downloaded, while you keep using the app. Renew the cache to also get the changes to the others
+refresh-data-button = Check
+refresh-data-fetching = Downloaded { $fetched } of { $total } new Pokémon...
+refresh-data-finished = Data updated, { $new } new Pokémon and { $renamed } renamed
+refresh-data-failed = The data could not be updated: { $error }
+fetch-missing-sprites = Download Missing Sprites
+fetch-missing-sprites-description = Sprites missing from your computer are downloaded from PokéApi when found
+sprite-cache = Sprite Memory
+sprite-cache-description = Decoded sprites are kept in memory up to { $megabytes } MB
 renew-cache-button = Renew
+search-aliases = Search Aliases
+alias = Alias
+alias-pokemon = Pokémon
+remove-alias = Remove Alias
+add = Add
 
 <#-- Landing (Main) Page -->
 landing-page-title = All Pokémon
 search = Search
 filter = Filter
 clear-filters = Clear
+empty-state-search = No Pokémon match “{ $query }”
+empty-state-filters = No Pokémon match the filters
+did-you-mean = Did you mean
+clear-search-and-filters = Clear Search and Filters
+restore-session-prompt = StarryDex didn't close properly last time.
+restore-session = Restore Session
+dismiss = Dismiss
+crash-report-prompt = StarryDex crashed last time. Reporting it helps getting it fixed.
+crash-report-send = Report on GitHub
+sort-number = Number
+sort-generation = Generation
+sort-type = Type
+view-grid = Grid
+view-table = Table
+pokemon-accessible-name = Number { $number }, { $name }, { $types }
+sorted-ascending = { $column }, sorted ascending
+sorted-descending = { $column }, sorted descending
+jump-to-generation = Jump to generation
+generation = Generation { $generation }
+unknown = Unknown
+
+<#-- Search Help -->
+search-help = Search Operators
+example = Example:
+search-op-exact = Pokémon with exactly this name
+search-op-type = Pokémon of the given type
+search-op-ability = Pokémon with an ability containing this text
+search-op-weak-to = Pokémon weak to the given type
+search-op-game = Pokémon found in the wild in the given game
+search-op-generation = Pokémon introduced in the given generation, as a number or in roman numerals
+search-op-stat = Pokémon with a base stat (hp, atk, def, spa, spd, spe or bst for the total) above, below or equal to a value
+search-op-id = Pokémon with this Pokédex number
+search-op-id-range = Pokémon with a Pokédex number between both numbers
+search-op-name = Pokémon whose name contains this text
 
 <#-- Pokemon Details Page -->
 pokemon-page = Pokémon
 height = HEIGHT
 weight = WEIGHT
-show-encounter-details = Show Encounter Details
+show-encounter-details = Show Encounter Details as Text
 no-encounter-info = No encounter info...
 link-more-info = More Info
+ev-yield = EV Yield
+base-experience = Base Experience
+capture-rate = Capture Rate
+base-happiness = Base Happiness
+growth-rate = Growth Rate
+gender-ratio = Gender Ratio
+held-items = Wild Held Items
+genderless = Genderless
+hatch-counter = Hatch Time
+egg-cycles = { $cycles } egg cycles (~{ $steps } steps)
+export-details = Export Printable Page
+copy-showdown = Copy for Showdown
+share-card = Save Share Card
+file = File
+export-list-csv = Export List as CSV
+export-list-json = Export List as JSON
+game-availability = Found in the Wild
+watch = Watch
+unwatch = Unwatch
+types = Types
+abilities = Abilities
+stats = Stats
+encounters = Encounters
+
+<#-- Moves Dex -->
+pokemon-dex = Pokémon
+moves-dex = Moves
+move-page = Move
+all = All
+name = Name
+type = Type
+damage-class = Category
+power = Power
+accuracy = Accuracy
+pp = PP
+learned-by = Learned by
+
+<#-- Abilities Dex -->
+abilities-dex = Abilities
+effect = Effect
+pokemon-count = Pokémon
+
+<#-- Locations Dex -->
+locations-dex = Locations
+
+<#-- Berries -->
+reference = Reference
+berries = Berries
+firmness = Firmness
+growth-time = Growth time
+growth-hours = { $hours }h per stage
+max-harvest = Max harvest
+natural-gift = Natural Gift
+flavors = Flavors
+
+<#-- Watchlist -->
+watchlist-changes = Watchlist Changes
+watchlist-no-changes = Nothing changed on your watched Pokémon.
+watch-stat-changed = { $stat }: { $old } → { $new }
+watch-types-changed = Types changed to { $types }
+watch-abilities-changed = Abilities changed to { $abilities }
+watch-new-encounters = { $count } new encounter locations
+watch-new-form = New form: { $form }
+
+<#-- Natures -->
+natures = Natures
+nature = Nature
+raises = Raises (+10%)
+lowers = Lowers (-10%)
+natures-help = Pick a nature to use it when calculating stats.
+
+<#-- Type Chart -->
+type-chart = Type Chart
+attacking-defending = Atk \ Def
+
+<#-- Evolution -->
+evolution = Evolution
+evolves-from = Evolves from { $pokemon }
+evolves-from-with = Evolves from { $pokemon } ({ $requirement })
+evolution-or = or
+evolution-or-requirement = or { $requirement }
+evolution-level = Level { $level }
+evolution-level-up = Level up
+evolution-trigger = { $trigger ->
+    [trade] Trade
+    [shed] Empty spot in the party
+    [spin] Spin around
+    [three-critical-hits] Three critical hits in a battle
+    [take-damage] Take damage
+    *[other] { $name }
+}
+evolution-use-item = Use { $item }
+evolution-hold-item = Hold { $item }
+evolution-know-move = Know { $move_name }
+evolution-know-move-type = Know a { $type_name } move
+evolution-friendship = High friendship
+evolution-affection = High affection
+evolution-beauty = High beauty
+evolution-location = At { $location }
+evolution-time-of-day = { $time ->
+    [day] During the day
+    [night] During the night
+    [dusk] At dusk
+    *[other] During the { $name }
+}
+evolution-female = Female
+evolution-male = Male
+evolution-party-species = With { $pokemon } in the party
+evolution-party-type = With a { $type_name } type in the party
+evolution-trade-for = For { $pokemon }
+evolution-attack-higher = Attack higher than Defense
+evolution-attack-equals-defense = Attack equal to Defense
+evolution-defense-higher = Defense higher than Attack
+evolution-rain = While raining
+evolution-upside-down = Holding the console upside down
+viewed-evolution = ▸ { $pokemon }
+
+<#-- Stat Calculator -->
+show-stat-calculator = Show Stat Calculator
+level = Level { $level }
+iv = IV { $iv }
+ev = EV { $ev }
+
+<#-- Rankings -->
+rankings = Rankings
+total = Total
+rank = Rank
+ranked-by = Ranked by
+stat-percentile = top { $percentile }%
+
+<#-- Weekly Challenge -->
+weekly-challenge = Weekly Challenge
+challenge-week = Week { $week }, { $year }
+challenge-catch-type = Catch { $count } { $type_name } type Pokémon
+challenge-catch-generation = Catch { $count } Pokémon from { $generation }
+challenge-heavier-than = Find a Pokémon heavier than { $weight }
+challenge-taller-than = Find a Pokémon taller than { $height }
+challenge-stat-at-least = Find a Pokémon with { $value } { $stat } or more
+challenge-progress = { $caught } of { $goal } caught
+challenge-completed = Challenge completed!
+challenge-streak = Streak: { $weeks } weeks
+challenge-help = Mark the Pokémon you catch, a new challenge starts every Monday.
+
+<#-- Stat Trivia -->
+stat-trivia = Stat Trivia
+trivia-question = Which one has the higher { $stat }?
+trivia-correct = Correct!
+trivia-wrong = Wrong!
+trivia-next = Next
+trivia-streak = Streak: { $streak } · Best: { $best }
+
+<#-- Command Palette -->
+command-palette = Command Palette
+command-palette-placeholder = Search Pokémon or commands...
+go-to-number = Go to Number
+go-to-number-placeholder = National Pokédex number
+go-to-number-invalid = No Pokémon has this number
+go = Go
+cancel = Cancel
+close = Close
+sprite-viewer-hint = Scroll to zoom, drag to move the sprite, Escape to close
+view-sprite = View the sprite of { $name }
+focus-search = Focus Search
+
+<#-- Keyboard Shortcuts -->
+keyboard-shortcuts = Keyboard Shortcuts
+change-shortcut = Change
+reset-shortcut = Reset Shortcut
+press-shortcut = Press the new shortcut, Escape to cancel
+no-shortcut = No shortcut
+shortcut-conflict = That shortcut is already used by {$action}
+
+<#-- Egg Groups -->
+egg-groups = Egg Groups
+no-egg-groups = No egg group data, renew the cache from Settings to get it.
+
+<#-- Items Dex -->
+items-dex = Items
+item-page = Item
+category = Category
+cost = Cost
+evolves-with-item = Evolves With This Item
+no-item-evolutions = No Pokémon evolves with this item.
 
 <#-- Filters Page -->
 filters-page = Filters
@@ -81,3 +362,40 @@ defense = Defense
 sp-a = Special Attack
 sp-d = Special Defense
 spd = Speed
+
+<#-- Storage -->
+storage = Storage
+storage-pokemon-data = Pokémon Data
+storage-extra-data = Moves, Items, Abilities and Berries
+storage-sprites = Pokémon Sprites
+storage-sprite-sets = Sprite Sets
+storage-clear = Clear
+old-caches-removed = The caches of older versions were removed, freeing { $size }
+data-folder = Data Folder
+data-folder-default = Default
+data-folder-placeholder = New folder, it has to be empty
+data-folder-move = Move
+data-folder-moving = Moving the data…
+data-folder-move-failed = The data couldn't be moved: { $error }
+data-folder-portable = Next to the executable (portable mode)
+data-folder-command-line = Chosen with --data-dir
+
+<#-- Backup -->
+backup = Backup
+backup-export = Export Backup
+backup-export-button = Export
+backup-description = The settings, aliases, shortcuts, watchlist and caught Pokémon, to import them on another system
+backup-exported = Backup saved to { $path }
+backup-export-failed = The backup couldn't be exported: { $error }
+backup-import-placeholder = Backup file to import
+backup-import = Import
+backup-imported = Backup imported
+backup-import-failed = The backup couldn't be imported: { $error }
+
+<#-- Data Packs -->
+data-packs = Data Packs
+data-packs-folder = Data Packs Folder
+data-packs-description = JSON files with fan-made Pokémon, in the format of the Pokémon cache
+data-packs-reload = Reload
+data-packs-open = Open Folder
+data-pack-pokemon = { $count } Pokémon
@@ -29,7 +29,6 @@ other = Annat
 type-filter-mode = Skriv filtreringsläge
 inclusive = Inklusive
 exclusive = Exklusiv
-renew-cache = Förnya Cache
 renew-cache-button = Förnya
 
 <#-- Landningssida (huvudsida). -->
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::collections::BTreeMap;
+
+/// Common nicknames, Japanese romaji names and form shorthands, mapped to the PokéApi name of the Pokémon
+const BUNDLED_ALIASES: &[(&str, &str)] = &[
+    // Nicknames
+    ("zard", "charizard"),
+    ("tar", "tyranitar"),
+    ("ttar", "tyranitar"),
+    ("chomp", "garchomp"),
+    ("gyara", "gyarados"),
+    ("dnite", "dragonite"),
+    ("pika", "pikachu"),
+    ("lando", "landorus-incarnate"),
+    ("lando-t", "landorus-therian"),
+    ("toad", "politoed"),
+    ("ferro", "ferrothorn"),
+    ("rotom-w", "rotom-wash"),
+    ("rotom-h", "rotom-heat"),
+    ("zapdos-g", "zapdos-galar"),
+    // Japanese romaji names
+    ("fushigidane", "bulbasaur"),
+    ("hitokage", "charmander"),
+    ("zenigame", "squirtle"),
+    ("rizaadon", "charizard"),
+    ("kamekkusu", "blastoise"),
+    ("fushigibana", "venusaur"),
+    ("purin", "jigglypuff"),
+    ("nyaasu", "meowth"),
+    ("kodakku", "psyduck"),
+    ("gangaa", "gengar"),
+    ("iwaaku", "onix"),
+    ("koiking", "magikarp"),
+    ("kabigon", "snorlax"),
+    ("myuutsuu", "mewtwo"),
+    ("myuu", "mew"),
+    ("chikoriita", "chikorita"),
+    ("hinoarashi", "cyndaquil"),
+    ("waninoko", "totodile"),
+    ("bangirasu", "tyranitar"),
+    ("rukario", "lucario"),
+    ("gaburiasu", "garchomp"),
+    // Form shorthands
+    ("a-ninetales", "ninetales-alola"),
+    ("a-vulpix", "vulpix-alola"),
+    ("a-raichu", "raichu-alola"),
+    ("a-marowak", "marowak-alola"),
+    ("a-muk", "muk-alola"),
+    ("a-sandslash", "sandslash-alola"),
+    ("g-weezing", "weezing-galar"),
+    ("g-moltres", "moltres-galar"),
+    ("g-articuno", "articuno-galar"),
+    ("g-zapdos", "zapdos-galar"),
+    ("g-slowbro", "slowbro-galar"),
+    ("g-slowking", "slowking-galar"),
+    ("h-typhlosion", "typhlosion-hisui"),
+    ("h-samurott", "samurott-hisui"),
+    ("h-decidueye", "decidueye-hisui"),
+    ("h-zoroark", "zoroark-hisui"),
+    ("h-arcanine", "arcanine-hisui"),
+    ("p-tauros", "tauros-paldea-combat-breed"),
+];
+
+/// Returns the PokéApi name the given (normalized) alias stands for, user aliases take precedence over the bundled ones
+pub fn resolve(alias: &str, custom_aliases: &BTreeMap<String, String>) -> Option<String> {
+    custom_aliases.get(alias).cloned().or_else(|| {
+        BUNDLED_ALIASES
+            .iter()
+            .find(|(bundled_alias, _)| *bundled_alias == alias)
+            .map(|(_, name)| name.to_string())
+    })
+}
@@ -4,17 +4,184 @@ use futures::StreamExt;
 use rustemon::client::{
     CacheMode, CacheOptions, MokaManager, RustemonClient, RustemonClientBuilder,
 };
-use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, sync::Arc, time::Duration};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::sync::{RwLock, Semaphore};
 use tokio::time::timeout;
 
 use crate::{
-    app::{StarryPokemon, StarryPokemonData, StarryPokemonEncounterInfo},
-    utils::{capitalize_string, download_image, parse_pokemon_stats},
+    app::{
+        StarryAbility, StarryBerry, StarryItem, StarryLocationEncounter, StarryMove, StarryPokemon,
+        StarryPokemonData, StarryPokemonDetails, StarryPokemonEncounterInfo, StarryPokemonHeldItem,
+        StarryPokemonHeldItemVersion, StarryPokemonSpecie,
+    },
+    data_dir::app_data_dir,
+    search::SearchIndex,
+    storage::StorageComponent,
+    utils::{
+        capitalize_string, disk_usage, download_image, download_image_to, parse_evolution_chain,
+        parse_pokemon_ev_yield, parse_pokemon_stats, remove_partial_downloads, remove_path,
+        resource_id,
+    },
 };
 
 const APP_ID: &str = "dev.mariinkys.StarryDex";
+/// Languages (as PokéApi names them) for which the Pokédex entries and genera are bundled,
+/// the one matching the language in use is shown, falling back to English
+const BUNDLED_LANGUAGES: &[&str] = &[
+    "en", "es", "fr", "de", "it", "ja", "ko", "zh-Hans", "zh-Hant",
+];
+/// Version of the cache format, the cache is stored in a directory per version so a new
+/// format never tries to read an old one
+pub const CACHE_VERSION: u32 = 3;
+/// File that exists while the cache is being constructed, if it's found on startup the
+/// previous construction was interrupted and has to be resumed
+const CACHE_BUILD_MARKER: &str = "cache_build_in_progress";
+/// Directory of the Pokémon a cache construction in progress fetched already, with their details
+/// saved, so an interrupted construction resumes from them instead of fetching them again
+const FETCHED_POKEMON_DIR: &str = "fetched";
+/// Files the cache was stored as in the app data directory, before it had a directory per version
+const LEGACY_CACHE_FILES: [&str; 2] = ["pokemon_cache.json", CACHE_BUILD_MARKER];
+/// File of the search index, next to the Pokémon cache
+const SEARCH_INDEX_FILE: &str = "search_index.json";
+/// Level of the zstd compression of the cache files, a fast one since most of them are written
+/// while the cache is built
+const CACHE_COMPRESSION_LEVEL: i32 = 3;
+
+/// Progress of a sprite repair
+#[derive(Debug, Clone, Copy)]
+pub enum SpriteRepairProgress {
+    Checking { checked: usize, total: usize },
+    Finished { repaired: usize, failed: usize },
+}
+
+/// Progress of the loading of the Pokémon Data, building the Cache if it doesn't exist
+#[derive(Debug, Clone)]
+pub enum CacheBuildProgress {
+    /// Reading an existing Cache
+    Loading,
+    DownloadingSprites {
+        downloaded: usize,
+        total: usize,
+    },
+    FetchingPokemon {
+        fetched: usize,
+        total: usize,
+    },
+    SavingCache,
+    /// The Pokémon list, or why it couldn't be loaded
+    Finished(Result<BTreeMap<i64, StarryPokemon>, String>),
+}
+
+/// Progress of a refresh of the Pokémon Data
+#[derive(Debug, Clone)]
+pub enum DataRefreshProgress {
+    /// The Pokémon missing from the Cache fetched so far, out of all of them
+    Fetching { fetched: usize, total: usize },
+    /// The refreshed Pokémon list and how many Pokémon were renamed, or why the refresh failed
+    Finished(Result<(BTreeMap<i64, StarryPokemon>, usize), String>),
+}
+
+/// Where a cache file is stored compressed, with `.zst` appended to its name
+fn compressed_path(path: &Path) -> PathBuf {
+    let mut compressed_path = path.as_os_str().to_owned();
+    compressed_path.push(".zst");
+    PathBuf::from(compressed_path)
+}
+
+/// Reads a JSON cache file compressed with zstd, decompressing it as it's parsed. Files saved
+/// before the cache was compressed are read too, and replaced with their compressed version.
+async fn read_cache_file<T: DeserializeOwned + Send + 'static>(
+    path: &Path,
+) -> Result<Option<T>, Box<dyn std::error::Error + Send + Sync>> {
+    let compressed_file = compressed_path(path);
+    if compressed_file.exists() {
+        let value = tokio::task::spawn_blocking(
+            move || -> Result<T, Box<dyn std::error::Error + Send + Sync>> {
+                let decoder =
+                    zstd::stream::read::Decoder::new(std::fs::File::open(&compressed_file)?)?;
+                Ok(serde_json::from_reader(decoder)?)
+            },
+        )
+        .await??;
+        return Ok(Some(value));
+    }
+
+    let Ok(data) = tokio::fs::read(path).await else {
+        return Ok(None);
+    };
+    let value = serde_json::from_slice(&data)?;
+    write_cache_file(path, data).await?;
+    tokio::fs::remove_file(path).await?;
+    Ok(Some(value))
+}
+
+/// Writes a JSON cache file compressed with zstd, through a temporary file so an interrupted
+/// write never leaves a broken file
+async fn write_cache_file(
+    path: &Path,
+    json: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let compressed_data = tokio::task::spawn_blocking(move || {
+        zstd::stream::encode_all(json.as_slice(), CACHE_COMPRESSION_LEVEL)
+    })
+    .await??;
+
+    let compressed_file = compressed_path(path);
+    let tmp_file = compressed_file.with_extension("zst.part");
+    tokio::fs::write(&tmp_file, compressed_data).await?;
+    tokio::fs::rename(&tmp_file, &compressed_file).await?;
+    Ok(())
+}
+
+/// Sprite path as it's saved in the cache, relative to the resources directory so the cache keeps
+/// working if the data directory moves. Paths outside of it are kept as they are.
+fn relative_sprite_path(path: &str, resources_dir: &Path) -> String {
+    Path::new(path)
+        .strip_prefix(resources_dir)
+        .map(|relative_path| relative_path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+/// Sprite path of the cache resolved against the current resources directory. Older caches saved
+/// absolute paths, maybe of another data directory (like the one of the Flatpak), those are
+/// resolved from their resources directory on.
+fn resolve_sprite_path(path: &str, resources_dir: &Path) -> String {
+    let path = Path::new(path);
+    if path.is_relative() {
+        return resources_dir.join(path).to_string_lossy().into_owned();
+    }
+
+    let components: Vec<&std::ffi::OsStr> = path.iter().collect();
+    match components
+        .windows(2)
+        .rposition(|pair| pair[0] == APP_ID && pair[1] == "resources")
+    {
+        Some(index) => resources_dir
+            .join(components[index + 2..].iter().collect::<PathBuf>())
+            .to_string_lossy()
+            .into_owned(),
+        None => path.to_string_lossy().into_owned(),
+    }
+}
+
+/// Checks that the file exists and starts like a PNG image does
+async fn is_valid_png(path: &str) -> bool {
+    const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    match tokio::fs::read(path).await {
+        Ok(data) => data.starts_with(&PNG_SIGNATURE),
+        Err(_) => false,
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct PokemonCache {
@@ -23,23 +190,29 @@ struct PokemonCache {
 
 #[derive(Debug)]
 pub struct Api {
-    app_id: String,
     client: Arc<RustemonClient>,
     cache: Arc<RwLock<Option<PokemonCache>>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Default for Api {
+    fn default() -> Self {
+        Api::new()
+    }
 }
 
 impl Clone for Api {
     fn clone(&self) -> Self {
         Api {
-            app_id: self.app_id.clone(),
             client: Arc::clone(&self.client),
             cache: Arc::clone(&self.cache),
+            cancelled: Arc::clone(&self.cancelled),
         }
     }
 }
 
 impl Api {
-    pub fn new(app_id: &str) -> Api {
+    pub fn new() -> Api {
         Api {
             client: Arc::new(
                 RustemonClientBuilder::default()
@@ -55,22 +228,267 @@ impl Api {
                     .unwrap(),
             ),
             cache: Arc::new(RwLock::new(None)),
-            app_id: app_id.to_string(),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Stops any running cache construction, the work done so far is kept so it can be resumed
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Clears a previous cancellation, called as a construction or refresh starts
+    fn reset_cancel(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn app_data_dir(&self) -> PathBuf {
+        app_data_dir()
+    }
+
+    /// Directory of the downloaded sprites, the sprite paths of the cache are relative to it
+    fn resources_dir(&self) -> PathBuf {
+        self.app_data_dir().join("resources")
+    }
+
+    fn cache_dir(&self) -> PathBuf {
+        self.app_data_dir()
+            .join("cache")
+            .join(format!("v{}", CACHE_VERSION))
+    }
+
+    fn pokemon_details_file(&self, id: i64) -> PathBuf {
+        self.cache_dir()
+            .join("details")
+            .join(format!("{}.json", id))
+    }
+
+    fn fetched_pokemon_file(&self, id: i64) -> PathBuf {
+        self.cache_dir()
+            .join(FETCHED_POKEMON_DIR)
+            .join(format!("{}.json", id))
+    }
+
+    /// Files of the cache and whether they exist, shown on bug reports
+    pub fn cache_files(&self) -> Vec<(String, bool)> {
+        let cache_dir = self.cache_dir();
+
+        [
+            "pokemon_cache.json",
+            "details",
+            "moves_cache.json",
+            "items_cache.json",
+            "abilities_cache.json",
+            "berries_cache.json",
+            SEARCH_INDEX_FILE,
+            CACHE_BUILD_MARKER,
+        ]
+        .into_iter()
+        .map(|file| {
+            // The JSON files are stored compressed
+            let file = if file.ends_with(".json") {
+                format!("{}.zst", file)
+            } else {
+                file.to_string()
+            };
+            (
+                format!("v{}/{}", CACHE_VERSION, file),
+                cache_dir.join(&file).exists(),
+            )
+        })
+        .collect()
+    }
+
+    /// Removes everything downloaded: the caches of every version and the resources (sprites and
+    /// sprite sets). What the user made (sprite overrides, data packs, the challenge history, the
+    /// session and crash reports) is kept.
+    pub async fn remove_downloaded_data(&self) -> Result<(), String> {
+        let app_data_dir = self.app_data_dir();
+        let paths: Vec<PathBuf> = LEGACY_CACHE_FILES
+            .iter()
+            .map(|file| app_data_dir.join(file))
+            .chain([app_data_dir.join("cache"), self.resources_dir()])
+            .collect();
+
+        tokio::task::spawn_blocking(move || {
+            paths
+                .iter()
+                .filter(|path| path.exists())
+                .try_for_each(remove_path)
+                .map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    /// Caches of older versions that the current one doesn't read: the `cache/vN` directories of
+    /// other versions and the files of the cache from before it had a directory per version
+    fn superseded_caches(&self) -> Vec<PathBuf> {
+        let app_data_dir = self.app_data_dir();
+        let current_version = format!("v{}", CACHE_VERSION);
+
+        let old_versions = std::fs::read_dir(app_data_dir.join("cache"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_dir()
+                    && path
+                        .file_name()
+                        .is_some_and(|name| name != current_version.as_str())
+            });
+
+        LEGACY_CACHE_FILES
+            .iter()
+            .map(|file| app_data_dir.join(file))
+            .filter(|path| path.exists())
+            .chain(old_versions)
+            .collect()
+    }
+
+    /// Removes the caches of older versions, returns how many bytes were freed
+    pub async fn remove_superseded_caches(&self) -> u64 {
+        let superseded = self.superseded_caches();
+
+        tokio::task::spawn_blocking(move || {
+            superseded
+                .into_iter()
+                .map(|path| {
+                    let size = disk_usage(&path);
+                    match remove_path(&path) {
+                        Ok(()) => size,
+                        Err(e) => {
+                            eprintln!("Failed to remove the old cache {}: {}", path.display(), e);
+                            0
+                        }
+                    }
+                })
+                .sum()
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Files and directories where a storage component is stored
+    fn storage_paths(&self, component: StorageComponent) -> Vec<PathBuf> {
+        let cache_dir = self.cache_dir();
+        let resources_dir = self.app_data_dir().join("resources");
+
+        match component {
+            // The JSON files may still be uncompressed if they are from before the compression
+            StorageComponent::PokemonData => ["pokemon_cache.json", SEARCH_INDEX_FILE]
+                .into_iter()
+                .map(|file| cache_dir.join(file))
+                .flat_map(|path| [compressed_path(&path), path])
+                .chain([
+                    cache_dir.join("details"),
+                    cache_dir.join(FETCHED_POKEMON_DIR),
+                    cache_dir.join(CACHE_BUILD_MARKER),
+                ])
+                .collect(),
+            StorageComponent::ExtraData => [
+                "moves_cache.json",
+                "items_cache.json",
+                "abilities_cache.json",
+                "berries_cache.json",
+            ]
+            .into_iter()
+            .map(|file| cache_dir.join(file))
+            .flat_map(|path| [compressed_path(&path), path])
+            .chain(std::iter::once(resources_dir.join("sprites").join("items")))
+            .collect(),
+            // Every folder of the sprites directory is a Pokémon, but the one of the items
+            StorageComponent::Sprites => std::fs::read_dir(resources_dir.join("sprites"))
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| !path.ends_with("items"))
+                .collect(),
+            StorageComponent::SpriteSets => vec![resources_dir.join("sprite-sets")],
+        }
+    }
+
+    /// Disk space used by each storage component, in bytes
+    pub async fn storage_usage(&self) -> Vec<(StorageComponent, u64)> {
+        let components: Vec<(StorageComponent, Vec<PathBuf>)> = StorageComponent::ALL
+            .into_iter()
+            .map(|component| (component, self.storage_paths(component)))
+            .collect();
+
+        tokio::task::spawn_blocking(move || {
+            components
+                .into_iter()
+                .map(|(component, paths)| (component, paths.iter().map(disk_usage).sum()))
+                .collect()
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Removes the files of a storage component, the data already loaded is kept until the app
+    /// is restarted
+    pub async fn clear_storage(&self, component: StorageComponent) -> Result<(), String> {
+        let paths = self.storage_paths(component);
+
+        tokio::task::spawn_blocking(move || {
+            paths
+                .iter()
+                .filter(|path| path.exists())
+                .try_for_each(remove_path)
+                .map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    /// Removes what an interrupted cache construction may have left half written
+    async fn clean_interrupted_build(&self) {
+        let cache_dir = self.cache_dir();
+        if !cache_dir.join(CACHE_BUILD_MARKER).exists() {
+            return;
+        }
+
+        println!("Previous cache construction was interrupted, resuming");
+        let cache_file = cache_dir.join("pokemon_cache.json");
+        let _ = tokio::fs::remove_file(compressed_path(&cache_file)).await;
+        let _ = tokio::fs::remove_file(cache_file).await;
+        if let Err(e) =
+            remove_partial_downloads(self.app_data_dir().join("resources").join("sprites"))
+        {
+            eprintln!("Failed to remove partial downloads: {}", e);
         }
     }
 
     /// Attempts to load the data from the cache
-    async fn load_cache(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let cache_file = dirs::data_dir()
-            .unwrap()
-            .join(&self.app_id)
-            .join("pokemon_cache.json");
-
-        if cache_file.exists() {
-            let cache_data = tokio::fs::read_to_string(cache_file).await?;
-            let cache: PokemonCache = serde_json::from_str(&cache_data)?;
+    async fn load_cache(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let cache_file = self.cache_dir().join("pokemon_cache.json");
+
+        if let Some(mut cache) = read_cache_file::<PokemonCache>(&cache_file).await? {
+            let resources_dir = self.resources_dir();
+            let mut absolute_paths = false;
+            for sprite_path in cache
+                .pokemon
+                .values_mut()
+                .filter_map(|p| p.sprite_path.as_mut())
+            {
+                absolute_paths |= Path::new(sprite_path.as_str()).is_absolute();
+                *sprite_path = resolve_sprite_path(sprite_path, &resources_dir);
+            }
+
             let mut write_guard = self.cache.write().await;
             *write_guard = Some(cache);
+            drop(write_guard); // Release the write lock
+
+            // Caches of older versions saved absolute paths, they are saved again relative
+            if absolute_paths {
+                self.save_cache().await?;
+            }
         }
 
         Ok(())
@@ -78,26 +496,58 @@ impl Api {
 
     /// Attempts to save the data to the cache
     async fn save_cache(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let cache_file = dirs::data_dir()
-            .unwrap()
-            .join(&self.app_id)
-            .join("pokemon_cache.json");
+        let cache_file = self.cache_dir().join("pokemon_cache.json");
 
         println!("Attempting to save cache to: {:?}", cache_file);
 
         // Retry logic for acquiring the lock
-        let cache_data = self.get_cache_data().await?;
+        let mut cache_data = self.get_cache_data().await?;
+
+        let resources_dir = self.resources_dir();
+        for sprite_path in cache_data
+            .pokemon
+            .values_mut()
+            .filter_map(|p| p.sprite_path.as_mut())
+        {
+            *sprite_path = relative_sprite_path(sprite_path, &resources_dir);
+        }
 
         // Perform serialization outside the lock
         let serialized_data =
-            tokio::task::spawn_blocking(move || serde_json::to_string(&cache_data)).await??;
+            tokio::task::spawn_blocking(move || serde_json::to_vec(&cache_data)).await??;
 
-        tokio::fs::write(&cache_file, serialized_data).await?;
+        write_cache_file(&cache_file, serialized_data).await?;
 
         println!("Cache successfully saved to: {:?}", cache_file);
         Ok(())
     }
 
+    /// Attempts to load a secondary dataset (moves, items...) from its cache file
+    async fn load_dataset<T: DeserializeOwned + Send + 'static>(
+        &self,
+        file_name: &str,
+    ) -> Option<T> {
+        let dataset_file = self.cache_dir().join(file_name);
+
+        read_cache_file(&dataset_file)
+            .await
+            .map_err(|e| eprintln!("Failed to load {:?}: {}", dataset_file, e))
+            .ok()
+            .flatten()
+    }
+
+    /// Attempts to save a secondary dataset (moves, items...) to its cache file
+    async fn save_dataset<T: Serialize>(
+        &self,
+        file_name: &str,
+        dataset: &T,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let dataset_file = self.cache_dir().join(file_name);
+        tokio::fs::create_dir_all(self.cache_dir()).await?;
+
+        write_cache_file(&dataset_file, serde_json::to_vec(dataset)?).await
+    }
+
     /// Attempts to get the data from the cache
     async fn get_cache_data(
         &self,
@@ -132,8 +582,31 @@ impl Api {
     // API
     //
 
-    /// Retrieve all Pokémon Data from Cache, if the cache does not exist, create the cache
-    pub async fn load_all_pokemon(&self) -> BTreeMap<i64, StarryPokemon> {
+    /// Retrieve all Pokémon Data from Cache, if the cache does not exist, create the cache. The
+    /// progress of each step is sent through the returned stream, the last message has the list.
+    /// A construction that fails or is cancelled is resumed by the next call.
+    pub fn load_all_pokemon(
+        &self,
+    ) -> futures::channel::mpsc::UnboundedReceiver<CacheBuildProgress> {
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let api = self.clone();
+        api.reset_cancel();
+
+        tokio::spawn(async move {
+            let pokemon = api.load_or_build_cache(&sender).await;
+            let _ = sender.unbounded_send(CacheBuildProgress::Finished(pokemon));
+        });
+
+        receiver
+    }
+
+    async fn load_or_build_cache(
+        &self,
+        sender: &futures::channel::mpsc::UnboundedSender<CacheBuildProgress>,
+    ) -> Result<BTreeMap<i64, StarryPokemon>, String> {
+        let _ = sender.unbounded_send(CacheBuildProgress::Loading);
+        self.clean_interrupted_build().await;
+
         println!("Loading Cache");
         self.load_cache()
             .await
@@ -145,17 +618,46 @@ impl Api {
         println!("Getting Cache");
         if let Some(cache_data) = &*read_guard {
             println!("Cache Found, returning list");
-            return cache_data.pokemon.clone();
+            return Ok(cache_data.pokemon.clone());
         }
         drop(read_guard); // Release the read lock
 
+        let build_marker = self.cache_dir().join(CACHE_BUILD_MARKER);
+        for dir in ["details", FETCHED_POKEMON_DIR] {
+            if let Err(e) = tokio::fs::create_dir_all(self.cache_dir().join(dir)).await {
+                eprintln!("Failed to create the cache directory: {}", e);
+            }
+        }
+        if let Err(e) = tokio::fs::write(&build_marker, b"").await {
+            eprintln!("Failed to create the cache build marker: {}", e);
+        }
+
         println!("No Cache, Downloading Sprites");
-        if let Err(e) = self.download_all_pokemon_sprites().await {
+        let sprites_downloaded = self
+            .download_all_pokemon_sprites(|downloaded, total| {
+                let _ = sender
+                    .unbounded_send(CacheBuildProgress::DownloadingSprites { downloaded, total });
+            })
+            .await;
+        if let Err(e) = sprites_downloaded {
             eprintln!("Error downloading sprites: {}", e);
         }
 
         println!("Fetching Pokemon");
-        let pokemon = self.fetch_all_pokemon().await;
+        let pokemon = self
+            .fetch_all_pokemon(|fetched, total| {
+                let _ =
+                    sender.unbounded_send(CacheBuildProgress::FetchingPokemon { fetched, total });
+            })
+            .await;
+
+        if self.is_cancelled() {
+            println!("Cache construction cancelled, it will be resumed on the next run");
+            return Err(String::from("The download was cancelled"));
+        }
+        if pokemon.is_empty() {
+            return Err(String::from("Could not reach PokéApi"));
+        }
 
         println!("Updating Cache");
         let mut write_guard = self.cache.write().await;
@@ -165,19 +667,297 @@ impl Api {
         drop(write_guard); // Release the write lock
 
         println!("Save Cache");
-        self.save_cache()
-            .await
-            .unwrap_or_else(|e| eprintln!("Failed to save cache: {}", e));
+        let _ = sender.unbounded_send(CacheBuildProgress::SavingCache);
+        match self.save_cache().await {
+            Ok(()) => {
+                let _ = tokio::fs::remove_file(&build_marker).await;
+                let _ = tokio::fs::remove_dir_all(self.cache_dir().join(FETCHED_POKEMON_DIR)).await;
+            }
+            Err(e) => eprintln!("Failed to save cache: {}", e),
+        }
 
         println!("Return Pokémon List");
-        pokemon
+        Ok(pokemon)
+    }
+
+    /// Fetch the Pokémon added to PokéApi since the Cache was built, and the ones whose name
+    /// changed, and add them to the Cache, reporting the progress of each Pokémon fetched. Only
+    /// the list of Pokémon is compared with the Cache, PokéApi has no cheap way to tell whether
+    /// the data of a Pokémon changed, so the Pokémon already cached are kept as they are (renewing
+    /// the Cache fetches them again). The current Cache is kept if the refresh fails or is
+    /// cancelled.
+    pub fn refresh_all_pokemon(
+        &self,
+    ) -> futures::channel::mpsc::UnboundedReceiver<DataRefreshProgress> {
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let api = self.clone();
+        api.reset_cancel();
+
+        tokio::spawn(async move {
+            let result = api.fetch_new_pokemon(&sender).await;
+            let _ = sender.unbounded_send(DataRefreshProgress::Finished(result));
+        });
+
+        receiver
+    }
+
+    async fn fetch_new_pokemon(
+        &self,
+        sender: &futures::channel::mpsc::UnboundedSender<DataRefreshProgress>,
+    ) -> Result<(BTreeMap<i64, StarryPokemon>, usize), String> {
+        let mut pokemon: BTreeMap<i64, StarryPokemon> = self
+            .cache
+            .read()
+            .await
+            .as_ref()
+            .map(|cache_data| cache_data.pokemon.clone())
+            .unwrap_or_default();
+
+        let all_entries = rustemon::pokemon::pokemon::get_all_entries(&self.client)
+            .await
+            .map_err(|_| String::from("Could not reach PokéApi"))?;
+        // The entries are compared by id, an id that is cached under another name was renamed
+        let changed_entries: Vec<_> = all_entries
+            .into_iter()
+            .filter(|entry| {
+                resource_id(&entry.url).is_none_or(|id| {
+                    pokemon
+                        .get(&id)
+                        .is_none_or(|cached| cached.pokemon.name != entry.name)
+                })
+            })
+            .collect();
+        let total = changed_entries.len();
+
+        let fetched: Vec<StarryPokemon> = futures::stream::iter(changed_entries)
+            .map(|entry| async move {
+                if self.is_cancelled() {
+                    return None;
+                }
+                let (pokemon, details) =
+                    Self::fetch_pokemon_details(&entry.name, &self.client).await;
+                // A Pokémon that couldn't be fetched has no id, it's fetched by the next refresh
+                if pokemon.pokemon.id <= 0 {
+                    return None;
+                }
+
+                if let Err(e) = self
+                    .save_pokemon_details(pokemon.pokemon.id, &details)
+                    .await
+                {
+                    eprintln!(
+                        "Failed to save the details of {}: {}",
+                        pokemon.pokemon.name, e
+                    );
+                }
+                Some(pokemon)
+            })
+            .buffer_unordered(30)
+            .enumerate()
+            .filter_map(|(fetched, result)| {
+                let _ = sender.unbounded_send(DataRefreshProgress::Fetching {
+                    fetched: fetched + 1,
+                    total,
+                });
+                futures::future::ready(result)
+            })
+            .collect()
+            .await;
+
+        if self.is_cancelled() {
+            return Err(String::from("Data refresh cancelled"));
+        }
+
+        let mut renamed = 0;
+        for fetched_pokemon in fetched {
+            renamed += pokemon.contains_key(&fetched_pokemon.pokemon.id) as usize;
+            pokemon.insert(fetched_pokemon.pokemon.id, fetched_pokemon);
+        }
+
+        let missing_sprites = pokemon.values().any(|p| {
+            p.sprite_path
+                .as_ref()
+                .is_some_and(|path| !Path::new(path).exists())
+        });
+        if missing_sprites {
+            if let Err(e) = self.download_all_pokemon_sprites(|_, _| ()).await {
+                eprintln!("Error downloading sprites: {}", e);
+            }
+        }
+
+        let mut write_guard = self.cache.write().await;
+        *write_guard = Some(PokemonCache {
+            pokemon: pokemon.clone(),
+        });
+        drop(write_guard); // Release the write lock
+
+        self.save_cache().await.map_err(|e| e.to_string())?;
+        Ok((pokemon, renamed))
+    }
+
+    /// Retrieve all Pokémon Data from an existing Cache without building it, none if there's no
+    /// cache yet. Used by the command line, which can't download the whole Pokédex.
+    pub async fn load_cached_pokemon(&self) -> Option<BTreeMap<i64, StarryPokemon>> {
+        self.load_cache().await.ok()?;
+
+        let read_guard = self.cache.read().await;
+        read_guard
+            .as_ref()
+            .map(|cache_data| cache_data.pokemon.clone())
+    }
+
+    /// Retrieve a single Pokémon Data from the loaded Cache
+    pub async fn get_pokemon(&self, id: i64) -> Option<StarryPokemon> {
+        let read_guard = self.cache.read().await;
+        read_guard
+            .as_ref()
+            .and_then(|cache_data| cache_data.pokemon.get(&id).cloned())
+    }
+
+    /// Retrieve the heavy data of a single Pokémon, it's stored on its own file so it's only
+    /// read when it's needed
+    pub async fn get_pokemon_details(&self, id: i64) -> StarryPokemonDetails {
+        read_cache_file(&self.pokemon_details_file(id))
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to read the details of Pokémon {}: {}", id, e);
+                None
+            })
+            .unwrap_or_default()
+    }
+
+    /// Loads the search index saved next to the cache, building (and saving) it again if it was
+    /// built from other names
+    pub async fn load_search_index(&self, names: Vec<(String, i64)>) -> SearchIndex {
+        if let Some(search_index) = self.load_dataset::<SearchIndex>(SEARCH_INDEX_FILE).await {
+            if search_index.is_built_from(&names) {
+                return search_index;
+            }
+        }
+
+        let search_index = tokio::task::spawn_blocking(move || SearchIndex::build(names))
+            .await
+            .unwrap_or_default();
+        if let Err(e) = self.save_dataset(SEARCH_INDEX_FILE, &search_index).await {
+            eprintln!("Failed to save the search index: {}", e);
+        }
+
+        search_index
+    }
+
+    /// Builds the location → Pokémon index from the encounter info of the given Pokémon
+    pub async fn load_location_index(
+        &self,
+        pokemon_ids: Vec<i64>,
+    ) -> BTreeMap<String, Vec<StarryLocationEncounter>> {
+        let mut location_index: BTreeMap<String, Vec<StarryLocationEncounter>> = BTreeMap::new();
+
+        let all_details: Vec<(i64, StarryPokemonDetails)> = futures::stream::iter(pokemon_ids)
+            .map(|id| async move { (id, self.get_pokemon_details(id).await) })
+            .buffer_unordered(30)
+            .collect()
+            .await;
+
+        for (pokemon_id, details) in all_details {
+            for encounter in details.encounter_info {
+                location_index
+                    .entry(encounter.city)
+                    .or_default()
+                    .push(StarryLocationEncounter {
+                        pokemon_id,
+                        games_method: encounter.games_method,
+                    });
+            }
+        }
+
+        for encounters in location_index.values_mut() {
+            encounters.sort_by_key(|encounter| encounter.pokemon_id);
+        }
+
+        location_index
+    }
+
+    /// Builds the item → evolving species index from the evolution chains of the given Pokémon
+    pub async fn load_evolution_item_index(
+        &self,
+        pokemon_ids: Vec<i64>,
+    ) -> BTreeMap<i64, BTreeSet<i64>> {
+        let mut evolution_item_index: BTreeMap<i64, BTreeSet<i64>> = BTreeMap::new();
+
+        let all_details: Vec<StarryPokemonDetails> = futures::stream::iter(pokemon_ids)
+            .map(|id| self.get_pokemon_details(id))
+            .buffer_unordered(30)
+            .collect()
+            .await;
+
+        // Every Pokémon of a chain has the whole chain, so the same stages are seen many times
+        for stage in all_details
+            .iter()
+            .flat_map(|details| &details.evolution_chain)
+        {
+            for item_id in &stage.item_ids {
+                evolution_item_index
+                    .entry(*item_id)
+                    .or_default()
+                    .insert(stage.species_id);
+            }
+        }
+
+        evolution_item_index
+    }
+
+    /// Saves the heavy data of a single Pokémon to its own file
+    async fn save_pokemon_details(
+        &self,
+        id: i64,
+        details: &StarryPokemonDetails,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let serialized_data = serde_json::to_vec(details)?;
+        write_cache_file(&self.pokemon_details_file(id), serialized_data).await
     }
 
-    /// Fetches all Pokémon Data from the PokéApi
-    async fn fetch_all_pokemon(&self) -> BTreeMap<i64, StarryPokemon> {
+    /// Saves a Pokémon fetched by the cache construction in progress, once its details are saved
+    async fn save_fetched_pokemon(
+        &self,
+        pokemon: &StarryPokemon,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut pokemon = pokemon.clone();
+        if let Some(sprite_path) = pokemon.sprite_path.as_mut() {
+            *sprite_path = relative_sprite_path(sprite_path, &self.resources_dir());
+        }
+
+        let serialized_data = serde_json::to_vec(&pokemon)?;
+        write_cache_file(
+            &self.fetched_pokemon_file(pokemon.pokemon.id),
+            serialized_data,
+        )
+        .await
+    }
+
+    /// Pokémon an interrupted cache construction fetched already, none if it has to be fetched
+    async fn load_fetched_pokemon(&self, url: &str) -> Option<StarryPokemon> {
+        let id = resource_id(url)?;
+        let mut pokemon: StarryPokemon = read_cache_file(&self.fetched_pokemon_file(id))
+            .await
+            .ok()
+            .flatten()?;
+        if let Some(sprite_path) = pokemon.sprite_path.as_mut() {
+            *sprite_path = resolve_sprite_path(sprite_path, &self.resources_dir());
+        }
+        Some(pokemon)
+    }
+
+    /// Fetches all Pokémon Data from the PokéApi, calling `on_progress` with how many of the total
+    /// are fetched after each one. The Pokémon an interrupted construction fetched are read from
+    /// disk instead.
+    async fn fetch_all_pokemon(
+        &self,
+        on_progress: impl Fn(usize, usize),
+    ) -> BTreeMap<i64, StarryPokemon> {
         let all_entries = rustemon::pokemon::pokemon::get_all_entries(&self.client)
             .await
             .unwrap_or_default();
+        let total = all_entries.len();
 
         let semaphore = Arc::new(Semaphore::new(30));
 
@@ -187,15 +967,46 @@ impl Api {
                 let sem = Arc::clone(&semaphore);
                 async move {
                     let _permit = sem.acquire().await.unwrap();
-                    Self::fetch_pokemon_details(&entry.name, &client).await
+                    if self.is_cancelled() {
+                        return None;
+                    }
+                    if let Some(pokemon) = self.load_fetched_pokemon(&entry.url).await {
+                        return Some(pokemon);
+                    }
+
+                    let (pokemon, details) =
+                        Self::fetch_pokemon_details(&entry.name, &client).await;
+                    let saved = match self
+                        .save_pokemon_details(pokemon.pokemon.id, &details)
+                        .await
+                    {
+                        // A Pokémon that couldn't be fetched has no id, it's fetched again
+                        Ok(()) if pokemon.pokemon.id > 0 => {
+                            self.save_fetched_pokemon(&pokemon).await
+                        }
+                        result => result,
+                    };
+                    if let Err(e) = saved {
+                        eprintln!(
+                            "Failed to save the details of {}: {}",
+                            pokemon.pokemon.name, e
+                        );
+                    }
+                    Some(pokemon)
                 }
             })
-            .buffer_unordered(30);
+            .buffer_unordered(30)
+            .enumerate()
+            .map(|(fetched, pokemon)| {
+                on_progress(fetched + 1, total);
+                pokemon
+            });
 
         pokemon_stream
-            .collect::<Vec<StarryPokemon>>()
+            .collect::<Vec<Option<StarryPokemon>>>()
             .await
             .into_iter()
+            .flatten()
             .map(|pokemon| (pokemon.pokemon.id, pokemon))
             .collect()
     }
@@ -204,20 +1015,42 @@ impl Api {
     async fn fetch_pokemon_details(
         name: &str,
         client: &rustemon::client::RustemonClient,
-    ) -> StarryPokemon {
+    ) -> (StarryPokemon, StarryPokemonDetails) {
         let pokemon = rustemon::pokemon::pokemon::get_by_name(name, client)
             .await
             .unwrap_or_default();
 
+        let specie = rustemon::pokemon::pokemon_species::get_by_name(&pokemon.species.name, client)
+            .await
+            .ok();
+
+        Self::complete_pokemon_details(pokemon, specie, client).await
+    }
+
+    /// Retrieve the rest of the data of a Pokémon whose PokéApi Pokémon and specie are fetched
+    /// already, and parse it all to the StarryDex format
+    async fn complete_pokemon_details(
+        pokemon: rustemon::model::pokemon::Pokemon,
+        specie: Option<rustemon::model::pokemon::PokemonSpecies>,
+        client: &rustemon::client::RustemonClient,
+    ) -> (StarryPokemon, StarryPokemonDetails) {
         let encounter_info = rustemon::pokemon::pokemon::encounters::get_by_id(pokemon.id, client)
             .await
             .unwrap_or_default();
 
-        let resources_path = dirs::data_dir()
-            .unwrap()
-            .join(APP_ID)
-            .join("resources")
-            .join("sprites");
+        let evolution_chain_id = specie
+            .as_ref()
+            .and_then(|specie| specie.evolution_chain.as_ref())
+            .and_then(|evolution_chain| resource_id(&evolution_chain.url));
+        let evolution_chain = match evolution_chain_id {
+            Some(id) => rustemon::evolution::evolution_chain::get_by_id(id, client)
+                .await
+                .map(|evolution_chain| parse_evolution_chain(&evolution_chain.chain))
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        let resources_path = app_data_dir().join("resources").join("sprites");
 
         let image_path = if let Some(_front_default_sprite) = &pokemon.sprites.front_default {
             let image_filename = format!("{}_front.png", pokemon.name);
@@ -250,8 +1083,90 @@ impl Api {
                 })
                 .collect(),
             stats: parse_pokemon_stats(&pokemon.stats),
+            ev_yield: Some(parse_pokemon_ev_yield(&pokemon.stats)),
+            base_experience: pokemon.base_experience,
+            available_games: Some(
+                encounter_info
+                    .iter()
+                    .flat_map(|ef| ef.version_details.iter())
+                    .map(|vd| vd.version.name.clone())
+                    .collect::<BTreeSet<String>>()
+                    .into_iter()
+                    .collect(),
+            ),
         };
 
+        // Entries are sorted from the oldest to the newest game, keep the newest one
+        let flavor_texts = specie
+            .iter()
+            .flat_map(|specie| &specie.flavor_text_entries)
+            .filter(|f| BUNDLED_LANGUAGES.contains(&f.language.name.as_str()))
+            .map(|f| {
+                (
+                    f.language.name.clone(),
+                    f.flavor_text
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                )
+            })
+            .collect();
+
+        // Parse Rustemon specie data to the StarryDex format
+        let starry_specie = specie.map(|specie| StarryPokemonSpecie {
+            genus: specie
+                .genera
+                .iter()
+                .find(|g| g.language.name == "en")
+                .map(|g| g.genus.clone()),
+            capture_rate: specie.capture_rate,
+            base_happiness: specie.base_happiness,
+            growth_rate: capitalize_string(&specie.growth_rate.name),
+            gender_rate: Some(specie.gender_rate),
+            hatch_counter: specie.hatch_counter,
+            generation: Some(specie.generation.name.clone()),
+            egg_groups: Some(
+                specie
+                    .egg_groups
+                    .iter()
+                    .map(|egg_group| egg_group.name.clone())
+                    .collect(),
+            ),
+            genera: Some(
+                specie
+                    .genera
+                    .iter()
+                    .filter(|g| BUNDLED_LANGUAGES.contains(&g.language.name.as_str()))
+                    .map(|g| (g.language.name.clone(), g.genus.clone()))
+                    .collect(),
+            ),
+            // Other varieties (megas, regional forms...) keep their own name
+            names: pokemon.is_default.then(|| {
+                specie
+                    .names
+                    .iter()
+                    .map(|name| (name.language.name.clone(), name.name.clone()))
+                    .collect()
+            }),
+        });
+
+        // Parse Rustemon held items data to the StarryDex format
+        let starry_held_items: Vec<StarryPokemonHeldItem> = pokemon
+            .held_items
+            .iter()
+            .map(|held_item| StarryPokemonHeldItem {
+                item: capitalize_string(&held_item.item.name),
+                version_details: held_item
+                    .version_details
+                    .iter()
+                    .map(|vd| StarryPokemonHeldItemVersion {
+                        version: capitalize_string(&vd.version.name),
+                        rarity: vd.rarity,
+                    })
+                    .collect(),
+            })
+            .collect();
+
         // Parse Rustemon encounter info data to the StarryDex format
         let starry_encounter_info: Vec<StarryPokemonEncounterInfo> = encounter_info
             .iter()
@@ -281,20 +1196,418 @@ impl Api {
             })
             .collect();
 
-        StarryPokemon {
-            pokemon: starry_pokemon_data,
-            sprite_path: image_path,
-            encounter_info: Some(starry_encounter_info),
+        (
+            StarryPokemon {
+                pokemon: starry_pokemon_data,
+                specie: starry_specie,
+                sprite_path: image_path,
+            },
+            StarryPokemonDetails {
+                encounter_info: starry_encounter_info,
+                held_items: starry_held_items,
+                flavor_texts,
+                evolution_chain,
+            },
+        )
+    }
+
+    /// Retrieve all Moves Data from Cache, if the cache does not exist, create it
+    pub async fn load_all_moves(&self) -> Vec<StarryMove> {
+        if let Some(moves) = self.load_dataset("moves_cache.json").await {
+            return moves;
+        }
+
+        println!("No Moves Cache, Fetching Moves");
+        let all_entries = rustemon::moves::move_::get_all_entries(&self.client)
+            .await
+            .unwrap_or_default();
+
+        let semaphore = Arc::new(Semaphore::new(30));
+
+        let mut moves: Vec<StarryMove> = futures::stream::iter(all_entries)
+            .map(|entry| {
+                let sem = Arc::clone(&semaphore);
+                async move {
+                    let _permit = sem.acquire().await.unwrap();
+                    if self.is_cancelled() {
+                        return None;
+                    }
+                    let pokemon_move =
+                        rustemon::moves::move_::get_by_name(&entry.name, &self.client)
+                            .await
+                            .ok()?;
+
+                    Some(StarryMove {
+                        id: pokemon_move.id,
+                        name: pokemon_move.name,
+                        type_name: pokemon_move.type_.name,
+                        damage_class: pokemon_move.damage_class.name,
+                        power: pokemon_move.power,
+                        accuracy: pokemon_move.accuracy,
+                        pp: pokemon_move.pp,
+                        effect: pokemon_move
+                            .effect_entries
+                            .iter()
+                            .find(|e| e.language.name == "en")
+                            .map(|e| {
+                                e.short_effect.replace(
+                                    "$effect_chance",
+                                    &pokemon_move.effect_chance.unwrap_or_default().to_string(),
+                                )
+                            }),
+                        learned_by_pokemon: pokemon_move
+                            .learned_by_pokemon
+                            .into_iter()
+                            .map(|pokemon| pokemon.name)
+                            .collect(),
+                    })
+                }
+            })
+            .buffer_unordered(30)
+            .filter_map(|pokemon_move| async move { pokemon_move })
+            .collect()
+            .await;
+
+        if self.is_cancelled() {
+            return Vec::new();
+        }
+
+        moves.sort_by_key(|pokemon_move| pokemon_move.id);
+        if let Err(e) = self.save_dataset("moves_cache.json", &moves).await {
+            eprintln!("Failed to save moves cache: {}", e);
+        }
+
+        moves
+    }
+
+    /// Retrieve all Abilities Data from Cache, if the cache does not exist, create it
+    pub async fn load_all_abilities(&self) -> Vec<StarryAbility> {
+        if let Some(abilities) = self.load_dataset("abilities_cache.json").await {
+            return abilities;
+        }
+
+        println!("No Abilities Cache, Fetching Abilities");
+        let all_entries = rustemon::pokemon::ability::get_all_entries(&self.client)
+            .await
+            .unwrap_or_default();
+
+        let semaphore = Arc::new(Semaphore::new(30));
+
+        let mut abilities: Vec<StarryAbility> = futures::stream::iter(all_entries)
+            .map(|entry| {
+                let sem = Arc::clone(&semaphore);
+                async move {
+                    let _permit = sem.acquire().await.unwrap();
+                    if self.is_cancelled() {
+                        return None;
+                    }
+                    let ability =
+                        rustemon::pokemon::ability::get_by_name(&entry.name, &self.client)
+                            .await
+                            .ok()?;
+
+                    // Abilities outside the main series games (Colosseum, XD...) are not shown
+                    if !ability.is_main_series {
+                        return None;
+                    }
+
+                    Some(StarryAbility {
+                        id: ability.id,
+                        name: ability.name,
+                        effect: ability
+                            .effect_entries
+                            .iter()
+                            .find(|e| e.language.name == "en")
+                            .map(|e| e.short_effect.clone()),
+                    })
+                }
+            })
+            .buffer_unordered(30)
+            .filter_map(|ability| async move { ability })
+            .collect()
+            .await;
+
+        if self.is_cancelled() {
+            return Vec::new();
+        }
+
+        abilities.sort_by_key(|ability| ability.id);
+        if let Err(e) = self.save_dataset("abilities_cache.json", &abilities).await {
+            eprintln!("Failed to save abilities cache: {}", e);
+        }
+
+        abilities
+    }
+
+    /// Retrieve all Berries Data from Cache, if the cache does not exist, create it
+    pub async fn load_all_berries(&self) -> Vec<StarryBerry> {
+        if let Some(berries) = self.load_dataset("berries_cache.json").await {
+            return berries;
+        }
+
+        println!("No Berries Cache, Fetching Berries");
+        let all_entries = rustemon::berries::berry::get_all_entries(&self.client)
+            .await
+            .unwrap_or_default();
+
+        let semaphore = Arc::new(Semaphore::new(30));
+
+        let mut berries: Vec<StarryBerry> = futures::stream::iter(all_entries)
+            .map(|entry| {
+                let sem = Arc::clone(&semaphore);
+                async move {
+                    let _permit = sem.acquire().await.unwrap();
+                    if self.is_cancelled() {
+                        return None;
+                    }
+                    let berry = rustemon::berries::berry::get_by_name(&entry.name, &self.client)
+                        .await
+                        .ok()?;
+
+                    Some(StarryBerry {
+                        id: berry.id,
+                        name: berry.name,
+                        firmness: berry.firmness.name,
+                        growth_time: berry.growth_time,
+                        max_harvest: berry.max_harvest,
+                        natural_gift_type: berry.natural_gift_type.name,
+                        natural_gift_power: berry.natural_gift_power,
+                        flavors: berry
+                            .flavors
+                            .into_iter()
+                            .map(|flavor| (flavor.flavor.name, flavor.potency))
+                            .collect(),
+                    })
+                }
+            })
+            .buffer_unordered(30)
+            .filter_map(|berry| async move { berry })
+            .collect()
+            .await;
+
+        if self.is_cancelled() {
+            return Vec::new();
+        }
+
+        berries.sort_by_key(|berry| berry.id);
+        if let Err(e) = self.save_dataset("berries_cache.json", &berries).await {
+            eprintln!("Failed to save berries cache: {}", e);
+        }
+
+        berries
+    }
+
+    /// Retrieve all Items Data from Cache, if the cache does not exist, create it and download the item sprites
+    pub async fn load_all_items(&self) -> Vec<StarryItem> {
+        let resources_dir = self.resources_dir();
+        if let Some(mut items) = self
+            .load_dataset::<Vec<StarryItem>>("items_cache.json")
+            .await
+        {
+            for sprite_path in items
+                .iter_mut()
+                .filter_map(|item| item.sprite_path.as_mut())
+            {
+                *sprite_path = resolve_sprite_path(sprite_path, &resources_dir);
+            }
+            return items;
+        }
+
+        println!("No Items Cache, Fetching Items");
+        let all_entries = rustemon::items::item::get_all_entries(&self.client)
+            .await
+            .unwrap_or_default();
+
+        let sprites_path = self
+            .app_data_dir()
+            .join("resources")
+            .join("sprites")
+            .join("items");
+        let download_client = reqwest::Client::builder()
+            .pool_max_idle_per_host(10)
+            .build()
+            .unwrap_or_default();
+
+        let semaphore = Arc::new(Semaphore::new(30));
+
+        let mut items: Vec<StarryItem> = futures::stream::iter(all_entries)
+            .map(|entry| {
+                let sem = Arc::clone(&semaphore);
+                let download_client = download_client.clone();
+                let sprites_path = sprites_path.clone();
+                async move {
+                    let _permit = sem.acquire().await.unwrap();
+                    if self.is_cancelled() {
+                        return None;
+                    }
+                    let item = rustemon::items::item::get_by_name(&entry.name, &self.client)
+                        .await
+                        .ok()?;
+
+                    let sprite_path = match item.sprites.default {
+                        Some(sprite_url) => {
+                            let image_path = sprites_path.join(format!("{}.png", item.name));
+                            match download_image_to(&download_client, sprite_url, &image_path).await
+                            {
+                                Ok(()) => image_path.to_str().map(String::from),
+                                Err(e) => {
+                                    eprintln!("Error downloading item sprite: {}", e);
+                                    None
+                                }
+                            }
+                        }
+                        None => None,
+                    };
+
+                    Some(StarryItem {
+                        id: item.id,
+                        name: item.name,
+                        category: item.category.name,
+                        cost: item.cost,
+                        effect: item
+                            .effect_entries
+                            .iter()
+                            .find(|e| e.language.name == "en")
+                            .map(|e| e.short_effect.clone()),
+                        sprite_path,
+                    })
+                }
+            })
+            .buffer_unordered(30)
+            .filter_map(|item| async move { item })
+            .collect()
+            .await;
+
+        if self.is_cancelled() {
+            return Vec::new();
+        }
+
+        items.sort_by_key(|item| item.id);
+        let mut saved_items = items.clone();
+        for sprite_path in saved_items
+            .iter_mut()
+            .filter_map(|item| item.sprite_path.as_mut())
+        {
+            *sprite_path = relative_sprite_path(sprite_path, &resources_dir);
         }
+        if let Err(e) = self.save_dataset("items_cache.json", &saved_items).await {
+            eprintln!("Failed to save items cache: {}", e);
+        }
+
+        items
     }
 
-    /// Download Pokémon Sprites to the designed folder
+    /// Checks the sprites of the given Pokémon (name, sprite path), downloading again the missing or
+    /// broken ones, the progress is sent through the returned stream
+    pub fn repair_sprites(
+        &self,
+        sprites: Vec<(String, String)>,
+    ) -> futures::channel::mpsc::UnboundedReceiver<SpriteRepairProgress> {
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let api = self.clone();
+
+        tokio::spawn(async move {
+            let client = reqwest::Client::builder()
+                .pool_max_idle_per_host(10)
+                .build()
+                .unwrap_or_default();
+            let total = sprites.len();
+
+            let results: Vec<Option<bool>> = futures::stream::iter(sprites)
+                .map(|(name, sprite_path)| {
+                    let client = client.clone();
+                    let api = &api;
+                    async move {
+                        if is_valid_png(&sprite_path).await {
+                            return None;
+                        }
+
+                        let _ = tokio::fs::remove_file(&sprite_path).await;
+                        let pokemon = rustemon::pokemon::pokemon::get_by_name(&name, &api.client)
+                            .await
+                            .ok()?;
+                        let sprite_url = pokemon.sprites.front_default?;
+                        Some(
+                            download_image_to(&client, sprite_url, Path::new(&sprite_path))
+                                .await
+                                .is_ok(),
+                        )
+                    }
+                })
+                .buffer_unordered(20)
+                .enumerate()
+                .map(|(checked, result)| {
+                    let _ = sender.unbounded_send(SpriteRepairProgress::Checking {
+                        checked: checked + 1,
+                        total,
+                    });
+                    result
+                })
+                .collect()
+                .await;
+
+            let _ = sender.unbounded_send(SpriteRepairProgress::Finished {
+                repaired: results.iter().filter(|r| **r == Some(true)).count(),
+                failed: results.iter().filter(|r| **r == Some(false)).count(),
+            });
+        });
+
+        receiver
+    }
+
+    /// Downloads the given missing sprites (Pokémon id, name and sprite path) in the background,
+    /// sending the id of each Pokémon whose sprite was downloaded
+    pub fn fetch_missing_sprites(
+        &self,
+        sprites: Vec<(i64, String, String)>,
+    ) -> futures::channel::mpsc::UnboundedReceiver<i64> {
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let api = self.clone();
+
+        tokio::spawn(async move {
+            let client = reqwest::Client::builder()
+                .pool_max_idle_per_host(10)
+                .build()
+                .unwrap_or_default();
+
+            futures::stream::iter(sprites)
+                .map(|(id, name, sprite_path)| {
+                    let client = client.clone();
+                    let api = &api;
+                    async move {
+                        let pokemon = rustemon::pokemon::pokemon::get_by_name(&name, &api.client)
+                            .await
+                            .ok()?;
+                        let sprite_url = pokemon.sprites.front_default?;
+                        download_image_to(&client, sprite_url, Path::new(&sprite_path))
+                            .await
+                            .ok()
+                            .map(|()| id)
+                    }
+                })
+                .buffer_unordered(10)
+                .for_each(|downloaded| {
+                    if let Some(id) = downloaded {
+                        let _ = sender.unbounded_send(id);
+                    }
+                    futures::future::ready(())
+                })
+                .await;
+        });
+
+        receiver
+    }
+
+    /// Download Pokémon Sprites to the designed folder, calling `on_progress` with how many of the
+    /// total are done after each one
     pub async fn download_all_pokemon_sprites(
         &self,
+        on_progress: impl Fn(usize, usize),
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let all_entries = rustemon::pokemon::pokemon::get_all_entries(&self.client)
             .await
             .unwrap_or_default();
+        let total = all_entries.len();
 
         let client = reqwest::Client::builder()
             .pool_max_idle_per_host(10)
@@ -308,6 +1621,9 @@ impl Api {
                 let semaphore = Arc::clone(&semaphore);
                 async move {
                     let _permit = semaphore.acquire().await.unwrap();
+                    if self.is_cancelled() {
+                        return Ok(());
+                    }
                     let pokemon =
                         rustemon::pokemon::pokemon::get_by_name(&entry.name, &self.client)
                             .await
@@ -320,6 +1636,11 @@ impl Api {
                 }
             })
             .buffer_unordered(20) // Adjust the number of concurrent tasks
+            .enumerate()
+            .map(|(downloaded, result)| {
+                on_progress(downloaded + 1, total);
+                result
+            })
             .collect::<Vec<_>>()
             .await;
 
@@ -1,23 +1,90 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::api::Api;
-use crate::config::{AppTheme, Config, TypeFilteringMode};
+use crate::api::{Api, CacheBuildProgress, DataRefreshProgress, SpriteRepairProgress};
+use crate::backup::Backup;
+use crate::challenges::{Challenge, ChallengeHistory, ChallengeWeek};
+use crate::cli::{ActivationRequest, Flags, OpenRequest};
+use crate::config::{
+    AppTheme, CardSize, ColorPalette, Config, KeyboardScheme, MeasurementUnits, SpriteSet,
+    TypeFilteringMode,
+};
+use crate::data_dir::{app_data_dir, data_dir_source, move_data_dir, DataDirSource};
+use crate::data_packs::{load_data_packs, DataPack, DATA_PACK_ID_START};
+use crate::export::ListFormat;
 use crate::fl;
-use crate::image_cache::ImageCache;
-use crate::utils::{capitalize_string, remove_dir_contents, scale_numbers};
+use crate::games::MAIN_SERIES_GAMES;
+use crate::image_cache::{ImageCache, DEFAULT_SPRITE_CACHE_MB};
+use crate::key_binds::{format_key_bind, key_bind_from_press, parse_key_bind};
+use crate::natures::NATURES;
+use crate::palette::{self, MAX_PALETTE_ENTRIES};
+use crate::rankings::{self, RankedStat, StatPercentiles, GENERATIONS};
+use crate::search::{SearchIndex, SearchQuery, SEARCH_OPERATORS};
+use crate::session::Session;
+use crate::stat_calculator::{StatCalculator, CALCULATOR_STATS, MAX_IV, MAX_LEVEL, MAX_STAT_EVS};
+use crate::storage::StorageComponent;
+use crate::trivia::{TriviaGame, TriviaSide};
+use crate::type_chart::{self, ALL_TYPES};
+use crate::utils::{capitalize_string, format_height, format_size, format_weight};
+use crate::watchlist::{WatchedChanges, WatchedSnapshot};
 use cosmic::app::{context_drawer, Core, Task};
 use cosmic::cosmic_config::{self, CosmicConfigEntry};
 use cosmic::iced::alignment::{Horizontal, Vertical};
+use cosmic::iced::keyboard::{key::Named, Key, Modifiers};
 use cosmic::iced::{Alignment, Length, Pixels, Subscription};
 use cosmic::iced_core::text::LineHeight;
 use cosmic::widget::about::About;
 use cosmic::widget::{self, menu, Column};
 use cosmic::{theme, Application, ApplicationExt, Element};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::time::Duration;
 
 const REPOSITORY: &str = "https://github.com/mariinkys/starrydex";
+static PALETTE_INPUT_ID: LazyLock<widget::Id> = LazyLock::new(widget::Id::unique);
+static GOTO_INPUT_ID: LazyLock<widget::Id> = LazyLock::new(widget::Id::unique);
+static SEARCH_INPUT_ID: LazyLock<widget::Id> = LazyLock::new(widget::Id::unique);
+static POKEMON_GRID_ID: LazyLock<widget::Id> = LazyLock::new(widget::Id::unique);
+/// Damage classes of the moves, named as PokéApi names them
+const DAMAGE_CLASSES: [&str; 3] = ["physical", "special", "status"];
+/// Steps needed to complete an egg cycle (Generation VIII onwards)
+const STEPS_PER_EGG_CYCLE: i64 = 257;
+const MALE_COLOR: cosmic::iced::Color = cosmic::iced::Color::from_rgb(0.24, 0.49, 0.79);
+const FEMALE_COLOR: cosmic::iced::Color = cosmic::iced::Color::from_rgb(0.88, 0.36, 0.54);
+const TYPE_CHART_CELL_WIDTH: f32 = 44.0;
+const TYPE_CHART_CELL_HEIGHT: f32 = 30.0;
+const ENCOUNTER_LOCATION_WIDTH: f32 = 160.0;
+const ENCOUNTER_CELL_WIDTH: f32 = 48.0;
+/// Sizes of the body and caption texts of the theme, multiplied by the text scale
+const BODY_TEXT_SIZE: f32 = 14.0;
+const CAPTION_TEXT_SIZE: f32 = 10.0;
+/// Height the number, name and types take in a card of the grid, which grows with the text scale
+const CARD_TEXT_HEIGHT: f32 = 45.0;
+/// Text scales the settings offer, in percent
+const MIN_TEXT_SCALE: u16 = 100;
+const MAX_TEXT_SCALE: u16 = 150;
+/// Most Pokémon suggested when the search matches none
+const MAX_SUGGESTIONS: usize = 5;
+/// Rows of placeholder cards shown while the Pokémon list is loaded
+const SKELETON_ROWS: usize = 4;
+/// Placeholder cards a shimmer passes over before starting again, and how often it moves
+const SKELETON_WAVE_LENGTH: usize = 12;
+const SKELETON_TICK: Duration = Duration::from_millis(80);
+/// Width of the progress bar shown while the cache is built
+const CACHE_BUILD_PROGRESS_WIDTH: f32 = 320.0;
+/// Height of the text of the group headers of the grid
+const GROUP_HEADER_HEIGHT: f32 = 28.0;
+/// Rows the infinite scroll grid loads at once
+const INFINITE_SCROLL_PAGE_ROWS: usize = 10;
+/// Most rows the infinite scroll grid keeps built, the farthest ones are dropped
+const INFINITE_SCROLL_MAX_ROWS: usize = 40;
+/// Distance to the edge of the loaded rows at which the next ones are loaded
+const INFINITE_SCROLL_THRESHOLD: f32 = 400.0;
+const TABLE_SPRITE_SIZE: f32 = 32.0;
+const TABLE_NUMBER_WIDTH: f32 = 64.0;
+const TABLE_STAT_WIDTH: f32 = 64.0;
 //const APP_ICON: &[u8] = include_bytes!("../res/icons/hicolor/256x256/apps/dev.mariinkys.StarryDex.svg");
 
 /// The application model stores app-specific state used to describe its interface and
@@ -33,6 +100,8 @@ pub struct StarryDex {
     key_binds: HashMap<menu::KeyBind, MenuAction>,
     // Configuration data that persists between application runs.
     config: Config,
+    // Where the configuration is saved, none if it can't be opened
+    config_handler: Option<cosmic_config::Config>,
     // Application Themes
     app_themes: Vec<String>,
     // API Client
@@ -45,32 +114,331 @@ pub struct StarryDex {
     filtered_pokemon_list: Vec<StarryPokemon>,
     // Holds the data of the currently selected Pokémon to show it on the context page
     selected_pokemon: Option<StarryPokemon>,
+    // Holds the id of the Pokémon that is being loaded to be shown on the context page
+    loading_pokemon: Option<i64>,
+    // Holds the heavy data of the currently selected Pokémon, loaded apart from the Pokémon
+    selected_pokemon_details: Option<StarryPokemonDetails>,
     // Controls the Pokémon Details Toggle of the Pokémon Context Page
     wants_pokemon_details: bool,
+    // Order (and grouping) of the shown list of Pokémon
+    sort_mode: SortMode,
+    // Options of the sort dropdown
+    sort_mode_options: Vec<String>,
+    // Whether the list of Pokémon is shown as a grid of cards or as a table
+    view_mode: ViewMode,
+    // Options of the view mode dropdown
+    view_mode_options: Vec<String>,
+    // Options of the jump to generation dropdown
+    jump_generation_options: Vec<String>,
+    // Generation last jumped to from the dropdown
+    jumped_generation: Option<usize>,
+    // Rows of the grid built in the infinite scroll mode
+    grid_window: std::ops::Range<usize>,
+    // Scroll offset and height of the view of the grid, only the rows in view are built
+    grid_viewport: (f32, f32),
+    // Column the table view is sorted by, and whether the order is descending
+    table_sort: (TableColumn, bool),
     // Holds the search input value
     search: String,
+    // Controls the visibility of the search help popover
+    show_search_help: bool,
     // Holds the currently applied filters if there are any
     filters: Filters,
     // Type Filter Modes
     type_filter_mode: Vec<String>,
+    // Keyboard Schemes
+    keyboard_schemes: Vec<String>,
+    // Whether g was pressed and a second g would jump to the first Pokémon (Vim scheme)
+    vim_pending_g: bool,
+    // Progress of the loading of the Pokémon list, shown while the cache is built
+    cache_build: Option<CacheBuildProgress>,
+    // Step of the shimmer of the placeholder cards shown while the Pokémon list is loaded
+    skeleton_frame: usize,
+    // Whether sprites of the grid are being decoded in the background, their cards show a
+    // placeholder until they are
+    preloading_sprites: bool,
+    // Bytes freed removing the caches of older versions on startup
+    freed_old_caches: u64,
+    // Disk space used by each storage component, loaded when the Settings are opened
+    storage_usage: Vec<(StorageComponent, u64)>,
+    // Folder typed in the Settings to move the data directory to
+    data_dir_input: String,
+    // Whether the data directory is being moved
+    moving_data_dir: bool,
+    // Why the last move of the data directory failed
+    data_dir_error: Option<String>,
+    // Backup file typed in the Settings to import
+    backup_path_input: String,
+    // What happened with the last backup exported or imported
+    backup_status: Option<String>,
+    // Progress of the sprite repair started from the Settings page
+    sprite_repair: Option<SpriteRepairProgress>,
+    // Status of the data refresh started from the Settings page
+    data_refresh: Option<DataRefresh>,
+    // Pokémon whose sprite file is missing, shown with the fallback sprite
+    missing_sprites: HashSet<i64>,
+    // Sprite Sets
+    sprite_set_options: Vec<String>,
+    // Card Sizes
+    card_size_options: Vec<String>,
+    measurement_units_options: Vec<String>,
+    color_palette_options: Vec<String>,
+    // Downloaded sprites of the selected sprite set, by Pokémon id
+    set_sprites: HashMap<i64, String>,
+    // Sprites of the user's overrides folder, by Pokémon id
+    sprite_overrides: HashMap<i64, String>,
+    // Data packs of the data packs folder, their Pokémon are added to the list if enabled
+    data_packs: Vec<DataPack>,
+    // Holds the alias input value of the Settings page
+    new_alias: String,
+    // Holds the Pokémon name input value of the Settings page
+    new_alias_pokemon: String,
+    // Session left behind by a previous run that didn't exit cleanly, offered to be restored
+    pending_session: Option<Session>,
+    // Report of the crash that ended the previous run, offered for reporting until dismissed
+    crash_report: Option<String>,
+    // Last saved session, to only save it again when something changed
+    saved_session: Session,
+    // Currently shown Dex
+    dex_mode: DexMode,
+    // Holds the list of moves, loaded the first time the Moves Dex is shown
+    moves_list: LazyData<Vec<StarryMove>>,
+    // Holds the moves search input value
+    moves_search: String,
+    // Options of the type filter dropdowns ("All" followed by every type)
+    type_filter_options: Vec<String>,
+    // Selected option of the moves type filter
+    moves_type_filter: usize,
+    // Options of the moves damage class filter dropdown ("All" followed by every class)
+    damage_class_filter_options: Vec<String>,
+    // Selected option of the moves damage class filter
+    moves_damage_class_filter: usize,
+    // Holds the id of the currently selected move to show it on the context page
+    selected_move: Option<i64>,
+    // Holds the list of items, loaded the first time the Items Dex is shown
+    items_list: LazyData<Vec<StarryItem>>,
+    // Holds the items search input value
+    items_search: String,
+    // Categories of the loaded items, used by the category filter dropdown ("All" followed by every category)
+    item_category_options: Vec<String>,
+    // Selected option of the items category filter
+    items_category_filter: usize,
+    // Holds the id of the currently selected item to show it on the context page
+    selected_item: Option<i64>,
+    // Holds the list of abilities, loaded the first time the Abilities Dex is shown
+    abilities_list: LazyData<Vec<StarryAbility>>,
+    // Holds the abilities search input value
+    abilities_search: String,
+    // Ids of the Pokémon that have each ability, built from the Pokémon list
+    ability_index: HashMap<String, Vec<i64>>,
+    // Ids of the Pokémon in each egg group, built from the Pokémon list
+    egg_group_index: BTreeMap<String, Vec<i64>>,
+    // Ids of the Pokémon of each type (lowercase), built from the Pokémon list
+    type_index: HashMap<String, BTreeSet<i64>>,
+    // Ids of the Pokémon introduced in each generation, built from the Pokémon list
+    generation_index: HashMap<String, Vec<i64>>,
+    // Names of the Pokémon list prepared for the search, none while it's being loaded
+    search_index: Option<SearchIndex>,
+    // Egg group picked on the Egg Groups page
+    selected_egg_group: Option<String>,
+    // Pokémon that can be found on each location, built the first time the Locations Dex is shown
+    location_index: LazyData<BTreeMap<String, Vec<StarryLocationEncounter>>>,
+    // Item id → ids of the species that evolve using it, loaded the first time an item is shown
+    evolution_item_index: LazyData<BTreeMap<i64, BTreeSet<i64>>>,
+    // Holds the locations search input value
+    locations_search: String,
+    // Games found on the encounter info, used by the game filter dropdown ("All" followed by every game)
+    location_game_options: Vec<String>,
+    // Selected option of the locations game filter
+    locations_game_filter: usize,
+    // Location picked on the Locations Dex
+    selected_location: Option<String>,
+    // Holds the list of berries, loaded the first time the Berries page is shown
+    berries_list: LazyData<Vec<StarryBerry>>,
+    // Snapshot of the watched Pokémon taken before renewing the cache
+    watchlist_snapshot: Option<Vec<WatchedSnapshot>>,
+    // What changed on the watched Pokémon after the last cache renewal
+    watchlist_changes: Vec<WatchedChanges>,
+    // Cell (attacking type, defending type) of the type chart under the cursor
+    hovered_type_matchup: Option<(usize, usize)>,
+    // Index (in `NATURES`) of the nature picked on the Natures page
+    selected_nature: Option<usize>,
+    // Filters of the Rankings page, the generation and type indexes are offset by the "All" option
+    ranked_stat_options: Vec<String>,
+    ranked_stat: usize,
+    generation_filter_options: Vec<String>,
+    rankings_generation_filter: usize,
+    rankings_type_filter: usize,
+    // Top percentage of every Pokémon for each stat, computed when the Pokémon list is loaded
+    stat_percentiles: StatPercentiles,
+    // Pokémon caught for the weekly challenges
+    challenge_history: ChallengeHistory,
+    // Stat calculator of the Pokémon page, its nature is the selected nature
+    wants_stat_calculator: bool,
+    stat_calculator: StatCalculator,
+    nature_options: Vec<String>,
+    // State of the stat guessing game
+    trivia: TriviaGame,
+    // Dialog shown over the app, if any
+    dialog_page: Option<DialogPage>,
+    // Command palette, the selected entry is an index of `palette_entries`
+    palette_query: String,
+    palette_selected: usize,
+    // Number typed on the Go to Number dialog
+    goto_number: String,
+    // Pokémon of the grid selected with the keyboard
+    grid_selection: Option<i64>,
+    // Action waiting for a key press to get a new shortcut
+    capturing_key_bind: Option<MenuAction>,
+    // Action that already had the last captured shortcut
+    key_bind_conflict: Option<MenuAction>,
+    // Pokémon asked for in the command line, opened once the Pokémon list is loaded
+    open_request: Option<OpenRequest>,
 }
 
 /// Messages emitted by the application and its widgets.
 #[derive(Debug, Clone)]
 pub enum Message {
     LaunchUrl(String),
+    ReportProblem,
+    ExportPokemonDetails,
+    ExportedPokemonDetails(Result<PathBuf, String>),
+    CopyShowdownSet,
+    SaveShareCard,
+    SavedShareCard(Result<PathBuf, String>),
+    ExportPokemonList(ListFormat),
+    ExportedPokemonList(Result<PathBuf, String>),
     ToggleContextPage(ContextPage),
     UpdateConfig(Config),
     UpdateTheme(usize),
     UpdateTypeFilterMode(usize),
+    UpdateKeyboardScheme(usize),
 
     LoadPokemon(i64),
+    LoadedPokemon(i64, Option<StarryPokemon>),
+    LoadedPokemonDetails(i64, StarryPokemonDetails),
     TogglePokemonDetails(bool),
     Search(String),
+    ToggleSearchHelp,
+    DbusRequest(ActivationRequest),
+    UpdateSortMode(usize),
+    UpdateViewMode(usize),
+    JumpToGeneration(usize),
+    GridScrolled(cosmic::iced::widget::scrollable::Viewport),
+    ToggleInfiniteScroll(bool),
+    UpdateSpriteCacheLimit(u16),
+    PreloadedSprites,
+    PreloadQueuedSprites,
+    SortTable(TableColumn),
     ApplyCurrentFilters,
     ClearFilters,
+    ClearSearchAndFilters,
     DeleteCache,
 
+    RepairSprites,
+    RepairSpritesProgress(SpriteRepairProgress),
+    CacheBuildProgress(CacheBuildProgress),
+    SkeletonTick,
+    CancelCacheBuild,
+    RemovedOldCaches(u64),
+    LoadStorageUsage,
+    LoadedStorageUsage(Vec<(StorageComponent, u64)>),
+    ClearStorage(StorageComponent),
+    ClearedStorage(StorageComponent, Result<(), String>),
+    DataDirInput(String),
+    MoveDataDir(Option<PathBuf>),
+    MovedDataDir(Result<(), String>),
+    ExportBackup,
+    ExportedBackup(Result<PathBuf, String>),
+    BackupPathInput(String),
+    ImportBackup,
+    ImportedBackup(Result<Backup, String>),
+    LoadingFailed(String),
+    RetryLoading,
+    RefreshData,
+    RefreshDataProgress(DataRefreshProgress),
+    ToggleFetchMissingSprites(bool),
+    ToggleTypeAccents(bool),
+    ToggleReduceMotion(bool),
+    ToggleGenerationHeaders(bool),
+    FetchMissingSprites,
+    FetchedSprite(i64),
+    UpdateSpriteSet(usize),
+    UpdateCardSize(usize),
+    UpdateMeasurementUnits(usize),
+    UpdateColorPalette(usize),
+    UpdateTextScale(u16),
+    LoadSpriteSet,
+    FetchedSetSprite(SpriteSet, i64, String),
+    LoadSpriteOverrides,
+    OpenSpriteOverrides,
+    ToggleDataPack(String, bool),
+    ReloadDataPacks,
+    LoadSearchIndex,
+    LoadedSearchIndex(SearchIndex),
+    OpenDataPacks,
+    NewAliasInput(String),
+    NewAliasPokemonInput(String),
+    AddAlias,
+    RemoveAlias(String),
+
+    SaveSession,
+    RestoreSession,
+    DismissSession,
+    ReportCrash,
+    DismissCrashReport,
+
+    ChangeDexMode(DexMode),
+    LoadedMoves(Vec<StarryMove>),
+    MovesSearch(String),
+    MovesTypeFilter(usize),
+    MovesDamageClassFilter(usize),
+    LoadMove(i64),
+    LoadedItems(Vec<StarryItem>),
+    ItemsSearch(String),
+    ItemsCategoryFilter(usize),
+    LoadItem(i64),
+    LoadedEvolutionItemIndex(BTreeMap<i64, BTreeSet<i64>>),
+    LoadedAbilities(Vec<StarryAbility>),
+    AbilitiesSearch(String),
+    FilterByAbility(String),
+    LoadedBerries(Vec<StarryBerry>),
+    LoadedLocationIndex(BTreeMap<String, Vec<StarryLocationEncounter>>),
+    LocationsSearch(String),
+    LocationsGameFilter(usize),
+    SelectLocation(String),
+    SelectNature(usize),
+    RankedStat(usize),
+    RankingsGenerationFilter(usize),
+    RankingsTypeFilter(usize),
+    ToggleChallengeCaught(i64),
+    Key(Modifiers, Key),
+    CaptureKeyBind(MenuAction),
+    ResetKeyBind(MenuAction),
+    FocusSearch,
+    OpenCommandPalette,
+    CloseDialog,
+    OpenGoToNumber,
+    OpenSpriteViewer,
+    GoToNumberInput(String),
+    GoToNumberSubmit,
+    PaletteInput(String),
+    PaletteSubmit,
+    PaletteActivate(usize),
+    TriviaAnswer(TriviaSide),
+    TriviaNextRound,
+    ToggleStatCalculator(bool),
+    CalculatorLevel(u8),
+    CalculatorNature(usize),
+    CalculatorIv(usize, u8),
+    CalculatorEvs(usize, u16),
+    HoverTypeMatchup(Option<(usize, usize)>),
+    SelectEggGroup(String),
+    ToggleWatched(i64),
+    RenewCache(Vec<WatchedSnapshot>),
+    RemovedDownloadedData(Result<(), String>),
+    ComparedWatchlist(Vec<WatchedSnapshot>),
+
     CompletedFirstRun(Config, BTreeMap<i64, StarryPokemon>),
     LoadedPokemonList(BTreeMap<i64, StarryPokemon>),
     TypeFilterToggled(bool, String),
@@ -80,8 +448,174 @@ pub enum Message {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StarryPokemon {
     pub pokemon: StarryPokemonData,
+    pub specie: Option<StarryPokemonSpecie>,
     pub sprite_path: Option<String>,
-    pub encounter_info: Option<Vec<StarryPokemonEncounterInfo>>,
+}
+
+/// Heavy data of a Pokémon, stored apart from the Pokémon list and only loaded when the
+/// details of the Pokémon are shown
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StarryPokemonDetails {
+    pub encounter_info: Vec<StarryPokemonEncounterInfo>,
+    pub held_items: Vec<StarryPokemonHeldItem>,
+    /// Latest Pokédex entry for each language, keyed by the PokéApi language code
+    pub flavor_texts: BTreeMap<String, String>,
+    /// Every stage of the evolution chain the Pokémon is in, parents before their evolutions
+    #[serde(default)]
+    pub evolution_chain: Vec<StarryEvolutionStage>,
+}
+
+/// A stage of an evolution chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryEvolutionStage {
+    /// Id of the specie, which is also the id of its default Pokémon
+    pub species_id: i64,
+    pub species: String,
+    /// Specie this one evolves from, none for the first stage
+    pub evolves_from: Option<String>,
+    /// How many evolutions away from the first stage it is
+    pub depth: usize,
+    /// Conditions the previous stage needs to evolve into this one, one group for each way it can
+    /// evolve (any of them is enough, every condition of a group is needed)
+    #[serde(default)]
+    pub needs_to_evolve: Vec<Vec<EvolutionRequirement>>,
+    /// Ids of the items that have to be used or held to evolve into this stage
+    #[serde(default)]
+    pub item_ids: Vec<i64>,
+}
+
+impl StarryEvolutionStage {
+    /// Whether this stage is the given Pokémon, or the specie of one of its forms
+    pub fn is_pokemon(&self, pokemon: &StarryPokemonData) -> bool {
+        self.species_id == pokemon.id
+            || (pokemon.id > 10000 && pokemon.name.starts_with(&format!("{}-", self.species)))
+    }
+
+    /// Localized conditions of each way to evolve into this stage, one string per way
+    pub fn requirement_labels(&self) -> Vec<String> {
+        self.needs_to_evolve
+            .iter()
+            .map(|requirements| {
+                requirements
+                    .iter()
+                    .map(EvolutionRequirement::label)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect()
+    }
+}
+
+/// A condition to evolve, the items, moves, places and Pokémon are named as in PokéApi
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvolutionRequirement {
+    Level(i64),
+    LevelUp,
+    /// A trigger other than leveling up or using an item ("trade", "shed")
+    Trigger(String),
+    UseItem(String),
+    HoldItem(String),
+    KnowMove(String),
+    KnowMoveType(String),
+    Friendship,
+    Affection,
+    Beauty,
+    Location(String),
+    TimeOfDay(String),
+    Female,
+    Male,
+    PartySpecies(String),
+    PartyType(String),
+    TradeFor(String),
+    AttackHigher,
+    AttackEqualsDefense,
+    DefenseHigher,
+    Rain,
+    UpsideDown,
+}
+
+impl EvolutionRequirement {
+    /// Localized description of the condition
+    pub fn label(&self) -> String {
+        match self {
+            EvolutionRequirement::Level(level) => fl!("evolution-level", level = *level),
+            EvolutionRequirement::LevelUp => fl!("evolution-level-up"),
+            EvolutionRequirement::Trigger(trigger) => fl!(
+                "evolution-trigger",
+                trigger = trigger.as_str(),
+                name = capitalize_string(trigger)
+            ),
+            EvolutionRequirement::UseItem(item) => {
+                fl!("evolution-use-item", item = capitalize_string(item))
+            }
+            EvolutionRequirement::HoldItem(item) => {
+                fl!("evolution-hold-item", item = capitalize_string(item))
+            }
+            EvolutionRequirement::KnowMove(known_move) => {
+                fl!(
+                    "evolution-know-move",
+                    move_name = capitalize_string(known_move)
+                )
+            }
+            EvolutionRequirement::KnowMoveType(type_name) => fl!(
+                "evolution-know-move-type",
+                type_name = crate::i18n::type_label(type_name)
+            ),
+            EvolutionRequirement::Friendship => fl!("evolution-friendship"),
+            EvolutionRequirement::Affection => fl!("evolution-affection"),
+            EvolutionRequirement::Beauty => fl!("evolution-beauty"),
+            EvolutionRequirement::Location(location) => {
+                fl!("evolution-location", location = capitalize_string(location))
+            }
+            EvolutionRequirement::TimeOfDay(time) => fl!(
+                "evolution-time-of-day",
+                time = time.as_str(),
+                name = capitalize_string(time)
+            ),
+            EvolutionRequirement::Female => fl!("evolution-female"),
+            EvolutionRequirement::Male => fl!("evolution-male"),
+            EvolutionRequirement::PartySpecies(species) => {
+                fl!(
+                    "evolution-party-species",
+                    pokemon = capitalize_string(species)
+                )
+            }
+            EvolutionRequirement::PartyType(type_name) => fl!(
+                "evolution-party-type",
+                type_name = crate::i18n::type_label(type_name)
+            ),
+            EvolutionRequirement::TradeFor(species) => {
+                fl!("evolution-trade-for", pokemon = capitalize_string(species))
+            }
+            EvolutionRequirement::AttackHigher => fl!("evolution-attack-higher"),
+            EvolutionRequirement::AttackEqualsDefense => fl!("evolution-attack-equals-defense"),
+            EvolutionRequirement::DefenseHigher => fl!("evolution-defense-higher"),
+            EvolutionRequirement::Rain => fl!("evolution-rain"),
+            EvolutionRequirement::UpsideDown => fl!("evolution-upside-down"),
+        }
+    }
+}
+
+impl StarryPokemonDetails {
+    /// Pokédex entry of the Pokémon in the language in use
+    pub fn localized_flavor_text(&self) -> Option<&str> {
+        crate::i18n::pick_localized(&self.flavor_texts)
+    }
+}
+
+impl StarryPokemon {
+    /// Name of the Pokémon in the language in use, if it's available
+    pub fn localized_name(&self) -> Option<&str> {
+        crate::i18n::pick_localized(self.specie.as_ref()?.names.as_ref()?)
+    }
+
+    /// Name of the Pokémon to show on the interface
+    pub fn display_name(&self) -> String {
+        match self.localized_name() {
+            Some(name) => name.to_string(),
+            None => capitalize_string(&self.pokemon.name),
+        }
+    }
 }
 
 /// Data of a Pokémon
@@ -94,6 +628,39 @@ pub struct StarryPokemonData {
     pub types: Vec<String>,
     pub abilities: Vec<String>,
     pub stats: StarryPokemonStats,
+    pub ev_yield: Option<StarryPokemonStats>,
+    pub base_experience: Option<i64>,
+    /// Games (as PokéApi names their versions) in which the Pokémon can be found in the wild
+    pub available_games: Option<Vec<String>>,
+}
+
+/// Data of a Pokémon Specie
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryPokemonSpecie {
+    pub genus: Option<String>,
+    pub capture_rate: i64,
+    pub base_happiness: Option<i64>,
+    pub growth_rate: String,
+    pub gender_rate: Option<i64>,
+    pub hatch_counter: Option<i64>,
+    /// Generation the specie was introduced in, as PokéApi names it (e.g. "generation-iii")
+    pub generation: Option<String>,
+    /// Egg groups of the specie, as PokéApi names them
+    pub egg_groups: Option<Vec<String>>,
+    /// Name of the specie for each language, keyed by the PokéApi language code
+    pub names: Option<BTreeMap<String, String>>,
+    /// Genus of the specie for each language, keyed by the PokéApi language code
+    pub genera: Option<BTreeMap<String, String>>,
+}
+
+impl StarryPokemonSpecie {
+    /// Genus of the specie in the language in use
+    pub fn localized_genus(&self) -> Option<&str> {
+        match &self.genera {
+            Some(genera) => crate::i18n::pick_localized(genera),
+            None => self.genus.as_deref(),
+        }
+    }
 }
 
 /// Represents a Pokémon
@@ -113,15 +680,125 @@ pub struct StarryPokemonEncounterInfo {
     pub games_method: Vec<String>,
 }
 
+impl StarryPokemonEncounterInfo {
+    /// Encounter methods of each game the Pokémon can be found in on this location
+    pub fn methods_by_game(&self) -> impl Iterator<Item = (&str, Vec<&str>)> {
+        self.games_method.iter().filter_map(|game_method| {
+            game_method
+                .split_once(": ")
+                .map(|(game, methods)| (game, methods.split(", ").collect()))
+        })
+    }
+}
+
+/// An item a wild Pokémon may be holding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryPokemonHeldItem {
+    pub item: String,
+    pub version_details: Vec<StarryPokemonHeldItemVersion>,
+}
+
+/// Chance of a wild Pokémon holding an item in a game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryPokemonHeldItemVersion {
+    pub version: String,
+    pub rarity: i64,
+}
+
+/// Represents a Pokémon move in the application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryMove {
+    pub id: i64,
+    pub name: String,
+    pub type_name: String,
+    pub damage_class: String,
+    pub power: Option<i64>,
+    pub accuracy: Option<i64>,
+    pub pp: Option<i64>,
+    pub effect: Option<String>,
+    pub learned_by_pokemon: Vec<String>,
+}
+
+/// Represents an item in the application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryItem {
+    pub id: i64,
+    pub name: String,
+    pub category: String,
+    pub cost: i64,
+    pub effect: Option<String>,
+    pub sprite_path: Option<String>,
+}
+
+/// Represents a Pokémon ability in the application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryAbility {
+    pub id: i64,
+    pub name: String,
+    pub effect: Option<String>,
+}
+
+/// Represents a berry in the application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarryBerry {
+    pub id: i64,
+    pub name: String,
+    pub firmness: String,
+    /// Hours the berry takes to grow a stage (it has 4 stages)
+    pub growth_time: i64,
+    pub max_harvest: i64,
+    pub natural_gift_type: String,
+    pub natural_gift_power: i64,
+    /// Potency of each flavor, keyed by the flavor name
+    pub flavors: Vec<(String, i64)>,
+}
+
+/// A Pokémon that can be found on a location
+#[derive(Debug, Clone)]
+pub struct StarryLocationEncounter {
+    pub pokemon_id: i64,
+    /// Games and encounter methods, as "Game: Method, Method"
+    pub games_method: Vec<String>,
+}
+
+impl StarryLocationEncounter {
+    /// Games in which the Pokémon can be found on the location
+    pub fn games(&self) -> impl Iterator<Item = &str> {
+        self.games_method
+            .iter()
+            .filter_map(|game_method| game_method.split_once(": ").map(|(game, _)| game))
+    }
+}
+
 pub struct Filters {
     pub selected_types: HashSet<String>,
 }
 
+/// Data that is only loaded the first time it's needed.
+pub enum LazyData<T> {
+    NotLoaded,
+    Loading,
+    Loaded(T),
+}
+
+/// Status of a refresh of the Pokémon data from PokéApi
+#[derive(Debug, Clone)]
+pub enum DataRefresh {
+    Fetching {
+        fetched: usize,
+        total: usize,
+    },
+    /// Number of new and renamed Pokémon, or why the refresh failed
+    Finished(Result<(usize, usize), String>),
+}
+
 /// Identifies the status of a page in the application.
 pub enum PageStatus {
     FirstRun,
     Loaded,
     Loading,
+    /// The Pokémon list couldn't be loaded, and why
+    Failed(String),
 }
 
 /// Create a COSMIC application from the app model
@@ -130,7 +807,7 @@ impl Application for StarryDex {
     type Executor = cosmic::executor::Default;
 
     /// Data that your application receives to its init method.
-    type Flags = ();
+    type Flags = Flags;
 
     /// Messages which the application and its widgets will emit.
     type Message = Message;
@@ -147,15 +824,12 @@ impl Application for StarryDex {
     }
 
     /// Initializes the application with any given flags and startup tasks.
-    fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
+    fn init(core: Core, flags: Self::Flags) -> (Self, Task<Self::Message>) {
         // Tasks that will get executed on the application init
         let mut tasks = vec![];
 
-        // Controls if it's the first time the application runs on a system
-        let mut first_run_completed = false;
-
         // Application about page
-        let about = About::default()
+        let mut about = About::default()
             .name(fl!("app-title"))
             .icon(Self::APP_ID)
             .version(env!("CARGO_PKG_VERSION"))
@@ -169,20 +843,25 @@ impl Application for StarryDex {
                 ),
             ])
             .developers([("mariinkys", "kysdev.owjga@aleeas.com")]);
+        if let Some(warning) = crate::i18n::localization_warning() {
+            about = about.comments(warning);
+        }
 
         // Construct the app model with the runtime's core.
+        let config_handler = cosmic_config::Config::new(Self::APP_ID, Config::VERSION).ok();
+
         let mut app = StarryDex {
             core,
             about,
             context_page: ContextPage::default(),
             key_binds: HashMap::new(),
             // Optional configuration file for an application.
-            config: cosmic_config::Config::new(Self::APP_ID, Config::VERSION)
-                .map(|context| match Config::get_entry(&context) {
-                    Ok(config) => {
-                        first_run_completed = config.first_run_completed;
-                        config
-                    }
+            config: config_handler
+                .as_ref()
+                .map(|context| match Config::get_entry(context) {
+                    Ok(config) => config,
+                    // A config saved by an older version lacks the fields added since, they get
+                    // their default and every field it has is kept
                     Err((_errors, config)) => {
                         // for why in errors {
                         //     tracing::error!(%why, "error loading app config");
@@ -192,70 +871,242 @@ impl Application for StarryDex {
                     }
                 })
                 .unwrap_or_default(),
+            config_handler,
             app_themes: vec![fl!("match-desktop"), fl!("dark"), fl!("light")],
-            api: Api::new(Self::APP_ID),
+            api: Api::new(),
             current_page_status: PageStatus::Loading,
             pokemon_list: BTreeMap::new(),
             filtered_pokemon_list: Vec::new(),
             selected_pokemon: None,
+            loading_pokemon: None,
+            selected_pokemon_details: None,
             wants_pokemon_details: false,
+            sort_mode: SortMode::default(),
+            sort_mode_options: vec![fl!("sort-number"), fl!("sort-generation"), fl!("sort-type")],
+            view_mode: ViewMode::default(),
+            view_mode_options: vec![fl!("view-grid"), fl!("view-table")],
+            jump_generation_options: GENERATIONS
+                .iter()
+                .map(|g| crate::i18n::generation_label(g))
+                .collect(),
+            jumped_generation: None,
+            grid_window: 0..INFINITE_SCROLL_PAGE_ROWS * 2,
+            grid_viewport: (0.0, 0.0),
+            table_sort: (TableColumn::Number, false),
             search: String::new(),
+            show_search_help: false,
             filters: Filters {
                 selected_types: HashSet::new(),
             },
             type_filter_mode: vec![fl!("exclusive"), fl!("inclusive")],
+            keyboard_schemes: vec![fl!("keyboard-scheme-default"), fl!("keyboard-scheme-vim")],
+            vim_pending_g: false,
+            cache_build: None,
+            skeleton_frame: 0,
+            preloading_sprites: false,
+            freed_old_caches: 0,
+            storage_usage: Vec::new(),
+            data_dir_input: String::new(),
+            moving_data_dir: false,
+            data_dir_error: None,
+            backup_path_input: String::new(),
+            backup_status: None,
+            sprite_repair: None,
+            data_refresh: None,
+            missing_sprites: HashSet::new(),
+            sprite_set_options: SpriteSet::ALL.iter().map(SpriteSet::label).collect(),
+            card_size_options: vec![
+                fl!("card-size-small"),
+                fl!("card-size-medium"),
+                fl!("card-size-large"),
+            ],
+            measurement_units_options: vec![
+                fl!("measurement-units-metric"),
+                fl!("measurement-units-imperial"),
+                fl!("measurement-units-both"),
+            ],
+            color_palette_options: vec![
+                fl!("color-palette-default"),
+                fl!("color-palette-color-blind"),
+                fl!("color-palette-high-contrast"),
+            ],
+            set_sprites: HashMap::new(),
+            sprite_overrides: HashMap::new(),
+            data_packs: crate::data_packs::load_data_packs(),
+            new_alias: String::new(),
+            new_alias_pokemon: String::new(),
+            pending_session: crate::session::load_unclean_session(),
+            crash_report: crate::crash_report::take_crash_report(),
+            saved_session: Session::default(),
+            dex_mode: DexMode::default(),
+            moves_list: LazyData::NotLoaded,
+            moves_search: String::new(),
+            type_filter_options: std::iter::once(fl!("all"))
+                .chain(ALL_TYPES.iter().map(|t| crate::i18n::type_label(t)))
+                .collect(),
+            moves_type_filter: 0,
+            damage_class_filter_options: std::iter::once(fl!("all"))
+                .chain(DAMAGE_CLASSES.iter().map(|c| capitalize_string(c)))
+                .collect(),
+            moves_damage_class_filter: 0,
+            selected_move: None,
+            items_list: LazyData::NotLoaded,
+            items_search: String::new(),
+            item_category_options: vec![fl!("all")],
+            items_category_filter: 0,
+            selected_item: None,
+            abilities_list: LazyData::NotLoaded,
+            abilities_search: String::new(),
+            ability_index: HashMap::new(),
+            egg_group_index: BTreeMap::new(),
+            type_index: HashMap::new(),
+            generation_index: HashMap::new(),
+            search_index: None,
+            selected_egg_group: None,
+            location_index: LazyData::NotLoaded,
+            evolution_item_index: LazyData::NotLoaded,
+            locations_search: String::new(),
+            location_game_options: vec![fl!("all")],
+            locations_game_filter: 0,
+            selected_location: None,
+            berries_list: LazyData::NotLoaded,
+            selected_nature: None,
+            ranked_stat_options: RankedStat::ALL.iter().map(|s| s.label()).collect(),
+            ranked_stat: 0,
+            generation_filter_options: std::iter::once(fl!("all"))
+                .chain(GENERATIONS.iter().map(|g| crate::i18n::generation_label(g)))
+                .collect(),
+            rankings_generation_filter: 0,
+            rankings_type_filter: 0,
+            stat_percentiles: StatPercentiles::new(),
+            challenge_history: crate::challenges::load_history(),
+            trivia: TriviaGame::default(),
+            dialog_page: None,
+            goto_number: String::new(),
+            grid_selection: None,
+            capturing_key_bind: None,
+            key_bind_conflict: None,
+            open_request: flags.open_request,
+            palette_query: String::new(),
+            palette_selected: 0,
+            wants_stat_calculator: false,
+            stat_calculator: StatCalculator::default(),
+            nature_options: std::iter::once(String::from("-"))
+                .chain(NATURES.iter().map(|nature| capitalize_string(nature.name)))
+                .collect(),
+            hovered_type_matchup: None,
+            watchlist_snapshot: None,
+            watchlist_changes: Vec::new(),
         };
+        app.key_binds = key_binds(&app.config.custom_key_binds);
+        ImageCache::set_sprite_cache_limit(app.config.sprite_cache_mb);
+
         // Startup task that sets the window title.
         tasks.push(app.update_title());
 
         // Create the directory where all of our application data will exist
-        let app_data_dir = dirs::data_dir().unwrap().join(Self::APP_ID);
+        let app_data_dir = app_data_dir();
         std::fs::create_dir_all(&app_data_dir).expect("Failed to create the app data directory");
 
-        // Clone the app api in order to use it.
+        // Caches of older versions are never read again
         let api_clone = app.api.clone();
+        tasks.push(cosmic::app::Task::perform(
+            async move { api_clone.remove_superseded_caches().await },
+            |freed| cosmic::app::message::app(Message::RemovedOldCaches(freed)),
+        ));
 
-        if !first_run_completed {
+        // Controls if it's the first time the application runs on a system
+        if !app.config.first_run_completed {
             // First application run, construct cache, download sprites and update the config
             app.current_page_status = PageStatus::FirstRun;
-            tasks.push(cosmic::app::Task::perform(
-                async move { api_clone.load_all_pokemon().await },
-                |pokemon_list| {
-                    cosmic::app::message::app(Message::CompletedFirstRun(
-                        Config {
-                            app_theme: crate::config::AppTheme::System,
-                            first_run_completed: true,
-                            pokemon_per_row: 3,
-                            type_filtering_mode: crate::config::TypeFilteringMode::Exclusive,
-                        },
-                        pokemon_list,
-                    ))
-                },
-            ));
+            tasks.push(app.load_pokemon_list(true));
         } else {
             // Load  the Pokémon List
             app.current_page_status = PageStatus::Loading;
-            tasks.push(cosmic::app::Task::perform(
-                async move { api_clone.load_all_pokemon().await },
-                |pokemon_list| cosmic::app::message::app(Message::LoadedPokemonList(pokemon_list)),
-            ));
+            tasks.push(app.load_pokemon_list(false));
         }
 
         (app, Task::batch(tasks))
     }
 
+    /// Handles the activations of another StarryDex launch or program through D-Bus, raising the
+    /// window of this instance.
+    fn dbus_activation(&mut self, msg: cosmic::dbus_activation::Message) -> Task<Self::Message> {
+        let cosmic::dbus_activation::Details::ActivateAction { action, args } = msg.msg else {
+            return self.raise_window();
+        };
+
+        match crate::cli::parse_activation(&action, &args) {
+            Some(request) => self.handle_activation(request),
+            None => self.raise_window(),
+        }
+    }
+
+    /// Serves the dev.mariinkys.StarryDex interface on the connection owning the name of the app,
+    /// next to the activation of the single instance.
+    fn dbus_connection(&mut self, conn: zbus::Connection) -> Task<Self::Message> {
+        cosmic::app::Task::run(crate::dbus::serve_requests(conn), |request| {
+            cosmic::app::message::app(Message::DbusRequest(request))
+        })
+    }
+
     /// Elements to pack at the start of the header bar.
     fn header_start(&self) -> Vec<Element<Self::Message>> {
-        let menu_bar = menu::bar(vec![menu::Tree::with_children(
-            menu::root(fl!("view")),
-            menu::items(
-                &self.key_binds,
-                vec![
-                    menu::Item::Button(fl!("about"), None, MenuAction::About),
-                    menu::Item::Button(fl!("settings"), None, MenuAction::Settings),
-                ],
+        let menu_bar = menu::bar(vec![
+            menu::Tree::with_children(
+                menu::root(fl!("file")),
+                menu::items(
+                    &self.key_binds,
+                    vec![
+                        menu::Item::Button(fl!("export-list-csv"), None, MenuAction::ExportCsv),
+                        menu::Item::Button(fl!("export-list-json"), None, MenuAction::ExportJson),
+                    ],
+                ),
+            ),
+            menu::Tree::with_children(
+                menu::root(fl!("view")),
+                menu::items(
+                    &self.key_binds,
+                    vec![
+                        menu::Item::Button(fl!("pokemon-dex"), None, MenuAction::PokemonDex),
+                        menu::Item::Button(fl!("moves-dex"), None, MenuAction::MovesDex),
+                        menu::Item::Button(fl!("items-dex"), None, MenuAction::ItemsDex),
+                        menu::Item::Button(fl!("abilities-dex"), None, MenuAction::AbilitiesDex),
+                        menu::Item::Button(fl!("locations-dex"), None, MenuAction::LocationsDex),
+                        menu::Item::Button(
+                            fl!("weekly-challenge"),
+                            None,
+                            MenuAction::WeeklyChallenge,
+                        ),
+                        menu::Item::Button(fl!("stat-trivia"), None, MenuAction::Trivia),
+                        menu::Item::Divider,
+                        menu::Item::Button(
+                            fl!("command-palette"),
+                            None,
+                            MenuAction::CommandPalette,
+                        ),
+                        menu::Item::Button(fl!("go-to-number"), None, MenuAction::GoToNumber),
+                        menu::Item::Button(fl!("focus-search"), None, MenuAction::FocusSearch),
+                        menu::Item::Button(fl!("about"), None, MenuAction::About),
+                        menu::Item::Button(fl!("settings"), None, MenuAction::Settings),
+                        menu::Item::Button(fl!("report-problem"), None, MenuAction::ReportProblem),
+                    ],
+                ),
+            ),
+            menu::Tree::with_children(
+                menu::root(fl!("reference")),
+                menu::items(
+                    &self.key_binds,
+                    vec![
+                        menu::Item::Button(fl!("berries"), None, MenuAction::Berries),
+                        menu::Item::Button(fl!("natures"), None, MenuAction::Natures),
+                        menu::Item::Button(fl!("type-chart"), None, MenuAction::TypeChart),
+                        menu::Item::Button(fl!("egg-groups"), None, MenuAction::EggGroups),
+                        menu::Item::Button(fl!("rankings"), None, MenuAction::Rankings),
+                    ],
+                ),
             ),
-        )]);
+        ]);
 
         vec![menu_bar.into()]
     }
@@ -288,6 +1139,21 @@ impl Application for StarryDex {
                 Message::ToggleContextPage(ContextPage::FiltersPage),
             )
             .title(fl!("filters-page")),
+            ContextPage::MovePage => context_drawer::context_drawer(
+                self.single_move_page(),
+                Message::ToggleContextPage(ContextPage::MovePage),
+            )
+            .title(fl!("move-page")),
+            ContextPage::ItemPage => context_drawer::context_drawer(
+                self.single_item_page(),
+                Message::ToggleContextPage(ContextPage::ItemPage),
+            )
+            .title(fl!("item-page")),
+            ContextPage::WatchlistChanges => context_drawer::context_drawer(
+                self.watchlist_changes_page(),
+                Message::ToggleContextPage(ContextPage::WatchlistChanges),
+            )
+            .title(fl!("watchlist-changes")),
         })
     }
 
@@ -298,26 +1164,41 @@ impl Application for StarryDex {
     fn view(&self) -> Element<Self::Message> {
         let space_s = theme::active().cosmic().spacing.space_s;
 
-        let content = match self.current_page_status {
+        let content = match &self.current_page_status {
             PageStatus::FirstRun => Column::new()
                 //.push(widget::text::text(fl!("downloading-sprites")))
                 //.push(widget::text::text(fl!("estimate")))
                 //.push(widget::text::text(fl!("once-message")))
                 // TODO: This is temporal because settings do not get saved and are lost upon app restart.
-                .push(widget::text::text("Loading..."))
+                .push(self.cache_build_progress())
                 .push(widget::text::text("First load may take a minute"))
                 .push(widget::text::text("It will go faster after the first load"))
                 .align_x(Alignment::Center)
                 .width(Length::Fill)
                 .spacing(space_s)
                 .into(),
-            PageStatus::Loaded => self.landing(),
+            PageStatus::Loaded => match self.dex_mode {
+                DexMode::Pokemon => self.landing(),
+                DexMode::Moves => self.moves_page(),
+                DexMode::Items => self.items_page(),
+                DexMode::Abilities => self.abilities_page(),
+                DexMode::Berries => self.berries_page(),
+                DexMode::Locations => self.locations_page(),
+                DexMode::Natures => self.natures_page(),
+                DexMode::TypeChart => self.type_chart_page(),
+                DexMode::EggGroups => self.egg_groups_page(),
+                DexMode::Rankings => self.rankings_page(),
+                DexMode::WeeklyChallenge => self.weekly_challenge_page(),
+                DexMode::Trivia => self.trivia_page(),
+            },
+            PageStatus::Loading if self.showing_skeletons() => self.skeleton_grid(),
             PageStatus::Loading => Column::new()
-                .push(widget::text::text(fl!("loading")))
+                .push(self.cache_build_progress())
                 .align_x(Alignment::Center)
                 .width(Length::Fill)
                 .spacing(space_s)
                 .into(),
+            PageStatus::Failed(error) => self.loading_failed(error),
         };
 
         widget::container(content)
@@ -328,13 +1209,41 @@ impl Application for StarryDex {
             .into()
     }
 
+    /// Display the command palette as a dialog when it's open.
+    fn dialog(&self) -> Option<Element<Self::Message>> {
+        match self.dialog_page? {
+            DialogPage::CommandPalette => Some(self.command_palette()),
+            DialogPage::GoToNumber => Some(self.goto_number_dialog()),
+            DialogPage::SpriteViewer => self.sprite_viewer_dialog(),
+        }
+    }
+
+    /// Called before the application exits.
+    fn on_app_exit(&mut self) -> Option<Self::Message> {
+        // Stop any cache construction, it will be resumed on the next run
+        self.api.cancel();
+        crate::session::clear_session();
+        None
+    }
+
     /// Register subscriptions for this application.
     ///
     /// Subscriptions are long-running async tasks running in the background which
     /// emit messages to the application through a channel. They are started at the
     /// beginning of the application, and persist through its lifetime.
     fn subscription(&self) -> Subscription<Self::Message> {
+        // Moves the shimmer of the placeholder cards while they are shown, unless motion is reduced
+        let skeleton_tick = if self.showing_skeletons() && !self.config.reduce_motion {
+            cosmic::iced::time::every(SKELETON_TICK).map(|_| Message::SkeletonTick)
+        } else {
+            Subscription::none()
+        };
+
         Subscription::batch(vec![
+            skeleton_tick,
+            // Decode in the background the sprites the view asked for before they were decoded
+            Subscription::run(crate::image_cache::queued_sprites)
+                .map(|()| Message::PreloadQueuedSprites),
             // Watch for application configuration changes.
             self.core()
                 .watch_config::<Config>(Self::APP_ID)
@@ -345,6 +1254,22 @@ impl Application for StarryDex {
 
                     Message::UpdateConfig(update.config)
                 }),
+            // Periodically save the session, to restore it if the app crashes
+            cosmic::iced::time::every(Duration::from_secs(15)).map(|_| Message::SaveSession),
+            // Key presses for the key binds, Escape is always wanted to close the command palette
+            cosmic::iced::event::listen_with(|event, status, _window| match event {
+                cosmic::iced::Event::Keyboard(cosmic::iced::keyboard::Event::KeyPressed {
+                    key,
+                    modifiers,
+                    ..
+                }) => match (status, &key) {
+                    (cosmic::iced::event::Status::Ignored, _) | (_, Key::Named(Named::Escape)) => {
+                        Some(Message::Key(modifiers, key))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }),
         ])
     }
 
@@ -353,10 +1278,102 @@ impl Application for StarryDex {
     /// Tasks may be returned for asynchronous execution of code in the background
     /// on the application's async runtime.
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
+        let old_config = self.config.clone();
+        let task = self.update_state(message);
+
+        // Every change of the settings is saved as it's made
+        if self.config != old_config {
+            self.save_config();
+        }
+        task
+    }
+}
+
+impl StarryDex {
+    /// Applies a message to the state of the application, see `update`.
+    fn update_state(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::LaunchUrl(url) => {
                 _ = open::that_detached(url);
             }
+            Message::ReportProblem => {
+                let diagnostics = crate::diagnostics::Diagnostics {
+                    cache_files: self.api.cache_files(),
+                    pokemon_count: self.pokemon_list.len(),
+                };
+                _ = open::that_detached(diagnostics.issue_url());
+            }
+            Message::ExportPokemonDetails => {
+                if let (Some(starry_pokemon), Some(details)) =
+                    (&self.selected_pokemon, &self.selected_pokemon_details)
+                {
+                    let moves = match &self.moves_list {
+                        LazyData::Loaded(moves) => Some(moves.as_slice()),
+                        _ => None,
+                    };
+                    let contents = crate::export::render_pokemon_details(
+                        starry_pokemon,
+                        details,
+                        moves,
+                        self.config.measurement_units,
+                    );
+                    let file_name = starry_pokemon.pokemon.name.clone();
+
+                    return cosmic::app::Task::perform(
+                        crate::export::export_pokemon_details(file_name, contents),
+                        |result| cosmic::app::message::app(Message::ExportedPokemonDetails(result)),
+                    );
+                }
+            }
+            Message::ExportedPokemonDetails(result) => match result {
+                // Open the exported page so it can be printed from the browser
+                Ok(path) => {
+                    _ = open::that_detached(path);
+                }
+                Err(e) => eprintln!("Error exporting Pokémon details: {}", e),
+            },
+            Message::ExportPokemonList(format) => {
+                let contents =
+                    crate::export::render_pokemon_list(&self.filtered_pokemon_list, format);
+                return cosmic::app::Task::perform(
+                    crate::export::export_pokemon_list(contents, format),
+                    |result| cosmic::app::message::app(Message::ExportedPokemonList(result)),
+                );
+            }
+            Message::ExportedPokemonList(result) => match result {
+                // Show the exported file in its folder
+                Ok(path) => {
+                    if let Some(export_dir) = path.parent() {
+                        _ = open::that_detached(export_dir);
+                    }
+                }
+                Err(e) => eprintln!("Error exporting the Pokémon list: {}", e),
+            },
+            Message::SaveShareCard => {
+                if let Some(starry_pokemon) = &self.selected_pokemon {
+                    return cosmic::app::Task::perform(
+                        crate::share_card::save_share_card(starry_pokemon.clone()),
+                        |result| cosmic::app::message::app(Message::SavedShareCard(result)),
+                    );
+                }
+            }
+            Message::SavedShareCard(result) => match result {
+                Ok(path) => {
+                    _ = open::that_detached(path);
+                }
+                Err(e) => eprintln!("Error saving the share card: {}", e),
+            },
+            Message::CopyShowdownSet => {
+                if let Some(starry_pokemon) = &self.selected_pokemon {
+                    let nature = self.selected_nature.and_then(|index| NATURES.get(index));
+                    let set = crate::export::render_showdown_set(
+                        starry_pokemon,
+                        &self.stat_calculator,
+                        nature,
+                    );
+                    return cosmic::iced::clipboard::write(set);
+                }
+            }
             Message::ToggleContextPage(context_page) => {
                 if self.context_page == context_page {
                     // Close the context drawer if the toggled context page is the same.
@@ -366,73 +1383,274 @@ impl Application for StarryDex {
                     self.context_page = context_page;
                     self.core.window.show_context = true;
                 }
+
+                if self.context_page == ContextPage::Settings && self.core.window.show_context {
+                    return self.update(Message::LoadStorageUsage);
+                }
             }
             Message::UpdateConfig(config) => {
                 self.config = config;
+                self.key_binds = key_binds(&self.config.custom_key_binds);
+                ImageCache::set_sprite_cache_limit(self.config.sprite_cache_mb);
                 return cosmic::app::command::set_theme(self.config.app_theme.theme());
             }
             Message::UpdateTheme(index) => {
-                let old_config = self.config.clone();
-
-                let app_theme = match index {
+                self.config.app_theme = match index {
                     1 => AppTheme::Dark,
                     2 => AppTheme::Light,
                     _ => AppTheme::System,
                 };
-                self.config = Config {
-                    first_run_completed: old_config.first_run_completed,
-                    pokemon_per_row: old_config.pokemon_per_row,
-                    type_filtering_mode: old_config.type_filtering_mode,
-                    app_theme,
-                };
                 return cosmic::app::command::set_theme(self.config.app_theme.theme());
             }
             Message::CompletedFirstRun(config, pokemon_list) => {
                 self.config = config;
+                self.key_binds = key_binds(&self.config.custom_key_binds);
+                ImageCache::set_sprite_cache_limit(self.config.sprite_cache_mb);
 
                 self.pokemon_list = pokemon_list;
                 //TODO: Remove this. This is to temporarly fixed an error that makes a empty pokemon to appear on the first position of the btree
                 //let mut pokemon_list = pokemon_list;
                 //pokemon_list.pop_first();
                 //self.pokemon_list = pokemon_list;
+                self.merge_data_packs();
 
                 self.filtered_pokemon_list = self.pokemon_list.values().cloned().collect();
+                self.ability_index = build_ability_index(&self.pokemon_list);
+                self.egg_group_index = build_egg_group_index(&self.pokemon_list);
+                self.type_index = build_type_index(&self.pokemon_list);
+                self.generation_index = build_generation_index(&self.pokemon_list);
+                self.stat_percentiles = rankings::stat_percentiles(&self.pokemon_list);
                 self.current_page_status = PageStatus::Loaded;
 
-                return cosmic::app::command::set_theme(self.config.app_theme.theme());
+                return Task::batch([
+                    cosmic::app::command::set_theme(self.config.app_theme.theme()),
+                    self.open_requested_pokemon(),
+                    self.update(Message::FetchMissingSprites),
+                    self.update(Message::LoadSpriteSet),
+                    self.update(Message::LoadSpriteOverrides),
+                    self.update(Message::LoadSearchIndex),
+                ]);
             }
             Message::LoadedPokemonList(pokemon_list) => {
-                //self.pokemon_list = pokemon_list; //TODO: This is to temporarly fix an error that makes a empty pokemon to appear on the first position of the btree
+                // The Pokémon that couldn't be fetched are kept as an empty entry under id 0
                 let mut pokemon_list = pokemon_list;
-                pokemon_list.pop_first();
+                pokemon_list.remove(&0);
                 self.pokemon_list = pokemon_list;
+                self.merge_data_packs();
 
                 self.filtered_pokemon_list = self.pokemon_list.values().cloned().collect();
+                self.ability_index = build_ability_index(&self.pokemon_list);
+                self.egg_group_index = build_egg_group_index(&self.pokemon_list);
+                self.type_index = build_type_index(&self.pokemon_list);
+                self.generation_index = build_generation_index(&self.pokemon_list);
+                self.stat_percentiles = rankings::stat_percentiles(&self.pokemon_list);
                 self.current_page_status = PageStatus::Loaded;
+                let fetch_sprites = Task::batch([
+                    self.update(Message::FetchMissingSprites),
+                    self.update(Message::LoadSpriteSet),
+                    self.update(Message::LoadSpriteOverrides),
+                    self.update(Message::LoadSearchIndex),
+                ]);
+
+                // Compare the watched Pokémon with the snapshot taken before the renewal
+                if self.watchlist_snapshot.is_some() {
+                    let api_clone = self.api.clone();
+                    let watchlist = self.config.watchlist.clone();
+                    let pokemon_list = self.pokemon_list.clone();
+                    return Task::batch([
+                        fetch_sprites,
+                        cosmic::app::Task::perform(
+                            crate::watchlist::snapshot(api_clone, watchlist, pokemon_list),
+                            |snapshot| {
+                                cosmic::app::message::app(Message::ComparedWatchlist(snapshot))
+                            },
+                        ),
+                    ]);
+                }
+
+                return Task::batch([fetch_sprites, self.open_requested_pokemon()]);
             }
             Message::LoadPokemon(pokemon_id) => {
-                self.selected_pokemon = self.pokemon_list.get(&pokemon_id).cloned();
+                self.grid_selection = Some(pokemon_id);
 
                 // Open Context Page
                 self.context_page = ContextPage::PokemonPage;
                 self.core.window.show_context = true;
+
+                // Ignore repeated clicks on the Pokémon that is already loading or shown
+                let already_selected = self
+                    .selected_pokemon
+                    .as_ref()
+                    .is_some_and(|p| p.pokemon.id == pokemon_id);
+                if self.loading_pokemon == Some(pokemon_id)
+                    || (self.loading_pokemon.is_none() && already_selected)
+                {
+                    return Task::none();
+                }
+
+                self.loading_pokemon = Some(pokemon_id);
+
+                // The Pokémon of data packs aren't in the cache, only in the Pokémon list
+                if pokemon_id >= DATA_PACK_ID_START {
+                    let pokemon = self.pokemon_list.get(&pokemon_id).cloned();
+                    return self.update(Message::LoadedPokemon(pokemon_id, pokemon));
+                }
+
+                let api_clone = self.api.clone();
+                return cosmic::app::Task::perform(
+                    async move { api_clone.get_pokemon(pokemon_id).await },
+                    move |pokemon| {
+                        cosmic::app::message::app(Message::LoadedPokemon(pokemon_id, pokemon))
+                    },
+                );
+            }
+            Message::LoadedPokemon(pokemon_id, pokemon) => {
+                // Only keep the result of the last requested Pokémon
+                if self.loading_pokemon == Some(pokemon_id) {
+                    self.selected_pokemon = pokemon;
+                    self.selected_pokemon_details = None;
+                    self.loading_pokemon = None;
+
+                    // Data packs have no details files, only the data of their Pokémon
+                    if pokemon_id >= DATA_PACK_ID_START {
+                        self.selected_pokemon_details = Some(StarryPokemonDetails::default());
+                        return Task::none();
+                    }
+
+                    let api_clone = self.api.clone();
+                    return cosmic::app::Task::perform(
+                        async move { api_clone.get_pokemon_details(pokemon_id).await },
+                        move |details| {
+                            cosmic::app::message::app(Message::LoadedPokemonDetails(
+                                pokemon_id, details,
+                            ))
+                        },
+                    );
+                }
+            }
+            Message::LoadedPokemonDetails(pokemon_id, details) => {
+                if self
+                    .selected_pokemon
+                    .as_ref()
+                    .is_some_and(|p| p.pokemon.id == pokemon_id)
+                {
+                    self.selected_pokemon_details = Some(details);
+                }
             }
             Message::TogglePokemonDetails(value) => self.wants_pokemon_details = value,
+            Message::DbusRequest(request) => return self.handle_activation(request),
             Message::Search(value) => {
                 // TODO: Improve search speed? Search by id...Search shouldn't erase filters
                 self.search = value;
-                self.filtered_pokemon_list = self
-                    .pokemon_list
+                self.reset_grid_window();
+                let query = SearchQuery::parse(&self.search, &self.config.custom_aliases);
+                self.filtered_pokemon_list = match &self.search_index {
+                    Some(search_index) => query
+                        .search(&self.pokemon_list, search_index)
+                        .into_iter()
+                        .cloned()
+                        .collect(),
+                    None => self
+                        .pokemon_list
+                        .values()
+                        .filter(|pokemon| query.matches(pokemon))
+                        .cloned()
+                        .collect(),
+                };
+            }
+            Message::ToggleSearchHelp => self.show_search_help = !self.show_search_help,
+            Message::UpdateSortMode(index) => {
+                self.sort_mode = match index {
+                    1 => SortMode::Generation,
+                    2 => SortMode::Type,
+                    _ => SortMode::Number,
+                };
+                self.reset_grid_window();
+            }
+            Message::UpdateViewMode(index) => {
+                self.view_mode = match index {
+                    1 => ViewMode::Table,
+                    _ => ViewMode::Grid,
+                };
+            }
+            Message::JumpToGeneration(index) => {
+                let Some(generation) = GENERATIONS.get(index) else {
+                    return Task::none();
+                };
+                self.jumped_generation = Some(index);
+
+                // The list has to be grouped by generation to have a section to jump to
+                let grouped_by_generation = match self.sort_mode {
+                    SortMode::Number => self.config.generation_headers,
+                    SortMode::Generation => true,
+                    SortMode::Type => false,
+                };
+                if !grouped_by_generation {
+                    self.sort_mode = SortMode::Generation;
+                }
+
+                // The offset of the section is the height of the rows before it
+                let target_header = crate::i18n::generation_label(generation);
+                let rows = self.grid_rows();
+                let Some(target_row) = rows.iter().position(
+                    |row| matches!(row, GridRow::Header(header) if *header == target_header),
+                ) else {
+                    return Task::none();
+                };
+                let offset: f32 = rows[..target_row]
                     .iter()
-                    .filter(|(&_id, pokemon)| {
-                        pokemon
-                            .pokemon
-                            .name
-                            .to_lowercase()
-                            .contains(&self.search.to_lowercase())
-                    })
-                    .map(|(_, pokemon)| pokemon.clone())
-                    .collect();
+                    .map(|row| self.grid_row_height(row))
+                    .sum();
+
+                // The infinite scroll grid starts its window at the section instead
+                if self.config.infinite_scroll {
+                    self.grid_window = target_row..target_row + INFINITE_SCROLL_PAGE_ROWS * 2;
+                    return cosmic::iced::widget::scrollable::scroll_to(
+                        POKEMON_GRID_ID.clone(),
+                        cosmic::iced::widget::scrollable::AbsoluteOffset::default(),
+                    );
+                }
+
+                return cosmic::iced::widget::scrollable::scroll_to(
+                    POKEMON_GRID_ID.clone(),
+                    cosmic::iced::widget::scrollable::AbsoluteOffset { x: 0.0, y: offset },
+                );
+            }
+            Message::GridScrolled(viewport) => {
+                self.grid_viewport = (viewport.absolute_offset().y, viewport.bounds().height);
+                if self.view_mode != ViewMode::Grid {
+                    return Task::none();
+                }
+
+                let slide_task = self.slide_grid_window(viewport);
+                return Task::batch([slide_task, self.preload_adjacent_sprites()]);
+            }
+            Message::PreloadedSprites => {
+                self.preloading_sprites = false;
+                return self.update(Message::PreloadQueuedSprites);
+            }
+            // The sprites asked for while a preload runs are decoded once it's done
+            Message::PreloadQueuedSprites if !self.preloading_sprites => {
+                return self.preload_sprites(ImageCache::take_queued_sprites());
+            }
+            Message::PreloadQueuedSprites => {}
+            Message::SkeletonTick => {
+                self.skeleton_frame = (self.skeleton_frame + 1) % SKELETON_WAVE_LENGTH;
+            }
+            Message::ToggleInfiniteScroll(value) => {
+                self.config.infinite_scroll = value;
+                self.reset_grid_window();
+            }
+            Message::UpdateSpriteCacheLimit(megabytes) => {
+                self.config.sprite_cache_mb = megabytes as usize;
+                ImageCache::set_sprite_cache_limit(self.config.sprite_cache_mb);
+            }
+            Message::SortTable(column) => {
+                // Clicking the sorted column again reverses the order, stats start from the highest
+                self.table_sort = match self.table_sort {
+                    (sorted_column, descending) if sorted_column == column => (column, !descending),
+                    _ => (column, matches!(column, TableColumn::Stat(_))),
+                };
             }
             Message::TypeFilterToggled(value, type_name) => {
                 if value {
@@ -446,302 +1664,3886 @@ impl Application for StarryDex {
             Message::ApplyCurrentFilters => {
                 //TODO: Revisit how to do this without this being necessary, search does not need to be lost?
                 self.search = String::new();
+                self.reset_grid_window();
+
+                let selected_types_lowercase: HashSet<String> = self
+                    .filters
+                    .selected_types
+                    .iter()
+                    .map(|t| t.to_lowercase())
+                    .collect();
+                let type_ids = |type_name: &String| {
+                    self.type_index.get(type_name).cloned().unwrap_or_default()
+                };
 
-                match self.config.type_filtering_mode {
-                    TypeFilteringMode::Inclusive => {
+                let filtered_ids: BTreeSet<i64> = if selected_types_lowercase.is_empty() {
+                    self.pokemon_list.keys().copied().collect()
+                } else {
+                    match self.config.type_filtering_mode {
                         // Ej: If fire and ice are selected it will show fire pokemons and ice pokemons
-                        let selected_types_lowercase: HashSet<String> = self
-                            .filters
-                            .selected_types
-                            .iter()
-                            .map(|t| t.to_lowercase())
-                            .collect();
-
-                        self.filtered_pokemon_list = self
-                            .pokemon_list
-                            .values()
-                            .filter(|pokemon| {
-                                selected_types_lowercase.is_empty()
-                                    || pokemon.pokemon.types.iter().any(|t| {
-                                        selected_types_lowercase.contains(&t.to_lowercase())
-                                    })
-                            })
-                            .cloned()
-                            .collect();
-                    }
-                    TypeFilteringMode::Exclusive => {
+                        TypeFilteringMode::Inclusive => {
+                            selected_types_lowercase.iter().flat_map(type_ids).collect()
+                        }
                         // Ej: If fire and ice are selected it will show pokemons that are both fire and ice types
-                        let selected_types_lowercase: HashSet<String> = self
-                            .filters
-                            .selected_types
+                        TypeFilteringMode::Exclusive => selected_types_lowercase
                             .iter()
-                            .map(|t| t.to_lowercase())
-                            .collect();
-
-                        self.filtered_pokemon_list = self
-                            .pokemon_list
-                            .values()
-                            .filter(|pokemon| {
-                                selected_types_lowercase.is_empty()
-                                    || selected_types_lowercase.iter().all(|selected_type| {
-                                        pokemon
-                                            .pokemon
-                                            .types
-                                            .iter()
-                                            .any(|t| t.to_lowercase() == *selected_type)
-                                    })
-                            })
-                            .cloned()
-                            .collect();
+                            .map(type_ids)
+                            .reduce(|a, b| a.intersection(&b).copied().collect())
+                            .unwrap_or_default(),
                     }
-                }
+                };
+
+                self.filtered_pokemon_list = filtered_ids
+                    .iter()
+                    .filter_map(|id| self.pokemon_list.get(id))
+                    .cloned()
+                    .collect();
 
                 self.core.window.show_context = false;
             }
             Message::ClearFilters => {
+                self.reset_grid_window();
                 self.filtered_pokemon_list = self.pokemon_list.values().cloned().collect();
                 self.filters = Filters {
                     selected_types: HashSet::new(),
                 };
                 self.current_page_status = PageStatus::Loaded;
             }
+            Message::ClearSearchAndFilters => {
+                self.search.clear();
+                return self.update(Message::ClearFilters);
+            }
             Message::UpdateTypeFilterMode(index) => {
-                let old_config = self.config.clone();
-
-                let filter_mode = match index {
+                self.config.type_filtering_mode = match index {
                     1 => TypeFilteringMode::Inclusive,
                     _ => TypeFilteringMode::Exclusive,
                 };
-                self.config = Config {
-                    first_run_completed: old_config.first_run_completed,
-                    pokemon_per_row: old_config.pokemon_per_row,
-                    type_filtering_mode: filter_mode,
-                    app_theme: old_config.app_theme,
+            }
+            Message::UpdateKeyboardScheme(index) => {
+                self.config.keyboard_scheme = match index {
+                    1 => KeyboardScheme::Vim,
+                    _ => KeyboardScheme::Default,
                 };
+                self.vim_pending_g = false;
             }
-            Message::DeleteCache => {
-                self.current_page_status = PageStatus::FirstRun;
-                self.set_show_context(false);
-
-                let data_dir = dirs::data_dir().unwrap().join(Self::APP_ID);
-                if let Err(e) = remove_dir_contents(&data_dir) {
-                    eprintln!("Error deleting cache: {}", e);
-                }
+            Message::RepairSprites => {
+                let sprites: Vec<(String, String)> = self
+                    .pokemon_list
+                    .values()
+                    .filter_map(|p| Some((p.pokemon.name.clone(), p.sprite_path.clone()?)))
+                    .collect();
 
-                // Reset the API
-                self.api = Api::new(Self::APP_ID);
+                self.sprite_repair = Some(SpriteRepairProgress::Checking {
+                    checked: 0,
+                    total: sprites.len(),
+                });
+                return cosmic::app::Task::run(self.api.repair_sprites(sprites), |progress| {
+                    cosmic::app::message::app(Message::RepairSpritesProgress(progress))
+                });
+            }
+            Message::RepairSpritesProgress(progress) => self.sprite_repair = Some(progress),
+            Message::CacheBuildProgress(progress) => self.cache_build = Some(progress),
+            // The construction stops and reports it was cancelled, it can be resumed with a retry
+            Message::CancelCacheBuild => self.api.cancel(),
+            Message::RemovedOldCaches(freed) => self.freed_old_caches = freed,
+            Message::LoadStorageUsage => {
+                let api_clone = self.api.clone();
+                return cosmic::app::Task::perform(
+                    async move { api_clone.storage_usage().await },
+                    |usage| cosmic::app::message::app(Message::LoadedStorageUsage(usage)),
+                );
+            }
+            Message::LoadedStorageUsage(usage) => self.storage_usage = usage,
+            Message::ClearStorage(component) => {
                 let api_clone = self.api.clone();
                 return cosmic::app::Task::perform(
-                    async move { api_clone.load_all_pokemon().await },
-                    |pokemon_list| {
-                        cosmic::app::message::app(Message::LoadedPokemonList(pokemon_list))
+                    async move { api_clone.clear_storage(component).await },
+                    move |result| {
+                        cosmic::app::message::app(Message::ClearedStorage(component, result))
                     },
                 );
             }
+            Message::ClearedStorage(component, result) => {
+                if let Err(e) = result {
+                    eprintln!("Failed to clear the {:?} storage: {}", component, e);
+                }
+
+                // Sprites that are no longer on disk are downloaded again or shown as missing
+                let refresh_sprites = match component {
+                    StorageComponent::Sprites => self.update(Message::FetchMissingSprites),
+                    StorageComponent::SpriteSets => self.update(Message::LoadSpriteSet),
+                    StorageComponent::PokemonData | StorageComponent::ExtraData => Task::none(),
+                };
+                return Task::batch([refresh_sprites, self.update(Message::LoadStorageUsage)]);
+            }
+            Message::DataDirInput(input) => self.data_dir_input = input,
+            Message::MoveDataDir(to) => {
+                // Nothing may be written to the data directory while it's moved
+                self.api.cancel();
+                self.moving_data_dir = true;
+                self.data_dir_error = None;
+
+                let to = to.unwrap_or_else(crate::data_dir::default_data_dir);
+                return cosmic::app::Task::perform(
+                    async move {
+                        tokio::task::spawn_blocking(move || move_data_dir(&to))
+                            .await
+                            .unwrap_or_else(|e| Err(e.to_string()))
+                    },
+                    |result| cosmic::app::message::app(Message::MovedDataDir(result)),
+                );
+            }
+            Message::MovedDataDir(result) => {
+                self.moving_data_dir = false;
+                match result {
+                    Ok(()) => self.data_dir_input.clear(),
+                    Err(e) => self.data_dir_error = Some(e),
+                }
+
+                // Everything is loaded again from the data directory in use now
+                self.moves_list = LazyData::NotLoaded;
+                self.items_list = LazyData::NotLoaded;
+                self.abilities_list = LazyData::NotLoaded;
+                self.berries_list = LazyData::NotLoaded;
+                self.location_index = LazyData::NotLoaded;
+                self.evolution_item_index = LazyData::NotLoaded;
+                self.dex_mode = DexMode::Pokemon;
+                self.data_packs = load_data_packs();
+                return Task::batch([
+                    self.update(Message::RetryLoading),
+                    self.update(Message::LoadStorageUsage),
+                ]);
+            }
+            Message::ExportBackup => {
+                let backup = Backup::new(&self.config, &self.challenge_history);
+                return cosmic::app::Task::perform(
+                    crate::backup::export_backup(backup),
+                    |result| cosmic::app::message::app(Message::ExportedBackup(result)),
+                );
+            }
+            Message::ExportedBackup(result) => match result {
+                // Show the backup in its folder, to copy it to the other system
+                Ok(path) => {
+                    self.backup_status =
+                        Some(fl!("backup-exported", path = path.display().to_string()));
+                    if let Some(export_dir) = path.parent() {
+                        _ = open::that_detached(export_dir);
+                    }
+                }
+                Err(e) => {
+                    self.backup_status = Some(fl!("backup-export-failed", error = e.as_str()))
+                }
+            },
+            Message::BackupPathInput(input) => self.backup_path_input = input,
+            Message::ImportBackup => {
+                let path = PathBuf::from(self.backup_path_input.trim());
+                return cosmic::app::Task::perform(crate::backup::import_backup(path), |result| {
+                    cosmic::app::message::app(Message::ImportedBackup(result))
+                });
+            }
+            Message::ImportedBackup(result) => match result {
+                Ok(backup) => {
+                    // The Pokémon data of this system stays, so its first run isn't done again
+                    let first_run_completed = self.config.first_run_completed;
+                    self.config = backup.config;
+                    self.config.first_run_completed = first_run_completed;
+                    self.key_binds = key_binds(&self.config.custom_key_binds);
+                    ImageCache::set_sprite_cache_limit(self.config.sprite_cache_mb);
+
+                    self.challenge_history = backup.challenge_history;
+                    crate::challenges::save_history(&self.challenge_history);
+
+                    self.backup_path_input.clear();
+                    self.backup_status = Some(fl!("backup-imported"));
+                    return Task::batch([
+                        cosmic::app::command::set_theme(self.config.app_theme.theme()),
+                        self.update(Message::LoadSpriteSet),
+                    ]);
+                }
+                Err(e) => {
+                    self.backup_status = Some(fl!("backup-import-failed", error = e.as_str()))
+                }
+            },
+            Message::LoadingFailed(error) => self.current_page_status = PageStatus::Failed(error),
+            Message::RetryLoading => {
+                let first_run = !self.config.first_run_completed;
+                self.current_page_status = if first_run {
+                    PageStatus::FirstRun
+                } else {
+                    PageStatus::Loading
+                };
+
+                // A cancelled API stays cancelled, the construction is resumed with a new one
+                self.api = Api::new();
+                return self.load_pokemon_list(first_run);
+            }
+            Message::RefreshData => {
+                self.data_refresh = Some(DataRefresh::Fetching {
+                    fetched: 0,
+                    total: 0,
+                });
+                return cosmic::app::Task::run(self.api.refresh_all_pokemon(), |progress| {
+                    cosmic::app::message::app(Message::RefreshDataProgress(progress))
+                });
+            }
+            Message::ToggleFetchMissingSprites(value) => {
+                self.config.fetch_missing_sprites = value;
+                if value {
+                    return self.update(Message::FetchMissingSprites);
+                }
+            }
+            Message::ToggleTypeAccents(value) => self.config.type_accents = value,
+            Message::ToggleReduceMotion(value) => self.config.reduce_motion = value,
+            Message::ToggleGenerationHeaders(value) => self.config.generation_headers = value,
+            Message::FetchMissingSprites => {
+                self.missing_sprites = self
+                    .pokemon_list
+                    .values()
+                    .filter(|p| {
+                        p.sprite_path
+                            .as_ref()
+                            .is_some_and(|path| !std::path::Path::new(path).exists())
+                    })
+                    .map(|p| p.pokemon.id)
+                    .collect();
+
+                if self.config.fetch_missing_sprites && !self.missing_sprites.is_empty() {
+                    let sprites = self
+                        .missing_sprites
+                        .iter()
+                        .filter_map(|id| {
+                            let starry_pokemon = self.pokemon_list.get(id)?;
+                            Some((
+                                *id,
+                                starry_pokemon.pokemon.name.clone(),
+                                starry_pokemon.sprite_path.clone()?,
+                            ))
+                        })
+                        .collect();
+                    return cosmic::app::Task::run(
+                        self.api.fetch_missing_sprites(sprites),
+                        |pokemon_id| cosmic::app::message::app(Message::FetchedSprite(pokemon_id)),
+                    );
+                }
+            }
+            Message::FetchedSprite(pokemon_id) => {
+                self.missing_sprites.remove(&pokemon_id);
+            }
+            Message::UpdateSpriteSet(index) => {
+                self.config.sprite_set = SpriteSet::ALL.get(index).copied().unwrap_or_default();
+                return self.update(Message::LoadSpriteSet);
+            }
+            Message::UpdateCardSize(index) => {
+                self.config.card_size = CardSize::ALL.get(index).copied().unwrap_or_default();
+            }
+            Message::UpdateTextScale(percent) => self.config.text_scale = percent as usize,
+            Message::UpdateColorPalette(index) => {
+                self.config.color_palette =
+                    ColorPalette::ALL.get(index).copied().unwrap_or_default();
+            }
+            Message::UpdateMeasurementUnits(index) => {
+                self.config.measurement_units = MeasurementUnits::ALL
+                    .get(index)
+                    .copied()
+                    .unwrap_or_default();
+            }
+            Message::LoadSpriteSet => {
+                let sprite_set = self.config.sprite_set;
+                let pokemon: Vec<(i64, String)> = self
+                    .pokemon_list
+                    .values()
+                    .map(|p| (p.pokemon.id, p.pokemon.name.clone()))
+                    .collect();
+
+                self.set_sprites = crate::sprite_sets::downloaded_sprites(sprite_set, &pokemon)
+                    .into_iter()
+                    .collect();
+                let missing: Vec<(i64, String)> = pokemon
+                    .into_iter()
+                    .filter(|(id, _)| !self.set_sprites.contains_key(id))
+                    .collect();
+
+                if sprite_set != SpriteSet::Default && !missing.is_empty() {
+                    return cosmic::app::Task::run(
+                        crate::sprite_sets::download_sprite_set(sprite_set, missing),
+                        move |(pokemon_id, path)| {
+                            cosmic::app::message::app(Message::FetchedSetSprite(
+                                sprite_set, pokemon_id, path,
+                            ))
+                        },
+                    );
+                }
+            }
+            Message::LoadSpriteOverrides => {
+                let pokemon: Vec<(i64, String)> = self
+                    .pokemon_list
+                    .values()
+                    .map(|p| (p.pokemon.id, p.pokemon.name.clone()))
+                    .collect();
+                self.sprite_overrides = crate::sprite_sets::sprite_overrides(&pokemon)
+                    .into_iter()
+                    .collect();
+            }
+            Message::OpenSpriteOverrides => {
+                let overrides_dir = crate::sprite_sets::sprite_overrides_dir();
+                if let Err(e) = std::fs::create_dir_all(&overrides_dir) {
+                    eprintln!("Failed to create the sprite overrides folder: {}", e);
+                }
+                _ = open::that_detached(overrides_dir);
+            }
+            Message::ToggleDataPack(pack_id, enabled) => {
+                if enabled {
+                    self.config.disabled_data_packs.remove(&pack_id);
+                } else {
+                    self.config.disabled_data_packs.insert(pack_id);
+                }
+                return self.update(Message::ReloadDataPacks);
+            }
+            Message::ReloadDataPacks => {
+                self.data_packs = load_data_packs();
+                self.merge_data_packs();
+
+                self.filtered_pokemon_list = self.pokemon_list.values().cloned().collect();
+                self.ability_index = build_ability_index(&self.pokemon_list);
+                self.egg_group_index = build_egg_group_index(&self.pokemon_list);
+                self.type_index = build_type_index(&self.pokemon_list);
+                self.generation_index = build_generation_index(&self.pokemon_list);
+                self.stat_percentiles = rankings::stat_percentiles(&self.pokemon_list);
+
+                let load_search_index = self.update(Message::LoadSearchIndex);
+                if !self.search.is_empty() {
+                    return Task::batch([
+                        load_search_index,
+                        self.update(Message::Search(self.search.clone())),
+                    ]);
+                }
+                return load_search_index;
+            }
+            Message::LoadSearchIndex => {
+                // Until the index of the new list is loaded, the search checks every name
+                self.search_index = None;
+
+                let api_clone = self.api.clone();
+                let names = SearchIndex::names_of(&self.pokemon_list);
+                return cosmic::app::Task::perform(
+                    async move { api_clone.load_search_index(names).await },
+                    |search_index| {
+                        cosmic::app::message::app(Message::LoadedSearchIndex(search_index))
+                    },
+                );
+            }
+            Message::LoadedSearchIndex(search_index) => {
+                // An index of a list that changed in the meantime is outdated
+                if search_index.is_built_from(&SearchIndex::names_of(&self.pokemon_list)) {
+                    self.search_index = Some(search_index);
+                }
+            }
+            Message::OpenDataPacks => {
+                let data_packs_dir = crate::data_packs::data_packs_dir();
+                if let Err(e) = std::fs::create_dir_all(&data_packs_dir) {
+                    eprintln!("Failed to create the data packs folder: {}", e);
+                }
+                _ = open::that_detached(data_packs_dir);
+            }
+            // Sprites of a set that is no longer selected are ignored
+            Message::FetchedSetSprite(sprite_set, pokemon_id, path) => {
+                if sprite_set == self.config.sprite_set {
+                    self.set_sprites.insert(pokemon_id, path);
+                }
+            }
+            Message::RefreshDataProgress(DataRefreshProgress::Fetching { fetched, total }) => {
+                self.data_refresh = Some(DataRefresh::Fetching { fetched, total });
+            }
+            Message::RefreshDataProgress(DataRefreshProgress::Finished(result)) => match result {
+                Ok((pokemon_list, renamed)) => {
+                    let old_ids: BTreeSet<i64> = self.pokemon_list.keys().copied().collect();
+                    let new_pokemon = pokemon_list
+                        .keys()
+                        .filter(|id| **id > 0 && !old_ids.contains(id))
+                        .count();
+                    self.data_refresh = Some(DataRefresh::Finished(Ok((new_pokemon, renamed))));
+
+                    // Swap the shown data keeping the current search
+                    let search = self.search.clone();
+                    let mut tasks = vec![self.update(Message::LoadedPokemonList(pokemon_list))];
+                    if !search.is_empty() {
+                        tasks.push(self.update(Message::Search(search)));
+                    }
+                    return Task::batch(tasks);
+                }
+                Err(error) => self.data_refresh = Some(DataRefresh::Finished(Err(error))),
+            },
+            Message::NewAliasInput(value) => self.new_alias = value,
+            Message::NewAliasPokemonInput(value) => self.new_alias_pokemon = value,
+            Message::AddAlias => {
+                let alias = self.new_alias.trim().to_lowercase().replace(' ', "-");
+                let pokemon_name = self
+                    .new_alias_pokemon
+                    .trim()
+                    .to_lowercase()
+                    .replace(' ', "-");
+
+                if !alias.is_empty() && !pokemon_name.is_empty() {
+                    self.config.custom_aliases.insert(alias, pokemon_name);
+                    self.new_alias = String::new();
+                    self.new_alias_pokemon = String::new();
+                }
+            }
+            Message::RemoveAlias(alias) => {
+                self.config.custom_aliases.remove(&alias);
+            }
+            Message::SaveSession => {
+                let session = Session {
+                    dex_mode: self.dex_mode,
+                    search: self.search.clone(),
+                    selected_types: self.filters.selected_types.iter().cloned().collect(),
+                    open_pokemon: self
+                        .selected_pokemon
+                        .as_ref()
+                        .filter(|_| self.core.window.show_context)
+                        .map(|p| p.pokemon.id),
+                };
+
+                if session != self.saved_session {
+                    crate::session::save_session(&session);
+                    self.saved_session = session;
+                }
+            }
+            Message::RestoreSession => {
+                if let Some(session) = self.pending_session.take() {
+                    self.filters.selected_types = session.selected_types.into_iter().collect();
+
+                    let mut tasks = vec![
+                        self.update(Message::ApplyCurrentFilters),
+                        self.update(Message::ChangeDexMode(session.dex_mode)),
+                    ];
+                    // Searching shows every matching Pokémon, so it replaces the restored filters
+                    if !session.search.is_empty() {
+                        tasks.push(self.update(Message::Search(session.search)));
+                    }
+                    if let Some(pokemon_id) = session.open_pokemon {
+                        tasks.push(self.update(Message::LoadPokemon(pokemon_id)));
+                    }
+                    return Task::batch(tasks);
+                }
+            }
+            Message::DismissSession => self.pending_session = None,
+            Message::ReportCrash => {
+                if let Some(crash_report) = self.crash_report.take() {
+                    _ = open::that_detached(crate::diagnostics::crash_issue_url(&crash_report));
+                }
+            }
+            Message::DismissCrashReport => self.crash_report = None,
+            Message::ChangeDexMode(dex_mode) => {
+                self.dex_mode = dex_mode;
+                self.core.window.show_context = false;
+
+                let api_clone = self.api.clone();
+                match dex_mode {
+                    DexMode::Moves if matches!(self.moves_list, LazyData::NotLoaded) => {
+                        self.moves_list = LazyData::Loading;
+                        return cosmic::app::Task::perform(
+                            async move { api_clone.load_all_moves().await },
+                            |moves| cosmic::app::message::app(Message::LoadedMoves(moves)),
+                        );
+                    }
+                    DexMode::Items if matches!(self.items_list, LazyData::NotLoaded) => {
+                        self.items_list = LazyData::Loading;
+                        return cosmic::app::Task::perform(
+                            async move { api_clone.load_all_items().await },
+                            |items| cosmic::app::message::app(Message::LoadedItems(items)),
+                        );
+                    }
+                    DexMode::Abilities if matches!(self.abilities_list, LazyData::NotLoaded) => {
+                        self.abilities_list = LazyData::Loading;
+                        return cosmic::app::Task::perform(
+                            async move { api_clone.load_all_abilities().await },
+                            |abilities| {
+                                cosmic::app::message::app(Message::LoadedAbilities(abilities))
+                            },
+                        );
+                    }
+                    DexMode::Trivia if self.trivia.round.is_none() => {
+                        self.trivia.next_round(&self.pokemon_list);
+                    }
+                    DexMode::Locations if matches!(self.location_index, LazyData::NotLoaded) => {
+                        self.location_index = LazyData::Loading;
+                        let pokemon_ids = self.pokemon_list.keys().copied().collect();
+                        return cosmic::app::Task::perform(
+                            async move { api_clone.load_location_index(pokemon_ids).await },
+                            |location_index| {
+                                cosmic::app::message::app(Message::LoadedLocationIndex(
+                                    location_index,
+                                ))
+                            },
+                        );
+                    }
+                    DexMode::Berries if matches!(self.berries_list, LazyData::NotLoaded) => {
+                        self.berries_list = LazyData::Loading;
+                        return cosmic::app::Task::perform(
+                            async move { api_clone.load_all_berries().await },
+                            |berries| cosmic::app::message::app(Message::LoadedBerries(berries)),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            Message::LoadedMoves(moves) => self.moves_list = LazyData::Loaded(moves),
+            Message::MovesSearch(value) => self.moves_search = value,
+            Message::MovesTypeFilter(index) => self.moves_type_filter = index,
+            Message::MovesDamageClassFilter(index) => self.moves_damage_class_filter = index,
+            Message::LoadMove(move_id) => {
+                self.selected_move = Some(move_id);
+                self.context_page = ContextPage::MovePage;
+                self.core.window.show_context = true;
+            }
+            Message::LoadedItems(items) => {
+                let categories: BTreeSet<&String> =
+                    items.iter().map(|item| &item.category).collect();
+                self.item_category_options = std::iter::once(fl!("all"))
+                    .chain(categories.into_iter().map(|c| capitalize_string(c)))
+                    .collect();
+                self.items_category_filter = 0;
+                self.items_list = LazyData::Loaded(items);
+            }
+            Message::LoadedAbilities(abilities) => {
+                self.abilities_list = LazyData::Loaded(abilities)
+            }
+            Message::AbilitiesSearch(value) => self.abilities_search = value,
+            Message::LoadedBerries(berries) => self.berries_list = LazyData::Loaded(berries),
+            Message::LoadedLocationIndex(location_index) => {
+                let games: BTreeSet<&str> = location_index
+                    .values()
+                    .flatten()
+                    .flat_map(|encounter| encounter.games())
+                    .collect();
+                self.location_game_options = std::iter::once(fl!("all"))
+                    .chain(games.into_iter().map(String::from))
+                    .collect();
+                self.locations_game_filter = 0;
+                self.location_index = LazyData::Loaded(location_index);
+            }
+            Message::LocationsSearch(value) => self.locations_search = value,
+            Message::LocationsGameFilter(index) => self.locations_game_filter = index,
+            Message::SelectLocation(location) => self.selected_location = Some(location),
+            Message::HoverTypeMatchup(matchup) => self.hovered_type_matchup = matchup,
+            Message::SelectEggGroup(egg_group) => self.selected_egg_group = Some(egg_group),
+            Message::SelectNature(index) => {
+                self.selected_nature = match self.selected_nature {
+                    Some(selected) if selected == index => None,
+                    _ => Some(index),
+                };
+            }
+            Message::RankedStat(index) => self.ranked_stat = index,
+            Message::RankingsGenerationFilter(index) => self.rankings_generation_filter = index,
+            Message::RankingsTypeFilter(index) => self.rankings_type_filter = index,
+            Message::ToggleChallengeCaught(pokemon_id) => {
+                self.challenge_history
+                    .toggle_caught(ChallengeWeek::current(), pokemon_id);
+                crate::challenges::save_history(&self.challenge_history);
+            }
+            Message::Key(modifiers, key) => {
+                if let Some(action) = self.capturing_key_bind {
+                    if key == Key::Named(Named::Escape) {
+                        self.capturing_key_bind = None;
+                    } else if let Some(key_bind) = key_bind_from_press(modifiers, &key) {
+                        self.capturing_key_bind = None;
+                        self.key_bind_conflict = self
+                            .key_binds
+                            .get(&key_bind)
+                            .copied()
+                            .filter(|bound_action| *bound_action != action);
+
+                        if self.key_bind_conflict.is_none() {
+                            self.config
+                                .custom_key_binds
+                                .insert(action.name().to_string(), format_key_bind(&key_bind));
+                            self.key_binds = key_binds(&self.config.custom_key_binds);
+                        }
+                    }
+                    return Task::none();
+                }
+
+                if key == Key::Named(Named::Escape) && self.dialog_page.is_some() {
+                    self.dialog_page = None;
+                    return Task::none();
+                }
+                if self.dialog_page == Some(DialogPage::CommandPalette) {
+                    let entries = self.palette_entries().len();
+                    match key {
+                        Key::Named(Named::ArrowDown) if entries > 0 => {
+                            self.palette_selected = (self.palette_selected + 1) % entries;
+                        }
+                        Key::Named(Named::ArrowUp) if entries > 0 => {
+                            self.palette_selected = (self.palette_selected + entries - 1) % entries;
+                        }
+                        _ => {}
+                    }
+                    return Task::none();
+                }
+
+                if self.config.keyboard_scheme == KeyboardScheme::Vim && self.dialog_page.is_none()
+                {
+                    if let Some(task) = self.vim_key(modifiers, &key) {
+                        return task;
+                    }
+                }
+
+                if self.dex_mode == DexMode::Pokemon
+                    && self.dialog_page.is_none()
+                    && modifiers.is_empty()
+                {
+                    if let Key::Named(named) = key {
+                        if let Some(task) = self.navigate_grid(named) {
+                            return task;
+                        }
+                    }
+                }
+
+                for (key_bind, action) in &self.key_binds {
+                    if key_bind.matches(modifiers, &key) {
+                        return self.update(action.message());
+                    }
+                }
+            }
+            Message::CaptureKeyBind(action) => {
+                self.capturing_key_bind = Some(action);
+                self.key_bind_conflict = None;
+            }
+            Message::ResetKeyBind(action) => {
+                self.config.custom_key_binds.remove(action.name());
+                self.key_binds = key_binds(&self.config.custom_key_binds);
+                self.key_bind_conflict = None;
+            }
+            Message::FocusSearch => {
+                self.dex_mode = DexMode::Pokemon;
+                return widget::text_input::focus(SEARCH_INPUT_ID.clone());
+            }
+            Message::OpenCommandPalette => {
+                self.dialog_page = Some(DialogPage::CommandPalette);
+                self.palette_query.clear();
+                self.palette_selected = 0;
+                return widget::text_input::focus(PALETTE_INPUT_ID.clone());
+            }
+            Message::CloseDialog => self.dialog_page = None,
+            Message::OpenGoToNumber => {
+                self.dialog_page = Some(DialogPage::GoToNumber);
+                self.goto_number.clear();
+                return widget::text_input::focus(GOTO_INPUT_ID.clone());
+            }
+            Message::OpenSpriteViewer => self.dialog_page = Some(DialogPage::SpriteViewer),
+            Message::GoToNumberInput(value) => self.goto_number = value,
+            Message::GoToNumberSubmit => {
+                if let Some(pokemon_id) = self.goto_number_target() {
+                    self.dialog_page = None;
+                    return self.update(Message::LoadPokemon(pokemon_id));
+                }
+            }
+            Message::PaletteInput(query) => {
+                self.palette_query = query;
+                self.palette_selected = 0;
+            }
+            Message::PaletteSubmit => {
+                return self.update(Message::PaletteActivate(self.palette_selected))
+            }
+            Message::PaletteActivate(index) => {
+                if let Some((_, message)) = self.palette_entries().into_iter().nth(index) {
+                    self.dialog_page = None;
+                    return self.update(message);
+                }
+            }
+            Message::TriviaAnswer(side) => self.trivia.answer(side, &self.pokemon_list),
+            Message::TriviaNextRound => self.trivia.next_round(&self.pokemon_list),
+            Message::ToggleStatCalculator(value) => self.wants_stat_calculator = value,
+            Message::CalculatorLevel(level) => self.stat_calculator.level = level,
+            Message::CalculatorNature(index) => self.selected_nature = index.checked_sub(1),
+            Message::CalculatorIv(index, iv) => self.stat_calculator.set_iv(index, iv),
+            Message::CalculatorEvs(index, evs) => self.stat_calculator.set_evs(index, evs),
+            Message::FilterByAbility(ability) => {
+                self.dex_mode = DexMode::Pokemon;
+                return self.update(Message::Search(format!("ability:{}", ability)));
+            }
+            Message::ItemsSearch(value) => self.items_search = value,
+            Message::ItemsCategoryFilter(index) => self.items_category_filter = index,
+            Message::LoadItem(item_id) => {
+                self.selected_item = Some(item_id);
+                self.context_page = ContextPage::ItemPage;
+                self.core.window.show_context = true;
+
+                if matches!(self.evolution_item_index, LazyData::NotLoaded) {
+                    self.evolution_item_index = LazyData::Loading;
+                    let api_clone = self.api.clone();
+                    let pokemon_ids = self.pokemon_list.keys().copied().collect();
+                    return cosmic::app::Task::perform(
+                        async move { api_clone.load_evolution_item_index(pokemon_ids).await },
+                        |index| cosmic::app::message::app(Message::LoadedEvolutionItemIndex(index)),
+                    );
+                }
+            }
+            Message::LoadedEvolutionItemIndex(index) => {
+                self.evolution_item_index = LazyData::Loaded(index);
+            }
+            Message::ToggleWatched(pokemon_id) => {
+                if !self.config.watchlist.remove(&pokemon_id) {
+                    self.config.watchlist.insert(pokemon_id);
+                }
+            }
+            Message::ComparedWatchlist(new_snapshot) => {
+                if let Some(old_snapshot) = self.watchlist_snapshot.take() {
+                    self.watchlist_changes =
+                        crate::watchlist::compare(&old_snapshot, &new_snapshot);
+                    self.context_page = ContextPage::WatchlistChanges;
+                    self.core.window.show_context = true;
+                }
+            }
+            Message::DeleteCache => {
+                // Take a snapshot of the watched Pokémon first, to tell what changed afterwards
+                if self.config.watchlist.is_empty() {
+                    return self.update(Message::RenewCache(Vec::new()));
+                }
+
+                let api_clone = self.api.clone();
+                let watchlist = self.config.watchlist.clone();
+                let pokemon_list = self.pokemon_list.clone();
+                return cosmic::app::Task::perform(
+                    crate::watchlist::snapshot(api_clone, watchlist, pokemon_list),
+                    |snapshot| cosmic::app::message::app(Message::RenewCache(snapshot)),
+                );
+            }
+            Message::RenewCache(watchlist_snapshot) => {
+                self.watchlist_snapshot =
+                    (!watchlist_snapshot.is_empty()).then_some(watchlist_snapshot);
+                self.current_page_status = PageStatus::FirstRun;
+                self.set_show_context(false);
+
+                // Reset the API, stopping first what the old one runs so it doesn't write into
+                // the folders being emptied
+                self.api.cancel();
+                self.api = Api::new();
+                self.moves_list = LazyData::NotLoaded;
+                self.items_list = LazyData::NotLoaded;
+                self.abilities_list = LazyData::NotLoaded;
+                self.berries_list = LazyData::NotLoaded;
+                self.location_index = LazyData::NotLoaded;
+                self.evolution_item_index = LazyData::NotLoaded;
+                self.dex_mode = DexMode::Pokemon;
+
+                let api_clone = self.api.clone();
+                return cosmic::app::Task::perform(
+                    async move { api_clone.remove_downloaded_data().await },
+                    |result| cosmic::app::message::app(Message::RemovedDownloadedData(result)),
+                );
+            }
+            Message::RemovedDownloadedData(result) => {
+                if let Err(e) = result {
+                    eprintln!("Error deleting cache: {}", e);
+                }
+                return self.load_pokemon_list(!self.config.first_run_completed);
+            }
         }
         Task::none()
     }
-}
 
-impl StarryDex {
-    /// The settings context page for this app.
-    pub fn settings(&self) -> Element<Message> {
-        let app_theme_selected = match self.config.app_theme {
-            AppTheme::Dark => 1,
-            AppTheme::Light => 2,
-            AppTheme::System => 0,
-        };
+    /// Brings the window of this instance to the front
+    fn raise_window(&self) -> Task<Message> {
+        match self.core.main_window_id() {
+            Some(window_id) => cosmic::iced::window::gain_focus(window_id),
+            None => Task::none(),
+        }
+    }
+
+    /// Does what another program asked through D-Bus, raising the window of this instance
+    fn handle_activation(&mut self, request: ActivationRequest) -> Task<Message> {
+        let task = match request {
+            ActivationRequest::Search(query) => {
+                self.dex_mode = DexMode::Pokemon;
+                self.update(Message::Search(query))
+            }
+            ActivationRequest::Open(open_request) => {
+                self.open_request = Some(open_request);
+                // Before the list is loaded the Pokémon is opened once it is
+                if matches!(self.current_page_status, PageStatus::Loaded) {
+                    self.open_requested_pokemon()
+                } else {
+                    Task::none()
+                }
+            }
+        };
+        Task::batch([self.raise_window(), task])
+    }
+
+    /// Writes the config to disk, so it's the one loaded on the next run
+    fn save_config(&self) {
+        if let Some(config_handler) = &self.config_handler {
+            if let Err(e) = self.config.write_entry(config_handler) {
+                eprintln!("Failed to save the config: {}", e);
+            }
+        }
+    }
+
+    /// Loads the Pokémon list (building the cache if there's none) reporting the progress, on the
+    /// first run the default config is saved once it's loaded
+    fn load_pokemon_list(&mut self, first_run: bool) -> Task<Message> {
+        self.cache_build = None;
+        cosmic::app::Task::run(self.api.load_all_pokemon(), move |progress| {
+            cosmic::app::message::app(match progress {
+                CacheBuildProgress::Finished(Ok(pokemon_list)) if first_run => {
+                    Message::CompletedFirstRun(
+                        Config {
+                            first_run_completed: true,
+                            pokemon_per_row: 3,
+                            generation_headers: true,
+                            sprite_cache_mb: DEFAULT_SPRITE_CACHE_MB,
+                            ..Config::default()
+                        },
+                        pokemon_list,
+                    )
+                }
+                CacheBuildProgress::Finished(Ok(pokemon_list)) => {
+                    Message::LoadedPokemonList(pokemon_list)
+                }
+                CacheBuildProgress::Finished(Err(error)) => Message::LoadingFailed(error),
+                progress => Message::CacheBuildProgress(progress),
+            })
+        })
+    }
+
+    /// Why the Pokémon list couldn't be loaded, with the actions to try again
+    fn loading_failed(&self, error: &str) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        Column::new()
+            .push(widget::text::title3(fl!("loading-failed")))
+            .push(widget::text::text(error.to_string()))
+            .push(
+                widget::row()
+                    .push(widget::button::suggested(fl!("retry")).on_press(Message::RetryLoading))
+                    .push(
+                        widget::button::destructive(fl!("delete-cache-and-retry"))
+                            .on_press(Message::RenewCache(Vec::new())),
+                    )
+                    .spacing(spacing.space_xs),
+            )
+            .align_x(Alignment::Center)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// What's being done to load the Pokémon list, with a progress bar for the steps that have one
+    fn cache_build_progress(&self) -> Element<Message> {
+        let (description, progress) = match &self.cache_build {
+            Some(CacheBuildProgress::DownloadingSprites { downloaded, total }) => (
+                fl!(
+                    "loading-downloading-sprites",
+                    downloaded = downloaded,
+                    total = total
+                ),
+                Some((*downloaded, *total)),
+            ),
+            Some(CacheBuildProgress::FetchingPokemon { fetched, total }) => (
+                fl!("loading-fetching-pokemon", fetched = fetched, total = total),
+                Some((*fetched, *total)),
+            ),
+            Some(CacheBuildProgress::SavingCache) => (fl!("loading-saving-cache"), None),
+            Some(CacheBuildProgress::Loading | CacheBuildProgress::Finished(_)) | None => {
+                (fl!("loading"), None)
+            }
+        };
+
+        let mut column = Column::new()
+            .push(widget::text::text(description))
+            .align_x(Alignment::Center)
+            .spacing(theme::active().cosmic().spacing.space_xs);
+        if let Some((done, total)) = progress {
+            column = column
+                .push(
+                    widget::progress_bar(0.0..=total.max(1) as f32, done as f32)
+                        .width(Length::Fixed(CACHE_BUILD_PROGRESS_WIDTH))
+                        .height(Length::Fixed(6.0)),
+                )
+                .push(widget::button::standard(fl!("cancel")).on_press(Message::CancelCacheBuild));
+        }
+
+        column.into()
+    }
+
+    /// The settings context page for this app.
+    pub fn settings(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let app_theme_selected = match self.config.app_theme {
+            AppTheme::Dark => 1,
+            AppTheme::Light => 2,
+            AppTheme::System => 0,
+        };
+
+        let type_filter_mode_selected = match self.config.type_filtering_mode {
+            TypeFilteringMode::Inclusive => 1,
+            TypeFilteringMode::Exclusive => 0,
+        };
+
+        let keyboard_scheme_selected = match self.config.keyboard_scheme {
+            KeyboardScheme::Vim => 1,
+            KeyboardScheme::Default => 0,
+        };
+
+        let current_value = self.config.pokemon_per_row as u16;
+        let old_config = self.config.clone();
+
+        widget::settings::view_column(vec![
+            widget::settings::section()
+                .title(fl!("appearance"))
+                .add(
+                    widget::settings::item::builder(fl!("theme")).control(widget::dropdown(
+                        &self.app_themes,
+                        Some(app_theme_selected),
+                        Message::UpdateTheme,
+                    )),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("sprite-set"))
+                        .description(fl!("sprite-set-description"))
+                        .control(widget::dropdown(
+                            &self.sprite_set_options,
+                            SpriteSet::ALL
+                                .iter()
+                                .position(|sprite_set| *sprite_set == self.config.sprite_set),
+                            Message::UpdateSpriteSet,
+                        )),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("sprite-overrides"))
+                        .description(fl!(
+                            "sprite-overrides-description",
+                            count = self.sprite_overrides.len()
+                        ))
+                        .control(
+                            widget::Row::new()
+                                .push(
+                                    widget::button::standard(fl!("sprite-overrides-reload"))
+                                        .on_press(Message::LoadSpriteOverrides),
+                                )
+                                .push(
+                                    widget::button::standard(fl!("sprite-overrides-open"))
+                                        .on_press(Message::OpenSpriteOverrides),
+                                )
+                                .spacing(spacing.space_xxs),
+                        ),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("type-accents"))
+                        .description(fl!("type-accents-description"))
+                        .toggler(self.config.type_accents, Message::ToggleTypeAccents),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("reduce-motion"))
+                        .description(fl!("reduce-motion-description"))
+                        .toggler(self.config.reduce_motion, Message::ToggleReduceMotion),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("generation-headers"))
+                        .description(fl!("generation-headers-description"))
+                        .toggler(
+                            self.config.generation_headers,
+                            Message::ToggleGenerationHeaders,
+                        ),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("card-size")).control(widget::dropdown(
+                        &self.card_size_options,
+                        CardSize::ALL
+                            .iter()
+                            .position(|card_size| *card_size == self.config.card_size),
+                        Message::UpdateCardSize,
+                    )),
+                )
+                .add({
+                    let text_scale = (self.text_scale() * 100.0).round() as u16;
+
+                    widget::settings::item::builder(fl!("text-scale"))
+                        .description(fl!("text-scale-description", percent = text_scale))
+                        .control(
+                            widget::slider(
+                                MIN_TEXT_SCALE..=MAX_TEXT_SCALE,
+                                text_scale,
+                                Message::UpdateTextScale,
+                            )
+                            .step(10u16),
+                        )
+                })
+                .add(
+                    widget::settings::item::builder(fl!("color-palette"))
+                        .description(fl!("color-palette-description"))
+                        .control(widget::dropdown(
+                            &self.color_palette_options,
+                            ColorPalette::ALL
+                                .iter()
+                                .position(|palette| *palette == self.config.color_palette),
+                            Message::UpdateColorPalette,
+                        )),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("measurement-units")).control(
+                        widget::dropdown(
+                            &self.measurement_units_options,
+                            MeasurementUnits::ALL
+                                .iter()
+                                .position(|units| *units == self.config.measurement_units),
+                            Message::UpdateMeasurementUnits,
+                        ),
+                    ),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("infinite-scroll"))
+                        .description(fl!("infinite-scroll-description"))
+                        .toggler(self.config.infinite_scroll, Message::ToggleInfiniteScroll),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("pokemon-per-row"))
+                        .description(format!("{}", current_value))
+                        .control(
+                            widget::slider(1..=10, current_value, move |new_value| {
+                                Message::UpdateConfig(Config {
+                                    pokemon_per_row: new_value as usize,
+                                    ..old_config.clone()
+                                })
+                            })
+                            .step(1u16),
+                        ),
+                )
+                .into(),
+            widget::settings::section()
+                .title(fl!("other"))
+                .add(
+                    widget::settings::item::builder(fl!("type-filter-mode")).control(
+                        widget::dropdown(
+                            &self.type_filter_mode,
+                            Some(type_filter_mode_selected),
+                            Message::UpdateTypeFilterMode,
+                        ),
+                    ),
+                )
+                .add(
+                    widget::settings::item::builder(fl!("keyboard-scheme"))
+                        .description(fl!("keyboard-scheme-description"))
+                        .control(widget::dropdown(
+                            &self.keyboard_schemes,
+                            Some(keyboard_scheme_selected),
+                            Message::UpdateKeyboardScheme,
+                        )),
+                )
+                .add({
+                    let repairing = matches!(
+                        self.sprite_repair,
+                        Some(SpriteRepairProgress::Checking { .. })
+                    );
+                    let item = widget::settings::item::builder(fl!("repair-sprites"));
+                    let item = match self.sprite_repair {
+                        Some(SpriteRepairProgress::Checking { checked, total }) => item
+                            .description(fl!(
+                                "repair-sprites-checking",
+                                checked = checked,
+                                total = total
+                            )),
+                        Some(SpriteRepairProgress::Finished { repaired, failed }) => item
+                            .description(fl!(
+                                "repair-sprites-finished",
+                                repaired = repaired,
+                                failed = failed
+                            )),
+                        None => item,
+                    };
+
+                    item.control(
+                        widget::button::standard(fl!("repair-sprites-button"))
+                            .on_press_maybe((!repairing).then_some(Message::RepairSprites)),
+                    )
+                })
+                .add({
+                    let item = widget::settings::item::builder(fl!("refresh-data"));
+                    let item =
+                        match &self.data_refresh {
+                            Some(DataRefresh::Fetching { fetched, total }) => item.description(
+                                fl!("refresh-data-fetching", fetched = fetched, total = total),
+                            ),
+                            Some(DataRefresh::Finished(Ok((new_pokemon, renamed)))) => item
+                                .description(fl!(
+                                    "refresh-data-finished",
+                                    new = new_pokemon,
+                                    renamed = renamed
+                                )),
+                            Some(DataRefresh::Finished(Err(error))) => {
+                                item.description(fl!("refresh-data-failed", error = error.as_str()))
+                            }
+                            None => item.description(fl!("refresh-data-description")),
+                        };
+
+                    item.control(
+                        widget::button::standard(fl!("refresh-data-button")).on_press_maybe(
+                            (!matches!(self.data_refresh, Some(DataRefresh::Fetching { .. })))
+                                .then_some(Message::RefreshData),
+                        ),
+                    )
+                })
+                .add(
+                    widget::settings::item::builder(fl!("fetch-missing-sprites"))
+                        .description(fl!("fetch-missing-sprites-description"))
+                        .toggler(
+                            self.config.fetch_missing_sprites,
+                            Message::ToggleFetchMissingSprites,
+                        ),
+                )
+                .add({
+                    let sprite_cache_mb = match self.config.sprite_cache_mb {
+                        0 => DEFAULT_SPRITE_CACHE_MB,
+                        megabytes => megabytes,
+                    } as u16;
+
+                    widget::settings::item::builder(fl!("sprite-cache"))
+                        .description(fl!("sprite-cache-description", megabytes = sprite_cache_mb))
+                        .control(
+                            widget::slider(
+                                16..=512,
+                                sprite_cache_mb,
+                                Message::UpdateSpriteCacheLimit,
+                            )
+                            .step(16u16),
+                        )
+                })
+                .into(),
+            self.storage_section(),
+            self.backup_section(),
+            self.key_binds_section(),
+            self.aliases_section(),
+            self.data_packs_section(),
+        ])
+        .into()
+    }
+
+    /// The storage section of the settings context page, with the disk space used by each part of
+    /// the data and a button to clear it. The Pokémon data is cleared renewing the cache.
+    fn storage_section(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let source = data_dir_source();
+        let can_move = !self.moving_data_dir
+            && matches!(source, DataDirSource::Default | DataDirSource::Settings);
+
+        let data_dir_description = match (&self.data_dir_error, source) {
+            _ if self.moving_data_dir => fl!("data-folder-moving"),
+            (Some(error), _) => fl!("data-folder-move-failed", error = error.as_str()),
+            (None, DataDirSource::Portable) => fl!("data-folder-portable"),
+            (None, DataDirSource::CommandLine) => fl!("data-folder-command-line"),
+            (None, DataDirSource::Default | DataDirSource::Settings) => {
+                app_data_dir().display().to_string()
+            }
+        };
+        let move_row = widget::Row::new()
+            .push(
+                widget::text_input(fl!("data-folder-placeholder"), &self.data_dir_input)
+                    .on_input(Message::DataDirInput),
+            )
+            .push(
+                widget::button::standard(fl!("data-folder-move")).on_press_maybe(
+                    (can_move && !self.data_dir_input.trim().is_empty()).then(|| {
+                        Message::MoveDataDir(Some(PathBuf::from(self.data_dir_input.trim())))
+                    }),
+                ),
+            )
+            .spacing(spacing.space_xxs)
+            .align_y(Alignment::Center);
+
+        let section = StorageComponent::ALL.into_iter().fold(
+            widget::settings::section()
+                .title(fl!("storage"))
+                .add(
+                    widget::settings::item::builder(fl!("data-folder"))
+                        .description(data_dir_description)
+                        .control(
+                            widget::button::standard(fl!("data-folder-default")).on_press_maybe(
+                                (can_move && source == DataDirSource::Settings)
+                                    .then_some(Message::MoveDataDir(None)),
+                            ),
+                        ),
+                )
+                .add(widget::settings::item_row(vec![move_row.into()])),
+            |section, component| {
+                let size = self
+                    .storage_usage
+                    .iter()
+                    .find(|(used_by, _)| *used_by == component)
+                    .map(|(_, size)| format_size(*size))
+                    .unwrap_or_else(|| fl!("loading"));
+                let clear_button = match component {
+                    StorageComponent::PokemonData => {
+                        widget::button::destructive(fl!("renew-cache-button"))
+                            .on_press(Message::DeleteCache)
+                    }
+                    component => widget::button::destructive(fl!("storage-clear"))
+                        .on_press(Message::ClearStorage(component)),
+                };
+
+                section.add(
+                    widget::settings::item::builder(component.label())
+                        .description(size)
+                        .control(clear_button),
+                )
+            },
+        );
+
+        match self.freed_old_caches {
+            0 => section.into(),
+            freed => section
+                .add(widget::settings::item_row(vec![widget::text::caption(
+                    fl!("old-caches-removed", size = format_size(freed)),
+                )
+                .into()]))
+                .into(),
+        }
+    }
+
+    /// The backup section of the settings context page, exports the settings and the caught
+    /// Pokémon to a file and imports one made on another system.
+    fn backup_section(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let import_row = widget::Row::new()
+            .push(
+                widget::text_input(fl!("backup-import-placeholder"), &self.backup_path_input)
+                    .on_input(Message::BackupPathInput),
+            )
+            .push(
+                widget::button::standard(fl!("backup-import")).on_press_maybe(
+                    (!self.backup_path_input.trim().is_empty()).then_some(Message::ImportBackup),
+                ),
+            )
+            .spacing(spacing.space_xxs)
+            .align_y(Alignment::Center);
+
+        widget::settings::section()
+            .title(fl!("backup"))
+            .add(
+                widget::settings::item::builder(fl!("backup-export"))
+                    .description(
+                        self.backup_status
+                            .clone()
+                            .unwrap_or_else(|| fl!("backup-description")),
+                    )
+                    .control(
+                        widget::button::standard(fl!("backup-export-button"))
+                            .on_press(Message::ExportBackup),
+                    ),
+            )
+            .add(widget::settings::item_row(vec![import_row.into()]))
+            .into()
+    }
+
+    /// The keyboard shortcuts section of the settings context page, shortcuts are changed by
+    /// pressing the new key combination after clicking the change button.
+    fn key_binds_section(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let section = MenuAction::REBINDABLE.into_iter().fold(
+            widget::settings::section().title(fl!("keyboard-shortcuts")),
+            |section, action| {
+                let description = if self.capturing_key_bind == Some(action) {
+                    fl!("press-shortcut")
+                } else {
+                    self.key_binds
+                        .iter()
+                        .find(|(_, bound_action)| **bound_action == action)
+                        .map(|(key_bind, _)| format_key_bind(key_bind))
+                        .unwrap_or_else(|| fl!("no-shortcut"))
+                };
+
+                let mut controls = widget::Row::new()
+                    .push(
+                        widget::button::standard(fl!("change-shortcut"))
+                            .on_press(Message::CaptureKeyBind(action)),
+                    )
+                    .spacing(spacing.space_xxs)
+                    .align_y(Alignment::Center);
+                if self.config.custom_key_binds.contains_key(action.name()) {
+                    controls = controls.push(
+                        widget::button::icon(widget::icon::from_name("edit-undo-symbolic"))
+                            .tooltip(fl!("reset-shortcut"))
+                            .on_press(Message::ResetKeyBind(action)),
+                    );
+                }
+
+                section.add(
+                    widget::settings::item::builder(action.label())
+                        .description(description)
+                        .control(controls),
+                )
+            },
+        );
+
+        match self.key_bind_conflict {
+            Some(action) => section
+                .add(
+                    widget::text(fl!("shortcut-conflict", action = action.label()))
+                        .class(theme::Text::Accent),
+                )
+                .into(),
+            None => section.into(),
+        }
+    }
+
+    /// The data packs section of the settings context page, each pack can be turned on or off.
+    fn data_packs_section(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let folder_buttons = widget::Row::new()
+            .push(
+                widget::button::standard(fl!("data-packs-reload"))
+                    .on_press(Message::ReloadDataPacks),
+            )
+            .push(widget::button::standard(fl!("data-packs-open")).on_press(Message::OpenDataPacks))
+            .spacing(spacing.space_xxs);
+
+        self.data_packs
+            .iter()
+            .fold(
+                widget::settings::section().title(fl!("data-packs")).add(
+                    widget::settings::item::builder(fl!("data-packs-folder"))
+                        .description(fl!("data-packs-description"))
+                        .control(folder_buttons),
+                ),
+                |section, data_pack| {
+                    let pack_id = data_pack.id.clone();
+                    section.add(
+                        widget::settings::item::builder(data_pack.name.clone())
+                            .description(fl!("data-pack-pokemon", count = data_pack.pokemon.len()))
+                            .toggler(
+                                !self.config.disabled_data_packs.contains(&data_pack.id),
+                                move |enabled| Message::ToggleDataPack(pack_id.clone(), enabled),
+                            ),
+                    )
+                },
+            )
+            .into()
+    }
+
+    /// The search aliases section of the settings context page.
+    fn aliases_section(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let new_alias_row = widget::Row::new()
+            .push(
+                widget::text_input(fl!("alias"), &self.new_alias)
+                    .on_input(Message::NewAliasInput)
+                    .on_submit(Message::AddAlias),
+            )
+            .push(
+                widget::text_input(fl!("alias-pokemon"), &self.new_alias_pokemon)
+                    .on_input(Message::NewAliasPokemonInput)
+                    .on_submit(Message::AddAlias),
+            )
+            .push(widget::button::standard(fl!("add")).on_press(Message::AddAlias))
+            .spacing(spacing.space_xxs)
+            .align_y(Alignment::Center);
+
+        self.config
+            .custom_aliases
+            .iter()
+            .fold(
+                widget::settings::section()
+                    .title(fl!("search-aliases"))
+                    .add(widget::settings::item_row(vec![new_alias_row.into()])),
+                |section, (alias, pokemon_name)| {
+                    section.add(
+                        widget::settings::item::builder(alias.clone())
+                            .description(capitalize_string(pokemon_name))
+                            .control(
+                                widget::button::icon(widget::icon::from_name(
+                                    "edit-delete-symbolic",
+                                ))
+                                .tooltip(fl!("remove-alias"))
+                                .on_press(Message::RemoveAlias(alias.clone())),
+                            ),
+                    )
+                },
+            )
+            .into()
+    }
+
+    /// The main page for this app.
+    pub fn landing(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let search = widget::search_input(fl!("search"), &self.search)
+            .id(SEARCH_INPUT_ID.clone())
+            .style(theme::TextInput::Search)
+            .on_input(Message::Search)
+            .line_height(LineHeight::Absolute(Pixels(30.0)))
+            .width(Length::Fill);
+
+        let search_help_button = widget::button::standard("?").on_press(Message::ToggleSearchHelp);
+
+        let mut search_help = widget::popover(search_help_button)
+            .position(widget::popover::Position::Bottom)
+            .on_close(Message::ToggleSearchHelp);
+
+        if self.show_search_help {
+            search_help = search_help.popup(self.search_help());
+        }
+
+        let sort_mode_selected = match self.sort_mode {
+            SortMode::Number => 0,
+            SortMode::Generation => 1,
+            SortMode::Type => 2,
+        };
+        let sort = widget::dropdown(
+            &self.sort_mode_options,
+            Some(sort_mode_selected),
+            Message::UpdateSortMode,
+        );
+
+        let view_mode_selected = match self.view_mode {
+            ViewMode::Grid => 0,
+            ViewMode::Table => 1,
+        };
+        let view_mode = widget::dropdown(
+            &self.view_mode_options,
+            Some(view_mode_selected),
+            Message::UpdateViewMode,
+        );
+
+        let filters = widget::button::standard(fl!("filter"))
+            .class(theme::Button::Suggested)
+            .on_press(Message::ToggleContextPage(ContextPage::FiltersPage))
+            .width(Length::Shrink);
+
+        let clear_filters = widget::button::standard(fl!("clear-filters"))
+            .class(theme::Button::Destructive)
+            .on_press(Message::ClearFilters)
+            .width(Length::Shrink);
+
+        let search_row = widget::Row::new()
+            .push(search)
+            .push(search_help)
+            .push_maybe((self.view_mode == ViewMode::Grid).then_some(sort))
+            .push_maybe((self.view_mode == ViewMode::Grid).then(|| {
+                widget::tooltip(
+                    widget::dropdown(
+                        &self.jump_generation_options,
+                        self.jumped_generation,
+                        Message::JumpToGeneration,
+                    ),
+                    widget::text(fl!("jump-to-generation")),
+                    widget::tooltip::Position::Bottom,
+                )
+            }))
+            .push(view_mode)
+            .push(filters)
+            .push(clear_filters)
+            .spacing(Pixels::from(spacing.space_xxxs))
+            .width(Length::Fill);
+
+        let restore_session = self.pending_session.as_ref().map(|_| {
+            widget::container(
+                widget::Row::new()
+                    .push(widget::text(fl!("restore-session-prompt")).width(Length::Fill))
+                    .push(widget::button::text(fl!("dismiss")).on_press(Message::DismissSession))
+                    .push(
+                        widget::button::suggested(fl!("restore-session"))
+                            .on_press(Message::RestoreSession),
+                    )
+                    .spacing(spacing.space_xxs)
+                    .align_y(Alignment::Center),
+            )
+            .padding(spacing.space_xxs)
+            .class(theme::Container::Card)
+        });
+
+        let crash_report = self.crash_report.as_ref().map(|_| {
+            widget::container(
+                widget::Row::new()
+                    .push(widget::text(fl!("crash-report-prompt")).width(Length::Fill))
+                    .push(
+                        widget::button::text(fl!("dismiss")).on_press(Message::DismissCrashReport),
+                    )
+                    .push(
+                        widget::button::suggested(fl!("crash-report-send"))
+                            .on_press(Message::ReportCrash),
+                    )
+                    .spacing(spacing.space_xxs)
+                    .align_y(Alignment::Center),
+            )
+            .padding(spacing.space_xxs)
+            .class(theme::Container::Card)
+        });
+
+        widget::Column::new()
+            .push_maybe(crash_report)
+            .push_maybe(restore_session)
+            .push(search_row)
+            .push(match self.view_mode {
+                _ if self.filtered_pokemon_list.is_empty() => self.empty_state(),
+                ViewMode::Grid if self.config.infinite_scroll => self.infinite_grid(),
+                ViewMode::Grid => self.virtual_grid(),
+                ViewMode::Table => self.pokemon_table(),
+            })
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// Shown instead of the grid when the search and filters match no Pokémon, with Pokémon whose
+    /// name is close to the search.
+    fn empty_state(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let query = self.search.trim();
+
+        let title = if query.is_empty() {
+            fl!("empty-state-filters")
+        } else {
+            fl!("empty-state-search", query = query)
+        };
+
+        let mut suggestions: Vec<(i64, &StarryPokemon)> = self
+            .pokemon_list
+            .values()
+            .filter(|_| !query.is_empty())
+            .filter_map(|starry_pokemon| {
+                let score = palette::fuzzy_score(query, &starry_pokemon.display_name())
+                    .max(palette::fuzzy_score(query, &starry_pokemon.pokemon.name))?;
+                Some((score, starry_pokemon))
+            })
+            .collect();
+        suggestions.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        let suggestions = (!suggestions.is_empty()).then(|| {
+            suggestions.into_iter().take(MAX_SUGGESTIONS).fold(
+                widget::Row::new()
+                    .push(widget::text(fl!("did-you-mean")))
+                    .spacing(spacing.space_xxs)
+                    .align_y(Alignment::Center),
+                |row, (_, starry_pokemon)| {
+                    row.push(
+                        widget::button::text(starry_pokemon.display_name())
+                            .on_press(Message::LoadPokemon(starry_pokemon.pokemon.id)),
+                    )
+                },
+            )
+        });
+
+        widget::container(
+            Column::new()
+                .push(widget::text::title3(title))
+                .push_maybe(suggestions)
+                .push(
+                    widget::button::standard(fl!("clear-search-and-filters"))
+                        .on_press(Message::ClearSearchAndFilters),
+                )
+                .spacing(spacing.space_s)
+                .align_x(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .into()
+    }
+
+    /// Whether the Pokémon list is read from the cache, which shows placeholder cards instead of
+    /// the progress of building the cache
+    fn showing_skeletons(&self) -> bool {
+        matches!(self.current_page_status, PageStatus::Loading)
+            && matches!(self.cache_build, None | Some(CacheBuildProgress::Loading))
+    }
+
+    /// Grid of placeholder cards sized like the cards of the Pokémon, shown while the list loads.
+    fn skeleton_grid(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let (card_width, card_height) = self.card_dimensions();
+        let sprite_size = self.config.card_size.sprite_size();
+        let per_row = self.config.pokemon_per_row.max(1);
+
+        let rows = (0..SKELETON_ROWS).map(|row| {
+            let cards = (0..per_row).map(|column| {
+                let shade = if self.config.reduce_motion {
+                    skeleton_shade(0, 0)
+                } else {
+                    skeleton_shade(self.skeleton_frame, row + column)
+                };
+                widget::container(
+                    widget::Column::new()
+                        .push(skeleton_block(sprite_size, sprite_size, shade))
+                        .push(skeleton_block(card_width * 0.6, 12.0, shade))
+                        .push(skeleton_block(card_width * 0.4, 10.0, shade))
+                        .spacing(spacing.space_xxs)
+                        .align_x(Alignment::Center),
+                )
+                .width(Length::Fixed(card_width))
+                .height(Length::Fixed(card_height))
+                .align_x(Horizontal::Center)
+                .into()
+            });
+
+            widget::container(widget::Row::with_children(cards))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .into()
+        });
+
+        widget::container(Column::with_children(rows))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding([spacing.space_none, spacing.space_s])
+            .into()
+    }
+
+    /// Card of a Pokémon of the grid, with its sprite, number, name and types.
+    fn pokemon_card<'a>(&'a self, pokemon: &'a StarryPokemon) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+        let text_scale = self.text_scale();
+        let (card_width, card_height) = self.card_dimensions();
+        let sprite_size = self.config.card_size.sprite_size();
+
+        let pokemon_image: Element<Message> =
+            match self.sprite_of(pokemon).map(|path| ImageCache::sprite(path)) {
+                // Shown as a placeholder until the background decoding gets to it
+                Some(None) => skeleton_block(sprite_size, sprite_size, skeleton_shade(0, 0)),
+                Some(Some(sprite)) => widget::Image::new(sprite)
+                    .content_fit(cosmic::iced::ContentFit::ScaleDown)
+                    .width(Length::Shrink)
+                    .height(Length::Fixed(sprite_size))
+                    .into(),
+                None => widget::Image::new(ImageCache::get("fallback"))
+                    .content_fit(cosmic::iced::ContentFit::ScaleDown)
+                    .width(Length::Shrink)
+                    .height(Length::Fixed(sprite_size))
+                    .into(),
+            };
+
+        widget::button::custom(
+            widget::Column::new()
+                .push(pokemon_image)
+                .push(
+                    widget::Row::new()
+                        .push(
+                            widget::text::caption(format!("#{:03}", pokemon.pokemon.id))
+                                .size(CAPTION_TEXT_SIZE * text_scale)
+                                .line_height(LineHeight::Relative(1.4))
+                                .class(theme::Text::Accent),
+                        )
+                        .push(
+                            widget::text::text(pokemon.display_name())
+                                .size(BODY_TEXT_SIZE * text_scale)
+                                .width(Length::Shrink)
+                                .line_height(LineHeight::Absolute(Pixels::from(15.0 * text_scale))),
+                        )
+                        .spacing(spacing.space_xxs)
+                        .align_y(Alignment::Center),
+                )
+                .push(
+                    widget::Row::with_children(
+                        pokemon
+                            .pokemon
+                            .types
+                            .iter()
+                            .map(|type_name| type_chip(type_name, color_palette, text_scale)),
+                    )
+                    .spacing(spacing.space_xxxs),
+                )
+                .spacing(spacing.space_xxxs)
+                .width(Length::Fill)
+                .align_x(Alignment::Center),
+        )
+        .name(pokemon_accessible_name(pokemon))
+        .width(Length::Fixed(card_width))
+        .height(Length::Fixed(card_height))
+        .on_press_down(Message::LoadPokemon(pokemon.pokemon.id))
+        .selected(self.grid_selection == Some(pokemon.pokemon.id))
+        .class(theme::Button::Image)
+        .padding([spacing.space_none, spacing.space_s])
+        .into()
+    }
+
+    /// Rows of the grid, group headers included, with the Pokémon per row of the settings.
+    fn grid_rows(&self) -> Vec<GridRow<'_>> {
+        let per_row = self.config.pokemon_per_row.max(1);
+
+        self.grouped_pokemon()
+            .into_iter()
+            .flat_map(|(header, group)| {
+                header.map(GridRow::Header).into_iter().chain(
+                    group
+                        .chunks(per_row)
+                        .map(|row| GridRow::Cards(row.to_vec()))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+
+    /// Height a row of the grid takes.
+    fn grid_row_height(&self, row: &GridRow) -> f32 {
+        match row {
+            GridRow::Header(_) => {
+                GROUP_HEADER_HEIGHT + 2.0 * theme::active().cosmic().spacing.space_s as f32
+            }
+            GridRow::Cards(_) => self.card_dimensions().1,
+        }
+    }
+
+    /// The grid of the infinite scroll mode, only the rows of the loaded window are built.
+    fn infinite_grid(&self) -> Element<Message> {
+        let rows = self.grid_rows();
+        let start = self.grid_window.start.min(rows.len());
+        let end = self.grid_window.end.min(rows.len());
+
+        let rows = rows[start..end].iter().map(|row| self.grid_row(row));
+
+        widget::scrollable(Column::with_children(rows).width(Length::Fill))
+            .id(POKEMON_GRID_ID.clone())
+            .on_scroll(Message::GridScrolled)
+            .width(Length::Fill)
+            .into()
+    }
+
+    /// The whole grid, only the rows in view (and a few around them) are built, the others are
+    /// replaced by empty space of their height.
+    fn virtual_grid(&self) -> Element<Message> {
+        // Rows built above and below the view, so they're ready while scrolling
+        const BUFFER: f32 = 400.0;
+
+        let rows = self.grid_rows();
+        let (offset, height) = self.grid_viewport;
+        // Before the first scroll the view height isn't known yet, a big window is assumed
+        let height = if height > 0.0 { height } else { 2000.0 };
+        // The list may have shrunk since the last scroll, the scrollable keeps the offset in it
+        let total_height: f32 = rows.iter().map(|row| self.grid_row_height(row)).sum();
+        let offset = offset.min((total_height - height).max(0.0));
+
+        let mut column = Column::new().width(Length::Fill);
+        let mut top = 0.0;
+        let mut skipped_above = 0.0;
+        let mut skipped_below = 0.0;
+        for row in &rows {
+            let row_height = self.grid_row_height(row);
+            if top + row_height < offset - BUFFER {
+                skipped_above += row_height;
+            } else if top > offset + height + BUFFER {
+                skipped_below += row_height;
+            } else {
+                column = column.push(self.grid_row(row));
+            }
+            top += row_height;
+        }
+
+        widget::scrollable(
+            Column::new()
+                .push(widget::Space::with_height(Length::Fixed(skipped_above)))
+                .push(column)
+                .push(widget::Space::with_height(Length::Fixed(skipped_below)))
+                .width(Length::Fill),
+        )
+        .id(POKEMON_GRID_ID.clone())
+        .on_scroll(Message::GridScrolled)
+        .width(Length::Fill)
+        .into()
+    }
+
+    /// A row of the grid, with the height given by `grid_row_height`.
+    fn grid_row<'a>(&'a self, row: &GridRow<'a>) -> Element<'a, Message> {
+        let height = Length::Fixed(self.grid_row_height(row));
+        match row {
+            GridRow::Header(header) => widget::container(widget::text::title3(header.clone()))
+                .height(height)
+                .align_y(Vertical::Center)
+                .into(),
+            GridRow::Cards(pokemon) => widget::container(widget::Row::with_children(
+                pokemon.iter().map(|&pokemon| self.pokemon_card(pokemon)),
+            ))
+            .width(Length::Fill)
+            .height(height)
+            .align_x(Horizontal::Center)
+            .into(),
+        }
+    }
+
+    /// Moves the window of the infinite scroll grid when the view nears one of its ends,
+    /// keeping the shown rows in place.
+    fn slide_grid_window(
+        &mut self,
+        viewport: cosmic::iced::widget::scrollable::Viewport,
+    ) -> Task<Message> {
+        if !self.config.infinite_scroll {
+            return Task::none();
+        }
+
+        let heights: Vec<f32> = self
+            .grid_rows()
+            .iter()
+            .map(|row| self.grid_row_height(row))
+            .collect();
+        let offset = viewport.absolute_offset().y;
+        let remaining = viewport.content_bounds().height - offset - viewport.bounds().height;
+
+        let mut window =
+            self.grid_window.start.min(heights.len())..self.grid_window.end.min(heights.len());
+        // Height added (or removed) above the shown rows, to keep them in place
+        let mut shift = 0.0;
+        if remaining < INFINITE_SCROLL_THRESHOLD && window.end < heights.len() {
+            window.end = (window.end + INFINITE_SCROLL_PAGE_ROWS).min(heights.len());
+            if window.len() > INFINITE_SCROLL_MAX_ROWS {
+                let start = window.end - INFINITE_SCROLL_MAX_ROWS;
+                shift -= heights[window.start..start].iter().sum::<f32>();
+                window.start = start;
+            }
+        } else if offset < INFINITE_SCROLL_THRESHOLD && window.start > 0 {
+            let start = window.start.saturating_sub(INFINITE_SCROLL_PAGE_ROWS);
+            shift += heights[start..window.start].iter().sum::<f32>();
+            window.start = start;
+            window.end = window.end.min(start + INFINITE_SCROLL_MAX_ROWS);
+        }
+
+        if window == self.grid_window {
+            return Task::none();
+        }
+        self.grid_window = window;
+        if shift != 0.0 {
+            return cosmic::iced::widget::scrollable::scroll_to(
+                POKEMON_GRID_ID.clone(),
+                cosmic::iced::widget::scrollable::AbsoluteOffset {
+                    x: 0.0,
+                    y: (offset + shift).max(0.0),
+                },
+            );
+        }
+
+        Task::none()
+    }
+
+    /// Decodes in the background the sprites of the rows in the view and just above and below it,
+    /// so they're ready when scrolled to.
+    fn preload_adjacent_sprites(&mut self) -> Task<Message> {
+        let rows = self.grid_rows();
+        let (offset, height) = self.grid_viewport;
+
+        // Rows of the scrollable content, the infinite scroll grid only has the ones of its window
+        let window = if self.config.infinite_scroll {
+            self.grid_window.start.min(rows.len())..self.grid_window.end.min(rows.len())
+        } else {
+            0..rows.len()
+        };
+        // The infinite scroll grid also preloads the pages around its window
+        let mut adjacent_rows: Vec<&GridRow> = if self.config.infinite_scroll {
+            let previous = window.start.saturating_sub(INFINITE_SCROLL_PAGE_ROWS);
+            let next = (window.end + INFINITE_SCROLL_PAGE_ROWS).min(rows.len());
+            rows[previous..window.start]
+                .iter()
+                .chain(&rows[window.end..next])
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut top = 0.0;
+        for row in &rows[window] {
+            let row_height = self.grid_row_height(row);
+            let adjacent = top + row_height >= offset - height && top <= offset + 2.0 * height;
+            if adjacent {
+                adjacent_rows.push(row);
+            }
+            top += row_height;
+        }
+
+        let paths = adjacent_rows.into_iter().flat_map(|row| match row {
+            GridRow::Header(_) => Vec::new(),
+            GridRow::Cards(pokemon) => pokemon
+                .iter()
+                .filter_map(|pokemon| self.sprite_of(pokemon).cloned())
+                .collect(),
+        });
+        self.preload_sprites(ImageCache::uncached_sprites(paths))
+    }
+
+    /// Decodes the given sprites in the background.
+    fn preload_sprites(&mut self, paths: Vec<String>) -> Task<Message> {
+        if paths.is_empty() {
+            return Task::none();
+        }
+        self.preloading_sprites = true;
+
+        cosmic::app::Task::perform(
+            async move {
+                _ = tokio::task::spawn_blocking(move || ImageCache::preload_sprites(paths)).await;
+            },
+            |_| cosmic::app::message::app(Message::PreloadedSprites),
+        )
+    }
+
+    /// Starts the window of the infinite scroll grid at the top of the list.
+    fn reset_grid_window(&mut self) {
+        self.grid_window = 0..INFINITE_SCROLL_PAGE_ROWS * 2;
+    }
+
+    /// The filtered Pokémon in the order of the table view.
+    fn table_pokemon(&self) -> Vec<&StarryPokemon> {
+        let mut pokemon_list: Vec<&StarryPokemon> = self.filtered_pokemon_list.iter().collect();
+        let (column, descending) = self.table_sort;
+
+        pokemon_list.sort_by(|a, b| {
+            let ordering = match column {
+                TableColumn::Number => a.pokemon.id.cmp(&b.pokemon.id),
+                TableColumn::Name => a.display_name().cmp(&b.display_name()),
+                TableColumn::Types => {
+                    let type_index = |p: &StarryPokemon| {
+                        p.pokemon
+                            .types
+                            .iter()
+                            .map(|t| ALL_TYPES.iter().position(|all_type| all_type == t))
+                            .collect::<Vec<Option<usize>>>()
+                    };
+                    type_index(a).cmp(&type_index(b))
+                }
+                TableColumn::Stat(stat) => stat
+                    .value(&a.pokemon.stats)
+                    .cmp(&stat.value(&b.pokemon.stats)),
+            };
+            let ordering = if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            ordering.then(a.pokemon.id.cmp(&b.pokemon.id))
+        });
+
+        pokemon_list
+    }
+
+    /// The Pokémon list as a table with a row for each Pokémon, clicking a column header sorts
+    /// the table by that column.
+    fn pokemon_table(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+        let text_scale = self.text_scale();
+
+        let stat_columns = [
+            (fl!("total"), RankedStat::Total),
+            (fl!("hp"), RankedStat::Hp),
+            (fl!("attack"), RankedStat::Attack),
+            (fl!("defense"), RankedStat::Defense),
+            (fl!("sp-a"), RankedStat::SpAttack),
+            (fl!("sp-d"), RankedStat::SpDefense),
+            (fl!("spd"), RankedStat::Speed),
+        ];
+
+        let header_cell = |label: String, column: TableColumn, width: Length| {
+            // Screen readers read the sort direction instead of the arrow
+            let (label, accessible_name) = match self.table_sort {
+                (sorted_column, true) if sorted_column == column => (
+                    format!("{label} ▼"),
+                    fl!("sorted-descending", column = label.as_str()),
+                ),
+                (sorted_column, false) if sorted_column == column => (
+                    format!("{label} ▲"),
+                    fl!("sorted-ascending", column = label.as_str()),
+                ),
+                _ => (label.clone(), label),
+            };
+            widget::button::custom(widget::text::heading(label))
+                .name(accessible_name)
+                .on_press(Message::SortTable(column))
+                .class(theme::Button::Text)
+                .padding(0)
+                .width(width)
+        };
+
+        let header = stat_columns.iter().fold(
+            widget::Row::new()
+                .push(widget::Space::with_width(Length::Fixed(TABLE_SPRITE_SIZE)))
+                .push(header_cell(
+                    String::from("#"),
+                    TableColumn::Number,
+                    Length::Fixed(TABLE_NUMBER_WIDTH),
+                ))
+                .push(header_cell(
+                    fl!("pokemon-dex"),
+                    TableColumn::Name,
+                    Length::FillPortion(3),
+                ))
+                .push(header_cell(
+                    fl!("types"),
+                    TableColumn::Types,
+                    Length::FillPortion(2),
+                )),
+            |row, (label, stat)| {
+                row.push(header_cell(
+                    label.clone(),
+                    TableColumn::Stat(*stat),
+                    Length::Fixed(TABLE_STAT_WIDTH),
+                ))
+            },
+        );
+
+        let rows = self.table_pokemon().into_iter().map(|pokemon| {
+            let sprite = match self.sprite_of(pokemon) {
+                Some(path) => widget::Image::new(
+                    ImageCache::sprite(path).unwrap_or_else(ImageCache::placeholder),
+                ),
+                None => widget::Image::new(ImageCache::get("fallback")),
+            }
+            .content_fit(cosmic::iced::ContentFit::ScaleDown)
+            .width(Length::Fixed(TABLE_SPRITE_SIZE))
+            .height(Length::Fixed(TABLE_SPRITE_SIZE));
+
+            let row =
+                stat_columns.iter().fold(
+                    widget::Row::new()
+                        .push(sprite)
+                        .push(
+                            widget::text(format!("#{:03}", pokemon.pokemon.id))
+                                .width(Length::Fixed(TABLE_NUMBER_WIDTH)),
+                        )
+                        .push(widget::text(pokemon.display_name()).width(Length::FillPortion(3)))
+                        .push(
+                            widget::Row::with_children(
+                                pokemon.pokemon.types.iter().map(|type_name| {
+                                    type_chip(type_name, color_palette, text_scale)
+                                }),
+                            )
+                            .spacing(spacing.space_xxxs)
+                            .width(Length::FillPortion(2)),
+                        ),
+                    |row, (_, stat)| {
+                        row.push(
+                            widget::text(stat.value(&pokemon.pokemon.stats).to_string())
+                                .width(Length::Fixed(TABLE_STAT_WIDTH)),
+                        )
+                    },
+                );
+
+            widget::button::custom(row.align_y(Alignment::Center))
+                .name(pokemon_accessible_name(pokemon))
+                .on_press(Message::LoadPokemon(pokemon.pokemon.id))
+                .selected(self.grid_selection == Some(pokemon.pokemon.id))
+                .class(theme::Button::MenuItem)
+                .width(Length::Fill)
+                .into()
+        });
+
+        widget::Column::new()
+            .push(header.padding([spacing.space_none, spacing.space_s]))
+            .push(
+                widget::scrollable(Column::with_children(rows).width(Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .spacing(spacing.space_xxs)
+            .width(Length::Fill)
+            .into()
+    }
+
+    /// How much larger than the theme's the text of the grid, the details page and the type
+    /// chart is
+    fn text_scale(&self) -> f32 {
+        match self.config.text_scale {
+            0 => 1.0,
+            percent => percent as f32 / 100.0,
+        }
+    }
+
+    /// Width and height of the cards of the grid, taller when the text is scaled up
+    fn card_dimensions(&self) -> (f32, f32) {
+        let (width, height) = self.config.card_size.card_dimensions();
+        (width, height + CARD_TEXT_HEIGHT * (self.text_scale() - 1.0))
+    }
+
+    /// Sprite the Pokémon is shown with: the one of the user's overrides folder, or of the
+    /// selected sprite set, if there's one, its default sprite otherwise. None if it has no sprite
+    /// or its file is missing.
+    fn sprite_of<'a>(&'a self, starry_pokemon: &'a StarryPokemon) -> Option<&'a String> {
+        let pokemon_id = starry_pokemon.pokemon.id;
+        self.sprite_overrides
+            .get(&pokemon_id)
+            .or_else(|| self.set_sprites.get(&pokemon_id))
+            .or_else(|| {
+                starry_pokemon
+                    .sprite_path
+                    .as_ref()
+                    .filter(|_| !self.missing_sprites.contains(&pokemon_id))
+            })
+    }
+
+    /// Replaces the Pokémon of data packs in the Pokémon list with the ones of the enabled packs.
+    fn merge_data_packs(&mut self) {
+        self.pokemon_list.retain(|id, _| *id < DATA_PACK_ID_START);
+
+        for (index, data_pack) in self.data_packs.iter().enumerate() {
+            if self.config.disabled_data_packs.contains(&data_pack.id) {
+                continue;
+            }
+            self.pokemon_list.extend(
+                data_pack
+                    .namespaced_pokemon(index)
+                    .map(|starry_pokemon| (starry_pokemon.pokemon.id, starry_pokemon)),
+            );
+        }
+    }
+
+    /// Opens the details of the Pokémon asked for in the command line, if any.
+    fn open_requested_pokemon(&mut self) -> Task<Message> {
+        match self
+            .open_request
+            .take()
+            .and_then(|request| request.resolve(&self.pokemon_list))
+        {
+            Some(pokemon_id) => self.update(Message::LoadPokemon(pokemon_id)),
+            None => Task::none(),
+        }
+    }
+
+    /// Moves the keyboard selection of the grid with the arrow, page, Home and End keys, Enter
+    /// opens the selected Pokémon. Returns none if the key isn't used to navigate the grid.
+    fn navigate_grid(&mut self, key: Named) -> Option<Task<Message>> {
+        const PAGE_ROWS: usize = 4;
+
+        let (groups, per_row): (Vec<Vec<i64>>, usize) = match self.view_mode {
+            ViewMode::Grid => (
+                self.grouped_pokemon()
+                    .into_iter()
+                    .map(|(_, group)| group.into_iter().map(|p| p.pokemon.id).collect())
+                    .collect(),
+                self.config.pokemon_per_row.max(1),
+            ),
+            // The table has a single Pokémon per row
+            ViewMode::Table => (
+                vec![self
+                    .table_pokemon()
+                    .into_iter()
+                    .map(|p| p.pokemon.id)
+                    .collect()],
+                1,
+            ),
+        };
+
+        let position = self.grid_selection.and_then(|selected| {
+            groups.iter().enumerate().find_map(|(group_index, group)| {
+                let index = group.iter().position(|id| *id == selected)?;
+                Some((group_index, index))
+            })
+        });
+
+        let Some((mut group_index, mut index)) = position else {
+            // Nothing selected yet, any navigation key selects the first Pokémon
+            return match key {
+                Named::End => {
+                    self.grid_selection = groups.last().and_then(|group| group.last()).copied();
+                    Some(Task::none())
+                }
+                Named::ArrowLeft
+                | Named::ArrowRight
+                | Named::ArrowUp
+                | Named::ArrowDown
+                | Named::PageUp
+                | Named::PageDown
+                | Named::Home => {
+                    self.grid_selection = groups.first().and_then(|group| group.first()).copied();
+                    Some(Task::none())
+                }
+                _ => None,
+            };
+        };
+
+        let row_down = |group_index: usize, index: usize| -> (usize, usize) {
+            let group_len = groups[group_index].len();
+            if index / per_row < (group_len - 1) / per_row {
+                (group_index, (index + per_row).min(group_len - 1))
+            } else if group_index + 1 < groups.len() {
+                let next_len = groups[group_index + 1].len();
+                (group_index + 1, (index % per_row).min(next_len - 1))
+            } else {
+                (group_index, index)
+            }
+        };
+        let row_up = |group_index: usize, index: usize| -> (usize, usize) {
+            if index >= per_row {
+                (group_index, index - per_row)
+            } else if group_index > 0 {
+                let previous_len = groups[group_index - 1].len();
+                let last_row_start = (previous_len - 1) / per_row * per_row;
+                (
+                    group_index - 1,
+                    (last_row_start + index % per_row).min(previous_len - 1),
+                )
+            } else {
+                (group_index, index)
+            }
+        };
+
+        match key {
+            Named::ArrowRight => {
+                if index + 1 < groups[group_index].len() {
+                    index += 1;
+                } else if group_index + 1 < groups.len() {
+                    (group_index, index) = (group_index + 1, 0);
+                }
+            }
+            Named::ArrowLeft => {
+                if index > 0 {
+                    index -= 1;
+                } else if group_index > 0 {
+                    group_index -= 1;
+                    index = groups[group_index].len() - 1;
+                }
+            }
+            Named::ArrowDown => (group_index, index) = row_down(group_index, index),
+            Named::ArrowUp => (group_index, index) = row_up(group_index, index),
+            Named::PageDown => {
+                for _ in 0..PAGE_ROWS {
+                    (group_index, index) = row_down(group_index, index);
+                }
+            }
+            Named::PageUp => {
+                for _ in 0..PAGE_ROWS {
+                    (group_index, index) = row_up(group_index, index);
+                }
+            }
+            Named::Home => (group_index, index) = (0, 0),
+            Named::End => {
+                group_index = groups.len() - 1;
+                index = groups[group_index].len() - 1;
+            }
+            Named::Enter => {
+                return Some(self.update(Message::LoadPokemon(groups[group_index][index])));
+            }
+            _ => return None,
+        }
+
+        self.grid_selection = Some(groups[group_index][index]);
+        Some(Task::none())
+    }
+
+    /// Handles the keys of the Vim keyboard scheme: h/l and j/k move the grid selection, / focuses
+    /// the search and gg/G jump to the first or last Pokémon. Returns none for any other key so it
+    /// reaches the key binds.
+    fn vim_key(&mut self, modifiers: Modifiers, key: &Key) -> Option<Task<Message>> {
+        let Key::Character(character) = key else {
+            self.vim_pending_g = false;
+            return None;
+        };
+        if modifiers.control() || modifiers.alt() || modifiers.logo() {
+            self.vim_pending_g = false;
+            return None;
+        }
+
+        let pending_g = std::mem::take(&mut self.vim_pending_g);
+        if character.as_str() == "/" {
+            return Some(self.update(Message::FocusSearch));
+        }
+        if self.dex_mode != DexMode::Pokemon {
+            return None;
+        }
+
+        let named = match character.as_str() {
+            "h" => Named::ArrowLeft,
+            "l" => Named::ArrowRight,
+            "j" => Named::ArrowDown,
+            "k" => Named::ArrowUp,
+            "G" => Named::End,
+            "g" if pending_g => Named::Home,
+            "g" => {
+                self.vim_pending_g = true;
+                return Some(Task::none());
+            }
+            _ => return None,
+        };
+        self.navigate_grid(named)
+    }
+
+    /// The shown list of Pokémon in the selected order, split in groups with their header.
+    fn grouped_pokemon(&self) -> Vec<(Option<String>, Vec<&StarryPokemon>)> {
+        let mut pokemon_list: Vec<&StarryPokemon> = self.filtered_pokemon_list.iter().collect();
+
+        let group_key = |starry_pokemon: &StarryPokemon| -> Option<String> {
+            match self.sort_mode {
+                SortMode::Number if !self.config.generation_headers => None,
+                SortMode::Number | SortMode::Generation => starry_pokemon
+                    .specie
+                    .as_ref()
+                    .and_then(|specie| specie.generation.clone()),
+                SortMode::Type => starry_pokemon.pokemon.types.first().cloned(),
+            }
+        };
+
+        match self.sort_mode {
+            SortMode::Number if !self.config.generation_headers => {
+                return vec![(None, pokemon_list)]
+            }
+            // Sorted by number the generations follow each other, only the headers are added
+            SortMode::Number => {}
+            SortMode::Generation => pokemon_list.sort_by_key(|p| {
+                let generation = group_key(p);
+                (
+                    generation.is_none(),
+                    generation.map(|g| generation_number(&g)),
+                    p.pokemon.id,
+                )
+            }),
+            SortMode::Type => pokemon_list.sort_by_key(|p| {
+                let type_index =
+                    group_key(p).and_then(|t| ALL_TYPES.iter().position(|all_type| *all_type == t));
+                (type_index.is_none(), type_index, p.pokemon.id)
+            }),
+        }
+
+        let mut groups: Vec<(Option<String>, Vec<&StarryPokemon>)> = Vec::new();
+        let mut current_key = None;
+        for starry_pokemon in pokemon_list {
+            let key = group_key(starry_pokemon);
+            if groups.is_empty() || key != current_key {
+                let header = match (&key, self.sort_mode) {
+                    (Some(generation), SortMode::Number | SortMode::Generation) => {
+                        crate::i18n::generation_label(generation)
+                    }
+                    (Some(type_name), _) => crate::i18n::type_label(type_name),
+                    (None, _) => fl!("unknown"),
+                };
+                groups.push((Some(header), Vec::new()));
+                current_key = key;
+            }
+            if let Some((_, group)) = groups.last_mut() {
+                group.push(starry_pokemon);
+            }
+        }
+
+        groups
+    }
+
+    /// The moves Dex page for this app.
+    pub fn moves_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let moves = match &self.moves_list {
+            LazyData::Loaded(moves) => moves,
+            _ => {
+                return widget::text::text(fl!("loading"))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .into()
+            }
+        };
+
+        let search = widget::search_input(fl!("search"), &self.moves_search)
+            .style(theme::TextInput::Search)
+            .on_input(Message::MovesSearch)
+            .line_height(LineHeight::Absolute(Pixels(30.0)))
+            .width(Length::Fill);
+
+        let type_filter = widget::dropdown(
+            &self.type_filter_options,
+            Some(self.moves_type_filter),
+            Message::MovesTypeFilter,
+        );
+
+        let damage_class_filter = widget::dropdown(
+            &self.damage_class_filter_options,
+            Some(self.moves_damage_class_filter),
+            Message::MovesDamageClassFilter,
+        );
+
+        let search_row = widget::Row::new()
+            .push(search)
+            .push(type_filter)
+            .push(damage_class_filter)
+            .spacing(Pixels::from(spacing.space_xxxs))
+            .align_y(Alignment::Center)
+            .width(Length::Fill);
+
+        let search_value = self.moves_search.to_lowercase().replace(' ', "-");
+        let selected_type = self
+            .moves_type_filter
+            .checked_sub(1)
+            .and_then(|index| ALL_TYPES.get(index));
+        let selected_damage_class = self
+            .moves_damage_class_filter
+            .checked_sub(1)
+            .and_then(|index| DAMAGE_CLASSES.get(index));
+
+        let move_row = |name: String,
+                        type_name: String,
+                        damage_class: String,
+                        power: String,
+                        accuracy: String,
+                        pp: String| {
+            widget::Row::new()
+                .push(widget::text(name).width(Length::FillPortion(3)))
+                .push(widget::text(type_name).width(Length::FillPortion(2)))
+                .push(widget::text(damage_class).width(Length::FillPortion(2)))
+                .push(widget::text(power).width(Length::FillPortion(1)))
+                .push(widget::text(accuracy).width(Length::FillPortion(1)))
+                .push(widget::text(pp).width(Length::FillPortion(1)))
+                .width(Length::Fill)
+        };
+
+        let optional_value = |value: Option<i64>| match value {
+            Some(value) => value.to_string(),
+            None => String::from("-"),
+        };
+
+        let header = move_row(
+            fl!("name"),
+            fl!("type"),
+            fl!("damage-class"),
+            fl!("power"),
+            fl!("accuracy"),
+            fl!("pp"),
+        )
+        .padding([spacing.space_none, spacing.space_s]);
+
+        let rows = moves
+            .iter()
+            .filter(|pokemon_move| {
+                pokemon_move.name.contains(&search_value)
+                    && selected_type.is_none_or(|t| pokemon_move.type_name == *t)
+                    && selected_damage_class.is_none_or(|c| pokemon_move.damage_class == *c)
+            })
+            .map(|pokemon_move| {
+                widget::button::custom(move_row(
+                    capitalize_string(&pokemon_move.name),
+                    crate::i18n::type_label(&pokemon_move.type_name),
+                    capitalize_string(&pokemon_move.damage_class),
+                    optional_value(pokemon_move.power),
+                    optional_value(pokemon_move.accuracy),
+                    optional_value(pokemon_move.pp),
+                ))
+                .on_press(Message::LoadMove(pokemon_move.id))
+                .class(theme::Button::MenuItem)
+                .width(Length::Fill)
+                .into()
+            });
+
+        widget::Column::new()
+            .push(search_row)
+            .push(header)
+            .push(
+                widget::scrollable(Column::with_children(rows).width(Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The move details context page for this app.
+    pub fn single_move_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let selected_move = match (&self.moves_list, self.selected_move) {
+            (LazyData::Loaded(moves), Some(move_id)) => moves.iter().find(|m| m.id == move_id),
+            _ => None,
+        };
+
+        let Some(pokemon_move) = selected_move else {
+            return widget::text::title1(fl!("generic-error"))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .into();
+        };
+
+        let info_row = |label: String, value: String| {
+            widget::Row::new()
+                .push(widget::text(label).width(Length::Fill))
+                .push(widget::text(value).align_x(Horizontal::Left))
+        };
+
+        let optional_value = |value: Option<i64>| match value {
+            Some(value) => value.to_string(),
+            None => String::from("-"),
+        };
+
+        let move_info = widget::container::Container::new(
+            Column::new()
+                .push(info_row(
+                    fl!("type"),
+                    crate::i18n::type_label(&pokemon_move.type_name),
+                ))
+                .push(info_row(
+                    fl!("damage-class"),
+                    capitalize_string(&pokemon_move.damage_class),
+                ))
+                .push(info_row(fl!("power"), optional_value(pokemon_move.power)))
+                .push(info_row(
+                    fl!("accuracy"),
+                    optional_value(pokemon_move.accuracy),
+                ))
+                .push(info_row(fl!("pp"), optional_value(pokemon_move.pp))),
+        )
+        .class(theme::Container::ContextDrawer)
+        .padding([spacing.space_none, spacing.space_xxs]);
+
+        let move_effect = pokemon_move.effect.as_ref().map(|effect| {
+            widget::container::Container::new(widget::text(effect).width(Length::Fill))
+                .class(theme::Container::ContextDrawer)
+                .padding([spacing.space_xxs, spacing.space_xxs])
+        });
+
+        let learned_by = pokemon_move
+            .learned_by_pokemon
+            .iter()
+            .filter_map(|name| {
+                self.pokemon_list
+                    .values()
+                    .find(|starry_pokemon| starry_pokemon.pokemon.name == *name)
+            })
+            .map(|starry_pokemon| {
+                widget::button::link(starry_pokemon.display_name())
+                    .on_press(Message::LoadPokemon(starry_pokemon.pokemon.id))
+                    .padding(0)
+                    .into()
+            });
+
+        widget::Column::new()
+            .push(
+                widget::text::title1(capitalize_string(&pokemon_move.name))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            )
+            .push(move_info)
+            .push_maybe(move_effect)
+            .push(widget::text::title4(fl!("learned-by")))
+            .push(
+                widget::container::Container::new(Column::with_children(learned_by))
+                    .class(theme::Container::ContextDrawer)
+                    .padding([spacing.space_none, spacing.space_xxs])
+                    .width(Length::Fill),
+            )
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The abilities Dex page for this app.
+    pub fn abilities_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let abilities = match &self.abilities_list {
+            LazyData::Loaded(abilities) => abilities,
+            _ => {
+                return widget::text::text(fl!("loading"))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .into()
+            }
+        };
+
+        let search = widget::search_input(fl!("search"), &self.abilities_search)
+            .style(theme::TextInput::Search)
+            .on_input(Message::AbilitiesSearch)
+            .line_height(LineHeight::Absolute(Pixels(30.0)))
+            .width(Length::Fill);
+
+        let ability_row = |name: String, effect: String, count: String| {
+            widget::Row::new()
+                .push(widget::text(name).width(Length::FillPortion(2)))
+                .push(widget::text(effect).width(Length::FillPortion(5)))
+                .push(widget::text(count).width(Length::FillPortion(1)))
+                .spacing(spacing.space_xs)
+                .width(Length::Fill)
+        };
+
+        let header = ability_row(fl!("name"), fl!("effect"), fl!("pokemon-count"))
+            .padding([spacing.space_none, spacing.space_s]);
+
+        let search_value = self.abilities_search.to_lowercase().replace(' ', "-");
+        let rows = abilities
+            .iter()
+            .filter(|ability| ability.name.contains(&search_value))
+            .map(|ability| {
+                let pokemon_count = self
+                    .ability_index
+                    .get(&ability.name)
+                    .map_or(0, |pokemon_ids| pokemon_ids.len());
+
+                widget::button::custom(ability_row(
+                    capitalize_string(&ability.name),
+                    ability.effect.clone().unwrap_or_default(),
+                    pokemon_count.to_string(),
+                ))
+                .on_press(Message::FilterByAbility(ability.name.clone()))
+                .class(theme::Button::MenuItem)
+                .width(Length::Fill)
+                .into()
+            });
+
+        widget::Column::new()
+            .push(search)
+            .push(header)
+            .push(
+                widget::scrollable(Column::with_children(rows).width(Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The berries reference page for this app.
+    pub fn berries_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let berries = match &self.berries_list {
+            LazyData::Loaded(berries) => berries,
+            _ => {
+                return widget::text::text(fl!("loading"))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .into()
+            }
+        };
+
+        let berry_row = |name: String,
+                         firmness: String,
+                         growth_time: String,
+                         max_harvest: String,
+                         natural_gift: String,
+                         flavors: String| {
+            widget::Row::new()
+                .push(widget::text(name).width(Length::FillPortion(2)))
+                .push(widget::text(firmness).width(Length::FillPortion(2)))
+                .push(widget::text(growth_time).width(Length::FillPortion(2)))
+                .push(widget::text(max_harvest).width(Length::FillPortion(1)))
+                .push(widget::text(natural_gift).width(Length::FillPortion(2)))
+                .push(widget::text(flavors).width(Length::FillPortion(3)))
+                .spacing(spacing.space_xs)
+                .width(Length::Fill)
+        };
+
+        let header = berry_row(
+            fl!("name"),
+            fl!("firmness"),
+            fl!("growth-time"),
+            fl!("max-harvest"),
+            fl!("natural-gift"),
+            fl!("flavors"),
+        );
+
+        let rows = berries.iter().map(|berry| {
+            let flavors = berry
+                .flavors
+                .iter()
+                .filter(|(_, potency)| *potency > 0)
+                .map(|(flavor, potency)| format!("{} {}", capitalize_string(flavor), potency))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            berry_row(
+                capitalize_string(&berry.name),
+                capitalize_string(&berry.firmness),
+                fl!("growth-hours", hours = berry.growth_time),
+                berry.max_harvest.to_string(),
+                format!(
+                    "{} ({})",
+                    capitalize_string(&berry.natural_gift_type),
+                    berry.natural_gift_power
+                ),
+                flavors,
+            )
+            .into()
+        });
+
+        widget::Column::new()
+            .push(widget::text::title3(fl!("berries")))
+            .push(header)
+            .push(
+                widget::scrollable(
+                    Column::with_children(rows)
+                        .spacing(spacing.space_xxs)
+                        .width(Length::Fill),
+                )
+                .width(Length::Fill)
+                .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The context page telling what changed on the watched Pokémon after renewing the cache.
+    pub fn watchlist_changes_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        if self.watchlist_changes.is_empty() {
+            return widget::text(fl!("watchlist-no-changes"))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .into();
+        }
+
+        let changes = self.watchlist_changes.iter().map(|watched| {
+            widget::Column::new()
+                .push(widget::text::title4(watched.name.clone()))
+                .push(
+                    widget::container::Container::new(Column::with_children(
+                        watched
+                            .changes
+                            .iter()
+                            .map(|change| widget::text(change.clone()).into()),
+                    ))
+                    .class(theme::Container::ContextDrawer)
+                    .padding([spacing.space_xxs, spacing.space_xxs])
+                    .width(Length::Fill),
+                )
+                .spacing(spacing.space_xxs)
+                .into()
+        });
+
+        Column::with_children(changes)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The type effectiveness chart page for this app.
+    pub fn type_chart_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+        let text_scale = self.text_scale();
+        let label_width = 90.0 * text_scale;
+        let (cell_width, cell_height) = (
+            TYPE_CHART_CELL_WIDTH * text_scale,
+            TYPE_CHART_CELL_HEIGHT * text_scale,
+        );
+
+        let (hovered_row, hovered_column) = self.hovered_type_matchup.unzip();
+
+        let header = ALL_TYPES.iter().enumerate().fold(
+            widget::Row::new().push(
+                widget::text::caption(fl!("attacking-defending"))
+                    .size(CAPTION_TEXT_SIZE * text_scale)
+                    .width(Length::Fixed(label_width))
+                    .height(Length::Fixed(cell_height)),
+            ),
+            |row, (column, defending)| {
+                let label = widget::text::heading(
+                    crate::i18n::type_label(defending)
+                        .chars()
+                        .take(3)
+                        .collect::<String>(),
+                )
+                .size(BODY_TEXT_SIZE * text_scale)
+                .width(Length::Fixed(cell_width))
+                .align_x(Horizontal::Center);
+                row.push(if hovered_column == Some(column) {
+                    label.class(theme::Text::Accent)
+                } else {
+                    label
+                })
+            },
+        );
+
+        let rows = ALL_TYPES.iter().enumerate().map(|(row, attacking)| {
+            let label = widget::text::heading(crate::i18n::type_label(attacking))
+                .size(BODY_TEXT_SIZE * text_scale)
+                .width(Length::Fixed(label_width))
+                .height(Length::Fixed(cell_height))
+                .align_y(Vertical::Center);
+            let label = if hovered_row == Some(row) {
+                label.class(theme::Text::Accent)
+            } else {
+                label
+            };
+
+            ALL_TYPES
+                .iter()
+                .enumerate()
+                .fold(
+                    widget::Row::new().push(label),
+                    |cells, (column, defending)| {
+                        let multiplier = type_chart::effectiveness(attacking, defending);
+                        let highlighted =
+                            hovered_row == Some(row) || hovered_column == Some(column);
+                        cells.push(type_chart_cell(
+                            row,
+                            column,
+                            multiplier,
+                            highlighted,
+                            color_palette,
+                            text_scale,
+                        ))
+                    },
+                )
+                .into()
+        });
+
+        let rows = Column::with_children(rows);
+        let chart = cosmic::iced::widget::mouse_area(widget::Column::new().push(header).push(rows))
+            .on_exit(Message::HoverTypeMatchup(None));
+
+        widget::Column::new()
+            .push(widget::text::title3(fl!("type-chart")))
+            .push(
+                widget::scrollable(chart)
+                    .direction(cosmic::iced::widget::scrollable::Direction::Both {
+                        vertical: Default::default(),
+                        horizontal: Default::default(),
+                    })
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The locations Dex page for this app.
+    pub fn locations_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let location_index = match &self.location_index {
+            LazyData::Loaded(location_index) => location_index,
+            _ => {
+                return widget::text::text(fl!("loading"))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .into()
+            }
+        };
+
+        let search = widget::search_input(fl!("search"), &self.locations_search)
+            .style(theme::TextInput::Search)
+            .on_input(Message::LocationsSearch)
+            .line_height(LineHeight::Absolute(Pixels(30.0)))
+            .width(Length::Fill);
+
+        let game_filter = widget::dropdown(
+            &self.location_game_options,
+            Some(self.locations_game_filter),
+            Message::LocationsGameFilter,
+        );
+
+        let search_row = widget::Row::new()
+            .push(search)
+            .push(game_filter)
+            .spacing(Pixels::from(spacing.space_xxxs))
+            .align_y(Alignment::Center)
+            .width(Length::Fill);
+
+        let selected_game = self
+            .location_game_options
+            .get(self.locations_game_filter)
+            .filter(|_| self.locations_game_filter > 0);
+        let in_selected_game = |encounter: &&StarryLocationEncounter| {
+            selected_game.is_none_or(|game| encounter.games().any(|g| g == game))
+        };
+
+        let search_value = self.locations_search.to_lowercase();
+        let locations = location_index
+            .iter()
+            .filter(|(location, encounters)| {
+                location.to_lowercase().contains(&search_value)
+                    && encounters.iter().any(|e| in_selected_game(&e))
+            })
+            .map(|(location, _)| {
+                widget::button::custom(widget::text(location.clone()))
+                    .on_press(Message::SelectLocation(location.clone()))
+                    .selected(self.selected_location.as_ref() == Some(location))
+                    .class(theme::Button::MenuItem)
+                    .width(Length::Fill)
+                    .into()
+            });
+
+        let location_pokemon = self
+            .selected_location
+            .as_ref()
+            .and_then(|location| location_index.get(location))
+            .into_iter()
+            .flatten()
+            .filter(&in_selected_game)
+            .filter_map(|encounter| {
+                let starry_pokemon = self.pokemon_list.get(&encounter.pokemon_id)?;
+                let games_method = encounter
+                    .games_method
+                    .iter()
+                    .filter(|game_method| {
+                        selected_game.is_none_or(|game| game_method.starts_with(game.as_str()))
+                    })
+                    .map(|game_method| widget::text::caption(game_method.clone()).into());
+
+                Some(
+                    widget::Column::new()
+                        .push(
+                            widget::button::link(starry_pokemon.display_name())
+                                .on_press(Message::LoadPokemon(starry_pokemon.pokemon.id))
+                                .padding(0),
+                        )
+                        .push(Column::with_children(games_method))
+                        .into(),
+                )
+            });
+
+        widget::Column::new()
+            .push(search_row)
+            .push(
+                widget::Row::new()
+                    .push(
+                        widget::scrollable(Column::with_children(locations))
+                            .width(Length::FillPortion(1))
+                            .height(Length::Fill),
+                    )
+                    .push(
+                        widget::scrollable(
+                            Column::with_children(location_pokemon)
+                                .spacing(spacing.space_xs)
+                                .width(Length::Fill),
+                        )
+                        .width(Length::FillPortion(2))
+                        .height(Length::Fill),
+                    )
+                    .spacing(spacing.space_m),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The egg groups page for this app.
+    pub fn egg_groups_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        if self.egg_group_index.is_empty() {
+            return widget::text(fl!("no-egg-groups"))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .into();
+        }
+
+        let egg_groups = self.egg_group_index.iter().map(|(egg_group, pokemon_ids)| {
+            widget::button::custom(
+                widget::Row::new()
+                    .push(widget::text(capitalize_string(egg_group)).width(Length::Fill))
+                    .push(widget::text(pokemon_ids.len().to_string())),
+            )
+            .on_press(Message::SelectEggGroup(egg_group.clone()))
+            .selected(self.selected_egg_group.as_ref() == Some(egg_group))
+            .class(theme::Button::MenuItem)
+            .width(Length::Fill)
+            .into()
+        });
+
+        let group_pokemon = self
+            .selected_egg_group
+            .as_ref()
+            .and_then(|egg_group| self.egg_group_index.get(egg_group))
+            .into_iter()
+            .flatten()
+            .filter_map(|pokemon_id| self.pokemon_list.get(pokemon_id))
+            .map(|starry_pokemon| {
+                widget::button::link(starry_pokemon.display_name())
+                    .on_press(Message::LoadPokemon(starry_pokemon.pokemon.id))
+                    .padding(0)
+                    .into()
+            });
+
+        widget::Column::new()
+            .push(widget::text::title3(fl!("egg-groups")))
+            .push(
+                widget::Row::new()
+                    .push(
+                        widget::scrollable(Column::with_children(egg_groups))
+                            .width(Length::FillPortion(1))
+                            .height(Length::Fill),
+                    )
+                    .push(
+                        widget::scrollable(
+                            Column::with_children(group_pokemon)
+                                .spacing(spacing.space_xxxs)
+                                .width(Length::Fill),
+                        )
+                        .width(Length::FillPortion(2))
+                        .height(Length::Fill),
+                    )
+                    .spacing(spacing.space_m),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The stat rankings page for this app.
+    pub fn rankings_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let stat = RankedStat::ALL
+            .get(self.ranked_stat)
+            .copied()
+            .unwrap_or(RankedStat::Total);
+        let generation = self
+            .rankings_generation_filter
+            .checked_sub(1)
+            .and_then(|index| GENERATIONS.get(index))
+            .copied();
+        let type_name = self
+            .rankings_type_filter
+            .checked_sub(1)
+            .and_then(|index| ALL_TYPES.get(index))
+            .copied();
+
+        let filters = widget::Row::new()
+            .push(widget::text(fl!("ranked-by")))
+            .push(widget::dropdown(
+                &self.ranked_stat_options,
+                Some(self.ranked_stat),
+                Message::RankedStat,
+            ))
+            .push(widget::dropdown(
+                &self.generation_filter_options,
+                Some(self.rankings_generation_filter),
+                Message::RankingsGenerationFilter,
+            ))
+            .push(widget::dropdown(
+                &self.type_filter_options,
+                Some(self.rankings_type_filter),
+                Message::RankingsTypeFilter,
+            ))
+            .spacing(spacing.space_xs)
+            .align_y(Alignment::Center);
+
+        let header = widget::Row::new()
+            .push(widget::text::heading(fl!("rank")).width(Length::FillPortion(1)))
+            .push(widget::text::heading(fl!("pokemon-dex")).width(Length::FillPortion(4)))
+            .push(widget::text::heading(stat.label()).width(Length::FillPortion(1)))
+            .padding([spacing.space_none, spacing.space_s]);
+
+        // The generation index narrows the Pokémon down before ranking them
+        let candidates: Vec<&StarryPokemon> = match generation {
+            Some(generation) => self
+                .generation_index
+                .get(generation)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| self.pokemon_list.get(id))
+                .collect(),
+            None => self.pokemon_list.values().collect(),
+        };
+        let rows = rankings::top_pokemon(candidates, stat, generation, type_name)
+            .into_iter()
+            .enumerate()
+            .map(|(index, (starry_pokemon, value))| {
+                widget::button::custom(
+                    widget::Row::new()
+                        .push(widget::text(format!("{}", index + 1)).width(Length::FillPortion(1)))
+                        .push(
+                            widget::text(starry_pokemon.display_name())
+                                .width(Length::FillPortion(4)),
+                        )
+                        .push(widget::text(value.to_string()).width(Length::FillPortion(1))),
+                )
+                .on_press(Message::LoadPokemon(starry_pokemon.pokemon.id))
+                .class(theme::Button::MenuItem)
+                .width(Length::Fill)
+                .into()
+            });
+
+        widget::Column::new()
+            .push(widget::text::title3(fl!("rankings")))
+            .push(filters)
+            .push(header)
+            .push(
+                widget::scrollable(Column::with_children(rows).width(Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// Calculator of the actual stats of the given Pokémon, shown on its page when toggled.
+    fn stat_calculator_section<'a>(
+        &'a self,
+        starry_pokemon: &'a StarryPokemon,
+    ) -> Element<'a, Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+
+        let toggle = widget::Checkbox::new(fl!("show-stat-calculator"), self.wants_stat_calculator)
+            .on_toggle(Message::ToggleStatCalculator);
+        if !self.wants_stat_calculator {
+            return toggle.into();
+        }
+
+        let calculator = &self.stat_calculator;
+        let nature = self.selected_nature.and_then(|index| NATURES.get(index));
+        let stats = calculator.stats(&starry_pokemon.pokemon.stats, nature);
+
+        let level = widget::Row::new()
+            .push(widget::text(fl!("level", level = calculator.level)).width(Length::Fill))
+            .push(
+                widget::slider(1..=MAX_LEVEL, calculator.level, Message::CalculatorLevel)
+                    .width(Length::FillPortion(2)),
+            )
+            .align_y(Alignment::Center);
+
+        let nature_row = widget::Row::new()
+            .push(widget::text(fl!("nature")).width(Length::Fill))
+            .push(widget::dropdown(
+                &self.nature_options,
+                Some(self.selected_nature.map_or(0, |index| index + 1)),
+                Message::CalculatorNature,
+            ))
+            .align_y(Alignment::Center);
+
+        let stat_labels = [
+            fl!("hp"),
+            fl!("attack"),
+            fl!("defense"),
+            fl!("sp-a"),
+            fl!("sp-d"),
+            fl!("spd"),
+        ];
+        let stat_rows = CALCULATOR_STATS.iter().zip(stat_labels).enumerate().map(
+            |(index, (nature_stat, label))| {
+                let color = match (nature, nature_stat) {
+                    (Some(nature), Some(stat)) if nature.percentage(*stat) > 100 => {
+                        Some(color_palette.positive_color())
+                    }
+                    (Some(nature), Some(stat)) if nature.percentage(*stat) < 100 => {
+                        Some(color_palette.negative_color())
+                    }
+                    _ => None,
+                };
+                let value = widget::text::heading(stats[index].to_string());
+
+                Column::new()
+                    .push(
+                        widget::Row::new()
+                            .push(widget::text(label).width(Length::Fill))
+                            .push(match color {
+                                Some(color) => value.class(theme::Text::Color(color)),
+                                None => value,
+                            }),
+                    )
+                    .push(
+                        widget::Row::new()
+                            .push(widget::text::caption(fl!("iv", iv = calculator.ivs[index])))
+                            .push(widget::slider(
+                                0..=MAX_IV,
+                                calculator.ivs[index],
+                                move |iv| Message::CalculatorIv(index, iv),
+                            ))
+                            .push(widget::text::caption(fl!("ev", ev = calculator.evs[index])))
+                            .push(widget::slider(
+                                0..=MAX_STAT_EVS,
+                                calculator.evs[index],
+                                move |evs| Message::CalculatorEvs(index, evs),
+                            ))
+                            .spacing(spacing.space_xs)
+                            .align_y(Alignment::Center),
+                    )
+                    .into()
+            },
+        );
+
+        Column::new()
+            .push(toggle)
+            .push(
+                widget::container::Container::new(
+                    Column::new()
+                        .push(level)
+                        .push(nature_row)
+                        .extend(stat_rows)
+                        .spacing(spacing.space_xxs),
+                )
+                .class(theme::Container::ContextDrawer)
+                .padding([spacing.space_xxs, spacing.space_xxs])
+                .width(Length::Fill),
+            )
+            .spacing(spacing.space_xxs)
+            .into()
+    }
+
+    /// The weekly challenge page for this app.
+    pub fn weekly_challenge_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
 
-        let type_filter_mode_selected = match self.config.type_filtering_mode {
-            TypeFilteringMode::Inclusive => 1,
-            TypeFilteringMode::Exclusive => 0,
+        let week = ChallengeWeek::current();
+        let challenge = Challenge::of_week(week);
+        let caught = self.challenge_history.caught(week);
+        let is_caught = |pokemon_id: i64| caught.is_some_and(|caught| caught.contains(&pokemon_id));
+
+        let candidates: Vec<&StarryPokemon> = self
+            .pokemon_list
+            .values()
+            .filter(|p| challenge.matches(p))
+            .collect();
+        let caught_count = candidates
+            .iter()
+            .filter(|p| is_caught(p.pokemon.id))
+            .count();
+
+        let progress = if caught_count >= challenge.goal() {
+            fl!("challenge-completed")
+        } else {
+            fl!(
+                "challenge-progress",
+                caught = caught_count,
+                goal = challenge.goal()
+            )
         };
 
-        let current_value = self.config.pokemon_per_row as u16;
-        let old_config = self.config.clone();
+        let rows = candidates.into_iter().map(|starry_pokemon| {
+            let pokemon_id = starry_pokemon.pokemon.id;
+            widget::Checkbox::new(starry_pokemon.display_name(), is_caught(pokemon_id))
+                .on_toggle(move |_| Message::ToggleChallengeCaught(pokemon_id))
+                .into()
+        });
 
-        widget::settings::view_column(vec![
-            widget::settings::section()
-                .title(fl!("appearance"))
-                .add(
-                    widget::settings::item::builder(fl!("theme")).control(widget::dropdown(
-                        &self.app_themes,
-                        Some(app_theme_selected),
-                        Message::UpdateTheme,
-                    )),
+        widget::Column::new()
+            .push(widget::text::title3(fl!("weekly-challenge")))
+            .push(widget::text::caption(fl!(
+                "challenge-week",
+                week = week.week,
+                year = week.year
+            )))
+            .push(widget::text::title4(
+                challenge.description(self.config.measurement_units),
+            ))
+            .push(
+                widget::Row::new()
+                    .push(widget::text(progress).width(Length::Fill))
+                    .push(widget::text(fl!(
+                        "challenge-streak",
+                        weeks = self.challenge_history.streak(&self.pokemon_list)
+                    ))),
+            )
+            .push(widget::text::caption(fl!("challenge-help")))
+            .push(
+                widget::scrollable(
+                    Column::with_children(rows)
+                        .spacing(spacing.space_xxs)
+                        .width(Length::Fill),
                 )
-                .add(
-                    widget::settings::item::builder(fl!("pokemon-per-row"))
-                        .description(format!("{}", current_value))
-                        .control(
-                            widget::slider(1..=10, current_value, move |new_value| {
-                                Message::UpdateConfig(Config {
-                                    app_theme: old_config.app_theme,
-                                    first_run_completed: old_config.first_run_completed,
-                                    pokemon_per_row: new_value as usize,
-                                    type_filtering_mode: old_config.type_filtering_mode,
-                                })
-                            })
-                            .step(1u16),
-                        ),
+                .width(Length::Fill)
+                .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// Entries of the command palette matching its query, best matches first. Commands are
+    /// matched by their name, Pokémon by their name or their Pokédex number.
+    fn palette_entries(&self) -> Vec<(String, Message)> {
+        let query = self.palette_query.trim();
+
+        let commands = [
+            (fl!("pokemon-dex"), MenuAction::PokemonDex),
+            (fl!("moves-dex"), MenuAction::MovesDex),
+            (fl!("items-dex"), MenuAction::ItemsDex),
+            (fl!("abilities-dex"), MenuAction::AbilitiesDex),
+            (fl!("locations-dex"), MenuAction::LocationsDex),
+            (fl!("weekly-challenge"), MenuAction::WeeklyChallenge),
+            (fl!("stat-trivia"), MenuAction::Trivia),
+            (fl!("berries"), MenuAction::Berries),
+            (fl!("natures"), MenuAction::Natures),
+            (fl!("type-chart"), MenuAction::TypeChart),
+            (fl!("egg-groups"), MenuAction::EggGroups),
+            (fl!("rankings"), MenuAction::Rankings),
+            (fl!("export-list-csv"), MenuAction::ExportCsv),
+            (fl!("export-list-json"), MenuAction::ExportJson),
+            (fl!("focus-search"), MenuAction::FocusSearch),
+            (fl!("settings"), MenuAction::Settings),
+            (fl!("about"), MenuAction::About),
+            (fl!("report-problem"), MenuAction::ReportProblem),
+        ]
+        .into_iter()
+        .map(|(label, action)| (label, action.message()))
+        .chain(std::iter::once((
+            fl!("filters-page"),
+            Message::ToggleContextPage(ContextPage::FiltersPage),
+        )))
+        .filter_map(|(label, message)| {
+            palette::fuzzy_score(query, &label).map(|score| (score, label, message))
+        });
+
+        let number = palette::parse_number(query);
+        let pokemon = self
+            .pokemon_list
+            .values()
+            .filter(|_| !query.is_empty())
+            .filter_map(|starry_pokemon| {
+                let display_name = starry_pokemon.display_name();
+                let score = if number == Some(starry_pokemon.pokemon.id) {
+                    Some(i64::MAX)
+                } else {
+                    palette::fuzzy_score(query, &display_name)
+                        .max(palette::fuzzy_score(query, &starry_pokemon.pokemon.name))
+                }?;
+
+                Some((
+                    score,
+                    format!("#{} {}", starry_pokemon.pokemon.id, display_name),
+                    Message::LoadPokemon(starry_pokemon.pokemon.id),
+                ))
+            });
+
+        let mut entries: Vec<(i64, String, Message)> = commands.chain(pokemon).collect();
+        entries.sort_by(|(a, ..), (b, ..)| b.cmp(a));
+        entries
+            .into_iter()
+            .take(MAX_PALETTE_ENTRIES)
+            .map(|(_, label, message)| (label, message))
+            .collect()
+    }
+
+    /// The command palette, a search over the app commands and the Pokémon.
+    fn command_palette(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let input = widget::text_input(fl!("command-palette-placeholder"), &self.palette_query)
+            .id(PALETTE_INPUT_ID.clone())
+            .on_input(Message::PaletteInput)
+            .on_submit(Message::PaletteSubmit)
+            .width(Length::Fill);
+
+        let entries = self
+            .palette_entries()
+            .into_iter()
+            .enumerate()
+            .map(|(index, (label, _))| {
+                widget::button::custom(widget::text(label).width(Length::Fill))
+                    .on_press(Message::PaletteActivate(index))
+                    .selected(index == self.palette_selected)
+                    .class(theme::Button::MenuItem)
+                    .width(Length::Fill)
+                    .into()
+            });
+
+        dialog_container(
+            Column::new()
+                .push(input)
+                .extend(entries)
+                .spacing(spacing.space_xxs),
+        )
+    }
+
+    /// Pokémon the number typed on the Go to Number dialog belongs to, if it's a known one.
+    fn goto_number_target(&self) -> Option<i64> {
+        palette::parse_number(&self.goto_number).filter(|id| self.pokemon_list.contains_key(id))
+    }
+
+    /// The Go to Number dialog, opens the Pokémon with the typed national Pokédex number.
+    fn goto_number_dialog(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+
+        let input = widget::text_input(fl!("go-to-number-placeholder"), &self.goto_number)
+            .id(GOTO_INPUT_ID.clone())
+            .on_input(Message::GoToNumberInput)
+            .on_submit(Message::GoToNumberSubmit)
+            .width(Length::Fill);
+
+        let target = self.goto_number_target();
+        let hint = if self.goto_number.trim().is_empty() {
+            None
+        } else {
+            Some(match target.and_then(|id| self.pokemon_list.get(&id)) {
+                Some(starry_pokemon) => widget::text::caption(starry_pokemon.display_name()),
+                None => widget::text::caption(fl!("go-to-number-invalid"))
+                    .class(theme::Text::Color(color_palette.negative_color())),
+            })
+        };
+
+        dialog_container(
+            Column::new()
+                .push(widget::text::heading(fl!("go-to-number")))
+                .push(
+                    widget::Row::new()
+                        .push(input)
+                        .push(
+                            widget::button::suggested(fl!("go"))
+                                .on_press_maybe(target.map(|_| Message::GoToNumberSubmit)),
+                        )
+                        .push(
+                            widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+                        )
+                        .spacing(spacing.space_xxs)
+                        .align_y(Alignment::Center),
                 )
-                .into(),
-            widget::settings::section()
-                .title(fl!("other"))
-                .add(
-                    widget::settings::item::builder(fl!("type-filter-mode")).control(
-                        widget::dropdown(
-                            &self.type_filter_mode,
-                            Some(type_filter_mode_selected),
-                            Message::UpdateTypeFilterMode,
-                        ),
-                    ),
+                .push_maybe(hint)
+                .spacing(spacing.space_xxs),
+        )
+    }
+
+    /// Dialog showing the sprite of the selected Pokémon, zoomed with the mouse wheel and panned by
+    /// dragging. Pixel art is scaled with nearest-neighbor so it stays sharp.
+    fn sprite_viewer_dialog(&self) -> Option<Element<Message>> {
+        let spacing = theme::active().cosmic().spacing;
+        let starry_pokemon = self.selected_pokemon.as_ref()?;
+        let sprite_path = self.sprite_of(starry_pokemon)?;
+
+        let viewer = cosmic::iced::widget::image::Viewer::new(
+            cosmic::iced::widget::image::Handle::from_path(sprite_path),
+        )
+        .filter_method(cosmic::iced::widget::image::FilterMethod::Nearest)
+        .min_scale(1.0)
+        .max_scale(16.0)
+        .scale_step(0.25)
+        .width(Length::Fill)
+        .height(Length::Fixed(400.0));
+
+        Some(dialog_container(
+            Column::new()
+                .push(
+                    widget::Row::new()
+                        .push(
+                            widget::text::heading(starry_pokemon.display_name())
+                                .width(Length::Fill),
+                        )
+                        .push(widget::button::standard(fl!("close")).on_press(Message::CloseDialog))
+                        .align_y(Alignment::Center),
                 )
-                .add(
-                    widget::settings::item::builder(fl!("renew-cache")).control(
-                        widget::button::destructive(fl!("renew-cache-button"))
-                            .on_press(Message::DeleteCache),
-                    ),
+                .push(viewer)
+                .push(widget::text::caption(fl!("sprite-viewer-hint")))
+                .spacing(spacing.space_xxs),
+        ))
+    }
+
+    /// The stat guessing game page for this app.
+    pub fn trivia_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+
+        let Some(round) = &self.trivia.round else {
+            return widget::text(fl!("loading"))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .into();
+        };
+        let answered = round.answer.is_some();
+
+        let pokemon_card = |pokemon_id: i64, side: TriviaSide| -> Element<Message> {
+            let Some(starry_pokemon) = self.pokemon_list.get(&pokemon_id) else {
+                return widget::text(fl!("generic-error")).into();
+            };
+
+            let image = match self.sprite_of(starry_pokemon) {
+                Some(path) => widget::Image::new(
+                    ImageCache::sprite(path).unwrap_or_else(ImageCache::placeholder),
+                ),
+                None => widget::Image::new(ImageCache::get("fallback")),
+            }
+            .content_fit(cosmic::iced::ContentFit::Contain)
+            .width(Length::Fixed(120.0))
+            .height(Length::Fixed(120.0));
+
+            let value = answered.then(|| {
+                widget::text::title3(round.stat.value(&starry_pokemon.pokemon.stats).to_string())
+            });
+
+            widget::button::custom(
+                Column::new()
+                    .push(image)
+                    .push(widget::text::heading(starry_pokemon.display_name()))
+                    .push_maybe(value)
+                    .align_x(Alignment::Center)
+                    .spacing(spacing.space_xxs)
+                    .width(Length::Fill),
+            )
+            .on_press_maybe((!answered).then_some(Message::TriviaAnswer(side)))
+            .selected(round.answer == Some(side))
+            .class(theme::Button::Image)
+            .width(Length::Fill)
+            .into()
+        };
+
+        let result = round.is_correct(&self.pokemon_list).map(|correct| {
+            Column::new()
+                .push(if correct {
+                    widget::text::title3(fl!("trivia-correct"))
+                        .class(theme::Text::Color(color_palette.positive_color()))
+                } else {
+                    widget::text::title3(fl!("trivia-wrong"))
+                        .class(theme::Text::Color(color_palette.negative_color()))
+                })
+                .push(
+                    widget::button::suggested(fl!("trivia-next"))
+                        .on_press(Message::TriviaNextRound),
                 )
-                .into(),
-        ])
-        .into()
+                .align_x(Alignment::Center)
+                .spacing(spacing.space_s)
+                .width(Length::Fill)
+        });
+
+        widget::Column::new()
+            .push(widget::text::title3(fl!("stat-trivia")))
+            .push(
+                widget::text::title4(fl!("trivia-question", stat = round.stat.label()))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            )
+            .push(
+                widget::Row::new()
+                    .push(pokemon_card(round.left, TriviaSide::Left))
+                    .push(pokemon_card(round.right, TriviaSide::Right))
+                    .spacing(spacing.space_m),
+            )
+            .push_maybe(result)
+            .push(
+                widget::text(fl!(
+                    "trivia-streak",
+                    streak = self.trivia.streak,
+                    best = self.trivia.best_streak
+                ))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_m)
+            .into()
     }
 
-    /// The main page for this app.
-    pub fn landing(&self) -> Element<Message> {
+    /// The natures reference page for this app.
+    pub fn natures_page(&self) -> Element<Message> {
         let spacing = theme::active().cosmic().spacing;
-        let mut pokemon_grid = widget::Grid::new().width(Length::Fill);
-
-        for (index, pokemon) in self.filtered_pokemon_list.iter().enumerate() {
-            let pokemon_image = if let Some(path) = &pokemon.sprite_path {
-                widget::Image::new(path)
-                    .content_fit(cosmic::iced::ContentFit::None)
-                    .width(Length::Fixed(100.0))
-                    .height(Length::Fixed(100.0))
+        let color_palette = self.config.color_palette;
+
+        let header = widget::Row::new()
+            .push(widget::text::heading(fl!("nature")).width(Length::Fill))
+            .push(widget::text::heading(fl!("raises")).width(Length::Fill))
+            .push(widget::text::heading(fl!("lowers")).width(Length::Fill))
+            .padding([spacing.space_none, spacing.space_s]);
+
+        let rows = NATURES.iter().enumerate().map(|(index, nature)| {
+            let (raised, lowered) = if nature.is_neutral() {
+                (widget::text("-"), widget::text("-"))
             } else {
-                widget::Image::new(ImageCache::get("fallback"))
-                    .content_fit(cosmic::iced::ContentFit::None)
-                    .width(Length::Fixed(100.0))
-                    .height(Length::Fixed(100.0))
+                (
+                    widget::text(nature.increased.label())
+                        .class(theme::Text::Color(color_palette.positive_color())),
+                    widget::text(nature.decreased.label())
+                        .class(theme::Text::Color(color_palette.negative_color())),
+                )
             };
 
-            let pokemon_container = widget::button::custom(
-                widget::Column::new()
-                    .push(pokemon_image.width(Length::Shrink))
-                    .push(
-                        widget::text::text(capitalize_string(&pokemon.pokemon.name))
-                            .width(Length::Shrink)
-                            .line_height(LineHeight::Absolute(Pixels::from(15.0))),
-                    )
+            widget::button::custom(
+                widget::Row::new()
+                    .push(widget::text(capitalize_string(nature.name)).width(Length::Fill))
+                    .push(raised.width(Length::Fill))
+                    .push(lowered.width(Length::Fill)),
+            )
+            .on_press(Message::SelectNature(index))
+            .selected(self.selected_nature == Some(index))
+            .class(theme::Button::MenuItem)
+            .width(Length::Fill)
+            .into()
+        });
+
+        widget::Column::new()
+            .push(widget::text::title3(fl!("natures")))
+            .push(widget::text::caption(fl!("natures-help")))
+            .push(header)
+            .push(
+                widget::scrollable(Column::with_children(rows).width(Length::Fill))
                     .width(Length::Fill)
-                    .align_x(Alignment::Center),
+                    .height(Length::Fill),
             )
-            .width(Length::Fixed(200.0))
-            .height(Length::Fixed(135.0))
-            .on_press_down(Message::LoadPokemon(pokemon.pokemon.id))
-            .class(theme::Button::Image)
-            .padding([spacing.space_none, spacing.space_s]);
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
 
-            // Insert a new row before adding the first Pokémon of each row
-            if index % self.config.pokemon_per_row == 0 {
-                pokemon_grid = pokemon_grid.insert_row();
-            }
+    /// The items Dex page for this app.
+    pub fn items_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
 
-            pokemon_grid = pokemon_grid.push(pokemon_container);
-        }
+        let items = match &self.items_list {
+            LazyData::Loaded(items) => items,
+            _ => {
+                return widget::text::text(fl!("loading"))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .into()
+            }
+        };
 
-        let search = widget::search_input(fl!("search"), &self.search)
+        let search = widget::search_input(fl!("search"), &self.items_search)
             .style(theme::TextInput::Search)
-            .on_input(Message::Search)
+            .on_input(Message::ItemsSearch)
             .line_height(LineHeight::Absolute(Pixels(30.0)))
             .width(Length::Fill);
 
-        let filters = widget::button::standard(fl!("filter"))
-            .class(theme::Button::Suggested)
-            .on_press(Message::ToggleContextPage(ContextPage::FiltersPage))
-            .width(Length::Shrink);
-
-        let clear_filters = widget::button::standard(fl!("clear-filters"))
-            .class(theme::Button::Destructive)
-            .on_press(Message::ClearFilters)
-            .width(Length::Shrink);
+        let category_filter = widget::dropdown(
+            &self.item_category_options,
+            Some(self.items_category_filter),
+            Message::ItemsCategoryFilter,
+        );
 
         let search_row = widget::Row::new()
             .push(search)
-            .push(filters)
-            .push(clear_filters)
+            .push(category_filter)
             .spacing(Pixels::from(spacing.space_xxxs))
+            .align_y(Alignment::Center)
             .width(Length::Fill);
 
+        let search_value = self.items_search.to_lowercase().replace(' ', "-");
+        let selected_category = self
+            .item_category_options
+            .get(self.items_category_filter)
+            .filter(|_| self.items_category_filter > 0);
+
+        let rows = items
+            .iter()
+            .filter(|item| {
+                item.name.contains(&search_value)
+                    && selected_category.is_none_or(|c| capitalize_string(&item.category) == *c)
+            })
+            .map(|item| {
+                let item_image = match &item.sprite_path {
+                    Some(path) => widget::Image::new(path),
+                    None => widget::Image::new(ImageCache::get("fallback")),
+                }
+                .width(Length::Fixed(30.0))
+                .height(Length::Fixed(30.0));
+
+                widget::button::custom(
+                    widget::Row::new()
+                        .push(item_image)
+                        .push(
+                            widget::text(capitalize_string(&item.name))
+                                .width(Length::FillPortion(3)),
+                        )
+                        .push(
+                            widget::text(capitalize_string(&item.category))
+                                .width(Length::FillPortion(2)),
+                        )
+                        .push(widget::text(item.cost.to_string()).width(Length::FillPortion(1)))
+                        .spacing(spacing.space_xs)
+                        .align_y(Alignment::Center)
+                        .width(Length::Fill),
+                )
+                .on_press(Message::LoadItem(item.id))
+                .class(theme::Button::MenuItem)
+                .width(Length::Fill)
+                .into()
+            });
+
         widget::Column::new()
             .push(search_row)
             .push(
-                widget::scrollable(
-                    widget::Container::new(pokemon_grid).align_x(Horizontal::Center),
+                widget::scrollable(Column::with_children(rows).width(Length::Fill))
+                    .width(Length::Fill)
+                    .height(Length::Fill),
+            )
+            .width(Length::Fill)
+            .spacing(spacing.space_s)
+            .into()
+    }
+
+    /// The item details context page for this app.
+    pub fn single_item_page(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let selected_item = match (&self.items_list, self.selected_item) {
+            (LazyData::Loaded(items), Some(item_id)) => items.iter().find(|i| i.id == item_id),
+            _ => None,
+        };
+
+        let Some(item) = selected_item else {
+            return widget::text::title1(fl!("generic-error"))
+                .width(Length::Fill)
+                .align_x(Horizontal::Center)
+                .into();
+        };
+
+        let item_image = match &item.sprite_path {
+            Some(path) => widget::Image::new(path),
+            None => widget::Image::new(ImageCache::get("fallback")),
+        }
+        .content_fit(cosmic::iced::ContentFit::Contain)
+        .width(Length::Fixed(80.0))
+        .height(Length::Fixed(80.0));
+
+        let info_row = |label: String, value: String| {
+            widget::Row::new()
+                .push(widget::text(label).width(Length::Fill))
+                .push(widget::text(value).align_x(Horizontal::Left))
+        };
+
+        let item_info = widget::container::Container::new(
+            Column::new()
+                .push(info_row(fl!("category"), capitalize_string(&item.category)))
+                .push(info_row(fl!("cost"), item.cost.to_string())),
+        )
+        .class(theme::Container::ContextDrawer)
+        .padding([spacing.space_none, spacing.space_xxs]);
+
+        let item_effect = item.effect.as_ref().map(|effect| {
+            widget::container::Container::new(widget::text(effect).width(Length::Fill))
+                .class(theme::Container::ContextDrawer)
+                .padding([spacing.space_xxs, spacing.space_xxs])
+        });
+
+        let evolutions: Element<Message> = match &self.evolution_item_index {
+            LazyData::Loaded(index) => match index.get(&item.id) {
+                Some(species_ids) => Column::with_children(
+                    species_ids
+                        .iter()
+                        .filter_map(|species_id| self.pokemon_list.get(species_id))
+                        .map(|starry_pokemon| {
+                            widget::button::link(starry_pokemon.display_name())
+                                .on_press(Message::LoadPokemon(starry_pokemon.pokemon.id))
+                                .padding(0)
+                                .into()
+                        }),
+                )
+                .spacing(spacing.space_xxxs)
+                .into(),
+                None => widget::text(fl!("no-item-evolutions")).into(),
+            },
+            _ => widget::text(fl!("loading")).into(),
+        };
+
+        widget::Column::new()
+            .push(
+                widget::text::title1(capitalize_string(&item.name))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            )
+            .push(
+                widget::container(item_image)
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            )
+            .push(item_info)
+            .push_maybe(item_effect)
+            .push(
+                widget::container::Container::new(
+                    Column::new()
+                        .push(widget::text::title4(fl!("evolves-with-item")))
+                        .push(evolutions)
+                        .spacing(spacing.space_xxs),
                 )
+                .class(theme::Container::ContextDrawer)
+                .padding([spacing.space_xxs, spacing.space_xxs])
                 .width(Length::Fill),
             )
-            .width(Length::Fill)
             .spacing(spacing.space_s)
             .into()
     }
 
+    /// The search operators cheatsheet, shown in a popover next to the search input.
+    pub fn search_help(&self) -> Element<Message> {
+        let spacing = theme::active().cosmic().spacing;
+
+        let operators = SEARCH_OPERATORS.iter().map(|operator| {
+            widget::Column::new()
+                .push(widget::text::heading(operator.syntax))
+                .push(widget::text::body((operator.description)()))
+                .push(
+                    widget::text::caption(format!("{} {}", fl!("example"), operator.example))
+                        .class(theme::Text::Accent),
+                )
+                .into()
+        });
+
+        widget::container(
+            widget::Column::new()
+                .push(widget::text::title4(fl!("search-help")))
+                .push(Column::with_children(operators).spacing(spacing.space_xs))
+                .spacing(spacing.space_s)
+                .width(Length::Fixed(300.0)),
+        )
+        .padding(spacing.space_s)
+        .class(theme::Container::Dropdown)
+        .into()
+    }
+
     /// The pokemon details context page for this app.
     pub fn single_pokemon_page(&self) -> Element<Message> {
         let spacing = theme::active().cosmic().spacing;
+        let color_palette = self.config.color_palette;
+
+        if self.loading_pokemon.is_some() {
+            return widget::container(
+                widget::text::text(fl!("loading"))
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center),
+            )
+            .width(Length::Fill)
+            .padding(spacing.space_l)
+            .into();
+        }
 
         let content: widget::Column<_> = match &self.selected_pokemon {
             Some(starry_pokemon) => {
-                let page_title =
-                    widget::text::title1(capitalize_string(starry_pokemon.pokemon.name.as_str()))
-                        .width(Length::Fill)
-                        .align_x(Horizontal::Center);
+                // Accents of the page tinted with the color of the primary type
+                let accent = starry_pokemon
+                    .pokemon
+                    .types
+                    .first()
+                    .filter(|_| self.config.type_accents)
+                    .map(|primary_type| type_accent_color(primary_type, color_palette));
+
+                let page_title = widget::text::title1(starry_pokemon.display_name())
+                    .width(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .class(match accent {
+                        Some(accent) => theme::Text::Color(accent),
+                        None => theme::Text::Default,
+                    });
 
-                let pokemon_image = if let Some(path) = &starry_pokemon.sprite_path {
-                    widget::Image::new(path).content_fit(cosmic::iced::ContentFit::Fill)
+                let pokemon_image = if let Some(path) = self.sprite_of(starry_pokemon) {
+                    widget::Image::new(
+                        ImageCache::sprite(path).unwrap_or_else(ImageCache::placeholder),
+                    )
+                    .content_fit(cosmic::iced::ContentFit::Fill)
                 } else {
                     widget::Image::new(ImageCache::get("fallback"))
                         .content_fit(cosmic::iced::ContentFit::Fill)
                 };
+                // Clicking the sprite opens it in the zoomable viewer
+                let pokemon_image = widget::button::custom(pokemon_image)
+                    .name(fl!("view-sprite", name = starry_pokemon.display_name()))
+                    .on_press_maybe(
+                        self.sprite_of(starry_pokemon)
+                            .map(|_| Message::OpenSpriteViewer),
+                    )
+                    .class(theme::Button::Image);
 
                 let pokemon_weight = widget::container::Container::new(
                     widget::Column::new()
                         .push(widget::text::title3(fl!("weight")))
                         .push(
-                            widget::text::text(format!(
-                                "{} Kg",
-                                scale_numbers(starry_pokemon.pokemon.weight)
+                            widget::text::text(format_weight(
+                                starry_pokemon.pokemon.weight,
+                                self.config.measurement_units,
                             ))
-                            .size(15.0),
+                            .size(15.0 * self.text_scale()),
                         )
                         .align_x(Alignment::Center)
                         .width(Length::Fill),
                 )
-                .class(theme::Container::ContextDrawer)
+                .class(details_card_class(accent))
                 .padding([spacing.space_none, spacing.space_xxs]);
 
                 let pokemon_height = widget::container::Container::new(
                     widget::Column::new()
                         .push(widget::text::title3(fl!("height")))
                         .push(
-                            widget::text::text(format!(
-                                "{} m",
-                                scale_numbers(starry_pokemon.pokemon.height)
+                            widget::text::text(format_height(
+                                starry_pokemon.pokemon.height,
+                                self.config.measurement_units,
                             ))
-                            .size(15.0),
+                            .size(15.0 * self.text_scale()),
                         )
                         .align_x(Alignment::Center)
                         .width(Length::Fill),
                 )
-                .class(theme::Container::ContextDrawer)
+                .class(details_card_class(accent))
                 .padding([spacing.space_none, spacing.space_xxs]);
 
                 let pokemon_types = widget::container::Container::new(Column::with_children(
                     starry_pokemon.pokemon.types.iter().map(|poke_type| {
                         widget::Row::new()
                             .push(
-                                widget::text(poke_type.to_uppercase())
+                                widget::text(crate::i18n::type_label(poke_type).to_uppercase())
                                     .width(Length::Fill)
                                     .align_x(Horizontal::Center),
                             )
@@ -749,7 +5551,7 @@ impl StarryDex {
                             .into()
                     }),
                 ))
-                .class(theme::Container::ContextDrawer)
+                .class(details_card_class(accent))
                 .padding([spacing.space_none, spacing.space_xxs]);
 
                 let pokemon_abilities = widget::container::Container::new(Column::with_children(
@@ -764,66 +5566,292 @@ impl StarryDex {
                             .into()
                     }),
                 ))
-                .class(theme::Container::ContextDrawer)
+                .class(details_card_class(accent))
                 .padding([spacing.space_none, spacing.space_xxs]);
 
-                let pokemon_stats = widget::container::Container::new(
-                    Column::new()
-                        .push(
-                            widget::Row::new()
-                                .push(widget::text(fl!("hp")).width(Length::Fill))
-                                .push(
-                                    widget::text(starry_pokemon.pokemon.stats.hp.to_string())
-                                        .align_x(Horizontal::Left),
-                                ),
-                        )
+                let percentiles = self.stat_percentiles.get(&starry_pokemon.pokemon.id);
+                let stats = &starry_pokemon.pokemon.stats;
+                let stat_rows = [
+                    (fl!("hp"), RankedStat::Hp),
+                    (fl!("attack"), RankedStat::Attack),
+                    (fl!("defense"), RankedStat::Defense),
+                    (fl!("sp-a"), RankedStat::SpAttack),
+                    (fl!("sp-d"), RankedStat::SpDefense),
+                    (fl!("spd"), RankedStat::Speed),
+                    (fl!("total"), RankedStat::Total),
+                ]
+                .into_iter()
+                .map(|(label, stat)| {
+                    let percentile = percentiles.and_then(|percentiles| {
+                        RankedStat::ALL
+                            .iter()
+                            .position(|s| *s == stat)
+                            .map(|index| percentiles[index])
+                    });
+
+                    widget::Row::new()
+                        .push(widget::text(label).width(Length::Fill))
+                        .push_maybe(percentile.map(|percentile| {
+                            widget::text::caption(fl!("stat-percentile", percentile = percentile))
+                        }))
                         .push(
-                            widget::Row::new()
-                                .push(widget::text(fl!("attack")).width(Length::Fill))
-                                .push(
-                                    widget::text(starry_pokemon.pokemon.stats.attack.to_string())
-                                        .align_x(Horizontal::Left),
-                                ),
+                            widget::text(stat.value(stats).to_string())
+                                .width(Length::Fixed(40.0))
+                                .align_x(Horizontal::Right)
+                                .class(match accent {
+                                    Some(accent) => theme::Text::Color(accent),
+                                    None => theme::Text::Default,
+                                }),
                         )
-                        .push(
+                        .spacing(spacing.space_xs)
+                        .align_y(Alignment::Center)
+                        .into()
+                });
+
+                let pokemon_stats =
+                    widget::container::Container::new(Column::with_children(stat_rows))
+                        .class(details_card_class(accent))
+                        .padding([spacing.space_none, spacing.space_xxs]);
+
+                let pokemon_ev_yield = starry_pokemon.pokemon.ev_yield.as_ref().map(|ev_yield| {
+                    let yields: Vec<String> = [
+                        (ev_yield.hp, fl!("hp")),
+                        (ev_yield.attack, fl!("attack")),
+                        (ev_yield.defense, fl!("defense")),
+                        (ev_yield.sp_attack, fl!("sp-a")),
+                        (ev_yield.sp_defense, fl!("sp-d")),
+                        (ev_yield.speed, fl!("spd")),
+                    ]
+                    .into_iter()
+                    .filter(|(effort, _)| *effort > 0)
+                    .map(|(effort, stat_name)| format!("{} {}", effort, stat_name))
+                    .collect();
+
+                    widget::container::Container::new(
+                        widget::Row::new()
+                            .push(widget::text(fl!("ev-yield")).width(Length::Fill))
+                            .push(widget::text(yields.join(", ")).align_x(Horizontal::Left)),
+                    )
+                    .class(details_card_class(accent))
+                    .padding([spacing.space_none, spacing.space_xxs])
+                });
+
+                let pokemon_specie_info = starry_pokemon.specie.as_ref().map(|specie| {
+                    let info_row = |label: String, value: String| {
+                        widget::Row::new()
+                            .push(widget::text(label).width(Length::Fill))
+                            .push(widget::text(value).align_x(Horizontal::Left))
+                    };
+
+                    widget::container::Container::new(
+                        Column::new()
+                            .push(info_row(
+                                fl!("base-experience"),
+                                starry_pokemon
+                                    .pokemon
+                                    .base_experience
+                                    .map(|exp| exp.to_string())
+                                    .unwrap_or_else(|| String::from("-")),
+                            ))
+                            .push(info_row(
+                                fl!("capture-rate"),
+                                specie.capture_rate.to_string(),
+                            ))
+                            .push(info_row(
+                                fl!("base-happiness"),
+                                specie
+                                    .base_happiness
+                                    .map(|happiness| happiness.to_string())
+                                    .unwrap_or_else(|| String::from("-")),
+                            ))
+                            .push(info_row(fl!("growth-rate"), specie.growth_rate.clone())),
+                    )
+                    .class(details_card_class(accent))
+                    .padding([spacing.space_none, spacing.space_xxs])
+                });
+
+                let pokemon_breeding_info = starry_pokemon.specie.as_ref().and_then(|specie| {
+                    if specie.gender_rate.is_none() && specie.hatch_counter.is_none() {
+                        return None;
+                    }
+
+                    let mut breeding_column = Column::new().spacing(spacing.space_xxs);
+                    if let Some(gender_rate) = specie.gender_rate {
+                        breeding_column = breeding_column.push(gender_ratio_bar(gender_rate));
+                    }
+                    if let Some(hatch_counter) = specie.hatch_counter {
+                        breeding_column = breeding_column.push(
                             widget::Row::new()
-                                .push(widget::text(fl!("defense")).width(Length::Fill))
+                                .push(widget::text(fl!("hatch-counter")).width(Length::Fill))
+                                .push(widget::text(fl!(
+                                    "egg-cycles",
+                                    cycles = hatch_counter,
+                                    steps = hatch_counter * STEPS_PER_EGG_CYCLE
+                                ))),
+                        );
+                    }
+
+                    Some(
+                        widget::container::Container::new(breeding_column)
+                            .class(details_card_class(accent))
+                            .padding([spacing.space_xxs, spacing.space_xxs]),
+                    )
+                });
+
+                let pokemon_held_items = self
+                    .selected_pokemon_details
+                    .as_ref()
+                    .map(|details| &details.held_items)
+                    .filter(|held_items| !held_items.is_empty())
+                    .map(|held_items| {
+                        // Group the held items by game, keeping the order of the games
+                        let mut games: Vec<(&str, Vec<String>)> = Vec::new();
+                        for held_item in held_items {
+                            for detail in &held_item.version_details {
+                                let item = format!("{} ({}%)", held_item.item, detail.rarity);
+                                match games.iter_mut().find(|(game, _)| *game == detail.version) {
+                                    Some((_, items)) => items.push(item),
+                                    None => games.push((detail.version.as_str(), vec![item])),
+                                }
+                            }
+                        }
+
+                        let children = games.into_iter().map(|(game, items)| {
+                            widget::Column::new()
                                 .push(
-                                    widget::text(starry_pokemon.pokemon.stats.defense.to_string())
-                                        .align_x(Horizontal::Left),
-                                ),
+                                    widget::text(game)
+                                        .class(accent_text_class(accent))
+                                        .size(15.0 * self.text_scale()),
+                                )
+                                .push(widget::text(items.join(", ")))
+                                .width(Length::Fill)
+                                .into()
+                        });
+
+                        widget::container::Container::new(
+                            Column::new()
+                                .push(widget::text::title4(fl!("held-items")))
+                                .push(Column::with_children(children)),
                         )
-                        .push(
+                        .class(details_card_class(accent))
+                        .padding([spacing.space_none, spacing.space_xxs])
+                    });
+
+                let pokemon_evolution = self
+                    .selected_pokemon_details
+                    .as_ref()
+                    .map(|details| &details.evolution_chain)
+                    .filter(|evolution_chain| evolution_chain.len() > 1)
+                    .map(|evolution_chain| {
+                        let stage_name = |species: &str| {
+                            self.pokemon_list
+                                .values()
+                                .find(|p| p.pokemon.name == species)
+                                .map(|p| p.display_name())
+                                .unwrap_or_else(|| capitalize_string(species))
+                        };
+
+                        let viewed_stage = evolution_chain
+                            .iter()
+                            .find(|stage| stage.is_pokemon(&starry_pokemon.pokemon));
+                        let evolves_from = viewed_stage.and_then(|stage| {
+                            let evolves_from = stage.evolves_from.as_deref()?;
+                            let requirements = stage.requirement_labels();
+                            Some(widget::text(if requirements.is_empty() {
+                                fl!("evolves-from", pokemon = stage_name(evolves_from))
+                            } else {
+                                fl!(
+                                    "evolves-from-with",
+                                    pokemon = stage_name(evolves_from),
+                                    requirement =
+                                        requirements.join(&format!(" {} ", fl!("evolution-or")))
+                                )
+                            }))
+                        });
+
+                        let stages = evolution_chain.iter().map(|stage| {
+                            let is_viewed = viewed_stage
+                                .is_some_and(|viewed| viewed.species_id == stage.species_id);
+                            let name: Element<Message> = if is_viewed {
+                                widget::text::heading(fl!(
+                                    "viewed-evolution",
+                                    pokemon = stage_name(&stage.species)
+                                ))
+                                .class(accent_text_class(accent))
+                                .into()
+                            } else {
+                                widget::button::link(stage_name(&stage.species))
+                                    .on_press(Message::LoadPokemon(stage.species_id))
+                                    .padding(0)
+                                    .into()
+                            };
+
                             widget::Row::new()
-                                .push(widget::text(fl!("sp-a")).width(Length::Fill))
-                                .push(
-                                    widget::text(
-                                        starry_pokemon.pokemon.stats.sp_attack.to_string(),
-                                    )
-                                    .align_x(Horizontal::Left),
-                                ),
+                                .push(widget::Space::with_width(Length::Fixed(
+                                    (stage.depth as f32) * 16.0,
+                                )))
+                                .push(name)
+                                .push(Column::with_children(
+                                    stage.requirement_labels().into_iter().enumerate().map(
+                                        |(index, requirements)| {
+                                            // Every way after the first is an alternative
+                                            widget::text::caption(match index {
+                                                0 => requirements,
+                                                _ => fl!(
+                                                    "evolution-or-requirement",
+                                                    requirement = requirements
+                                                ),
+                                            })
+                                            .into()
+                                        },
+                                    ),
+                                ))
+                                .spacing(spacing.space_xxs)
+                                .align_y(Alignment::Center)
+                                .into()
+                        });
+
+                        widget::container::Container::new(
+                            Column::new()
+                                .push(widget::text::title4(fl!("evolution")))
+                                .push_maybe(evolves_from)
+                                .extend(stages)
+                                .spacing(spacing.space_xxxs),
                         )
-                        .push(
-                            widget::Row::new()
-                                .push(widget::text(fl!("sp-d")).width(Length::Fill))
+                        .class(details_card_class(accent))
+                        .padding([spacing.space_xxs, spacing.space_xxs])
+                        .width(Length::Fill)
+                    });
+
+                let pokemon_game_availability = starry_pokemon
+                    .pokemon
+                    .available_games
+                    .as_ref()
+                    .map(|games| {
+                        let chips = MAIN_SERIES_GAMES.iter().map(|(game, label)| {
+                            let available = games.iter().any(|g| g == game);
+                            widget::tooltip(
+                                game_chip(label, available),
+                                widget::text(capitalize_string(game)),
+                                widget::tooltip::Position::Top,
+                            )
+                            .into()
+                        });
+
+                        widget::container::Container::new(
+                            Column::new()
+                                .push(widget::text::title4(fl!("game-availability")))
                                 .push(
-                                    widget::text(
-                                        starry_pokemon.pokemon.stats.sp_defense.to_string(),
-                                    )
-                                    .align_x(Horizontal::Left),
-                                ),
+                                    widget::flex_row(chips.collect())
+                                        .row_spacing(spacing.space_xxxs)
+                                        .column_spacing(spacing.space_xxxs),
+                                )
+                                .spacing(spacing.space_xxs),
                         )
-                        .push(
-                            widget::Row::new()
-                                .push(widget::text(fl!("spd")).width(Length::Fill))
-                                .push(
-                                    widget::text(starry_pokemon.pokemon.stats.speed.to_string())
-                                        .align_x(Horizontal::Left),
-                                ),
-                        ),
-                )
-                .class(theme::Container::ContextDrawer)
-                .padding([spacing.space_none, spacing.space_xxs]);
+                        .class(details_card_class(accent))
+                        .padding([spacing.space_xxs, spacing.space_xxs])
+                        .width(Length::Fill)
+                    });
 
                 let pokemon_first_row = widget::Row::new()
                     .push(pokemon_weight)
@@ -832,12 +5860,45 @@ impl StarryDex {
                     .spacing(8.0)
                     .align_y(Alignment::Center);
 
+                let pokemon_genus = starry_pokemon
+                    .specie
+                    .as_ref()
+                    .and_then(|specie| specie.localized_genus())
+                    .map(|genus| {
+                        widget::text::text(genus)
+                            .width(Length::Fill)
+                            .align_x(Horizontal::Center)
+                    });
+
+                let pokemon_flavor_text = self
+                    .selected_pokemon_details
+                    .as_ref()
+                    .and_then(|details| details.localized_flavor_text())
+                    .map(|flavor_text| {
+                        widget::container::Container::new(
+                            widget::text::text(flavor_text)
+                                .width(Length::Fill)
+                                .align_x(Horizontal::Center),
+                        )
+                        .class(details_card_class(accent))
+                        .padding([spacing.space_xxs, spacing.space_xxs])
+                    });
+
                 let mut result_col = widget::Column::new()
                     .push(page_title)
+                    .push_maybe(pokemon_genus)
                     .push(pokemon_image)
+                    .push_maybe(pokemon_flavor_text)
                     .push(pokemon_first_row)
                     .push(pokemon_abilities)
                     .push(pokemon_stats)
+                    .push_maybe(pokemon_ev_yield)
+                    .push(self.stat_calculator_section(starry_pokemon))
+                    .push_maybe(pokemon_specie_info)
+                    .push_maybe(pokemon_breeding_info)
+                    .push_maybe(pokemon_held_items)
+                    .push_maybe(pokemon_evolution)
+                    .push_maybe(pokemon_game_availability)
                     .align_x(Alignment::Center)
                     .spacing(10.0);
 
@@ -847,14 +5908,18 @@ impl StarryDex {
                 )
                 .on_toggle(Message::TogglePokemonDetails);
 
-                let encounter_info = match &starry_pokemon.encounter_info {
-                    Some(info) => {
+                let encounter_info = match self
+                    .selected_pokemon_details
+                    .as_ref()
+                    .map(|details| &details.encounter_info)
+                {
+                    Some(info) if !info.is_empty() => {
                         let children = info.iter().map(|ef| {
                             let mut version_column = widget::Column::new().width(Length::Fill);
                             version_column = version_column.push(
                                 widget::text(capitalize_string(&ef.city))
-                                    .class(theme::Text::Accent)
-                                    .size(Pixels::from(15)),
+                                    .class(accent_text_class(accent))
+                                    .size(15.0 * self.text_scale()),
                             );
 
                             for method in &ef.games_method {
@@ -865,11 +5930,13 @@ impl StarryDex {
                         });
 
                         widget::container::Container::new(Column::with_children(children))
-                            .class(theme::Container::ContextDrawer)
+                            .class(details_card_class(accent))
                             .padding([spacing.space_none, spacing.space_xxs])
                     }
-                    None => widget::Container::new(widget::Text::new(fl!("no-encounter-info")))
-                        .class(theme::Container::ContextDrawer),
+                    Some(_) => widget::Container::new(widget::Text::new(fl!("no-encounter-info")))
+                        .class(details_card_class(accent)),
+                    None => widget::Container::new(widget::Text::new(fl!("loading")))
+                        .class(details_card_class(accent)),
                 };
 
                 let link = widget::button::link(fl!("link-more-info"))
@@ -879,16 +5946,58 @@ impl StarryDex {
                     )))
                     .padding(0);
 
-                if starry_pokemon.encounter_info.is_some()
-                    && !starry_pokemon.encounter_info.clone().unwrap().is_empty()
+                if let Some(details) = self
+                    .selected_pokemon_details
+                    .as_ref()
+                    .filter(|details| !details.encounter_info.is_empty())
                 {
-                    result_col = result_col.push(show_details);
-                    if self.wants_pokemon_details {
-                        result_col = result_col.push(encounter_info);
-                    }
+                    result_col = result_col.push(
+                        widget::container::Container::new(
+                            Column::new()
+                                .push(widget::text::title4(fl!("encounters")))
+                                .push(encounter_matrix(&details.encounter_info))
+                                .spacing(spacing.space_xxs),
+                        )
+                        .class(details_card_class(accent))
+                        .padding([spacing.space_xxs, spacing.space_xxs])
+                        .width(Length::Fill),
+                    );
                 }
+                result_col = result_col.push(show_details);
+                if self.wants_pokemon_details {
+                    result_col = result_col.push(encounter_info);
+                }
+
+                let is_watched = self.config.watchlist.contains(&starry_pokemon.pokemon.id);
+                let watch = widget::button::standard(if is_watched {
+                    fl!("unwatch")
+                } else {
+                    fl!("watch")
+                })
+                .on_press(Message::ToggleWatched(starry_pokemon.pokemon.id));
+
+                let export = widget::button::standard(fl!("export-details")).on_press_maybe(
+                    self.selected_pokemon_details
+                        .is_some()
+                        .then_some(Message::ExportPokemonDetails),
+                );
 
-                result_col = result_col.push(link);
+                let showdown = widget::button::standard(fl!("copy-showdown"))
+                    .on_press(Message::CopyShowdownSet);
+                let share =
+                    widget::button::standard(fl!("share-card")).on_press(Message::SaveShareCard);
+
+                result_col = result_col.push(
+                    widget::Row::new()
+                        .push(link)
+                        .push(widget::horizontal_space())
+                        .push(watch)
+                        .push(showdown)
+                        .push(share)
+                        .push(export)
+                        .spacing(spacing.space_xxs)
+                        .align_y(Alignment::Center),
+                );
                 return result_col.into();
             }
             None => {
@@ -910,42 +6019,17 @@ impl StarryDex {
 
     /// The filters context page for this app.
     pub fn filters_page(&self) -> Element<Message> {
-        // TODO: Pokémon Types can't be transated because they need to match so the filtering works.
-        //let all_pokemon_types = vec![
-        //    fl!("normal"),
-        //    fl!("fire"),
-        //    fl!("water"),
-        //    fl!("electric"),
-        //    fl!("grass"),
-        //    fl!("ice"),
-        //    fl!("fighting"),
-        //    fl!("poison"),
-        //    fl!("ground"),
-        //    fl!("flying"),
-        //    fl!("psychic"),
-        //    fl!("bug"),
-        //    fl!("rock"),
-        //    fl!("ghost"),
-        //    fl!("dragon"),
-        //    fl!("dark"),
-        //    fl!("steel"),
-        //    fl!("fairy"),
-        //];
-        let all_pokemon_types = vec![
-            "Normal", "Fire", "Water", "Electric", "Grass", "Ice", "Fighting", "Poison", "Ground",
-            "Flying", "Psychic", "Bug", "Rock", "Ghost", "Dragon", "Dark", "Steel", "Fairy",
-        ];
-
-        let type_checkboxes: Vec<Element<Message>> = all_pokemon_types
+        // The filter keeps the types as PokéApi names them, only the label is localized
+        let type_checkboxes: Vec<Element<Message>> = ALL_TYPES
             .into_iter()
             .map(|pokemon_type| {
                 let is_checked = self.filters.selected_types.contains(pokemon_type);
-                let checkbox: Element<Message> =
-                    widget::checkbox::Checkbox::new(pokemon_type, is_checked)
-                        .on_toggle(move |value| {
-                            Message::TypeFilterToggled(value, pokemon_type.to_string())
-                        })
-                        .into();
+                let checkbox: Element<Message> = widget::checkbox::Checkbox::new(
+                    crate::i18n::type_label(pokemon_type),
+                    is_checked,
+                )
+                .on_toggle(move |value| Message::TypeFilterToggled(value, pokemon_type.to_string()))
+                .into();
 
                 widget::Container::new(checkbox).width(Length::Fill).into()
             })
@@ -1007,6 +6091,600 @@ impl StarryDex {
     }
 }
 
+/// Number of a generation as PokéApi names it ("generation-iii" is 3), used to order them
+fn generation_number(generation: &str) -> u32 {
+    generation
+        .trim_start_matches("generation-")
+        .chars()
+        .rev()
+        .fold((0, 0), |(total, previous), numeral| {
+            let value = match numeral {
+                'i' => 1,
+                'v' => 5,
+                'x' => 10,
+                _ => 0,
+            };
+            if value < previous {
+                (total - value, previous)
+            } else {
+                (total + value, value)
+            }
+        })
+        .0
+}
+
+/// Maps every egg group to the ids of the Pokémon in it
+fn build_egg_group_index(
+    pokemon_list: &BTreeMap<i64, StarryPokemon>,
+) -> BTreeMap<String, Vec<i64>> {
+    let mut egg_group_index: BTreeMap<String, Vec<i64>> = BTreeMap::new();
+
+    for starry_pokemon in pokemon_list.values() {
+        let egg_groups = starry_pokemon
+            .specie
+            .as_ref()
+            .and_then(|specie| specie.egg_groups.as_ref());
+
+        for egg_group in egg_groups.into_iter().flatten() {
+            egg_group_index
+                .entry(egg_group.clone())
+                .or_default()
+                .push(starry_pokemon.pokemon.id);
+        }
+    }
+
+    egg_group_index
+}
+
+/// Maps every type (lowercase) to the ids of the Pokémon of that type
+fn build_type_index(pokemon_list: &BTreeMap<i64, StarryPokemon>) -> HashMap<String, BTreeSet<i64>> {
+    let mut type_index: HashMap<String, BTreeSet<i64>> = HashMap::new();
+
+    for starry_pokemon in pokemon_list.values() {
+        for type_name in &starry_pokemon.pokemon.types {
+            type_index
+                .entry(type_name.to_lowercase())
+                .or_default()
+                .insert(starry_pokemon.pokemon.id);
+        }
+    }
+
+    type_index
+}
+
+/// Maps every generation to the ids of the Pokémon introduced in it
+fn build_generation_index(
+    pokemon_list: &BTreeMap<i64, StarryPokemon>,
+) -> HashMap<String, Vec<i64>> {
+    let mut generation_index: HashMap<String, Vec<i64>> = HashMap::new();
+
+    for starry_pokemon in pokemon_list.values() {
+        let generation = starry_pokemon
+            .specie
+            .as_ref()
+            .and_then(|specie| specie.generation.clone());
+
+        if let Some(generation) = generation {
+            generation_index
+                .entry(generation)
+                .or_default()
+                .push(starry_pokemon.pokemon.id);
+        }
+    }
+
+    generation_index
+}
+
+/// Maps every ability to the ids of the Pokémon that have it (hidden or not)
+fn build_ability_index(pokemon_list: &BTreeMap<i64, StarryPokemon>) -> HashMap<String, Vec<i64>> {
+    let mut ability_index: HashMap<String, Vec<i64>> = HashMap::new();
+
+    for starry_pokemon in pokemon_list.values() {
+        for ability in &starry_pokemon.pokemon.abilities {
+            ability_index
+                .entry(ability.trim_end_matches(" (HIDDEN)").to_string())
+                .or_default()
+                .push(starry_pokemon.pokemon.id);
+        }
+    }
+
+    ability_index
+}
+
+/// Ratio bar of the male/female chance of a Pokémon.
+///
+/// The gender rate is the chance of being female in eighths, -1 if the Pokémon is genderless.
+fn gender_ratio_bar<'a>(gender_rate: i64) -> Element<'a, Message> {
+    if gender_rate < 0 {
+        return widget::text(fl!("genderless"))
+            .width(Length::Fill)
+            .align_x(Horizontal::Center)
+            .into();
+    }
+
+    let female_eighths = gender_rate.clamp(0, 8) as u16;
+    let male_eighths = 8 - female_eighths;
+
+    let bar_segment = |portion: u16, color: cosmic::iced::Color| {
+        widget::container(widget::Space::new(Length::Fill, Length::Fixed(8.0)))
+            .width(Length::FillPortion(portion))
+            .class(theme::Container::custom(move |_theme| {
+                cosmic::iced::widget::container::Style {
+                    background: Some(cosmic::iced::Background::Color(color)),
+                    border: cosmic::iced::Border {
+                        radius: 4.0.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            }))
+    };
+
+    let mut bar = widget::Row::new().spacing(2.0).width(Length::Fill);
+    if male_eighths > 0 {
+        bar = bar.push(bar_segment(male_eighths, MALE_COLOR));
+    }
+    if female_eighths > 0 {
+        bar = bar.push(bar_segment(female_eighths, FEMALE_COLOR));
+    }
+
+    let labels = widget::Row::new()
+        .push(
+            widget::text(format!("♂ {}%", f64::from(male_eighths) * 12.5))
+                .width(Length::Fill)
+                .align_x(Horizontal::Left),
+        )
+        .push(
+            widget::text(format!("♀ {}%", f64::from(female_eighths) * 12.5))
+                .width(Length::Fill)
+                .align_x(Horizontal::Right),
+        );
+
+    widget::Column::new()
+        .push(widget::text(fl!("gender-ratio")))
+        .push(bar)
+        .push(labels)
+        .spacing(2.0)
+        .into()
+}
+
+/// Short label of a game, highlighted if the Pokémon is available in it.
+fn game_chip<'a>(label: &'a str, available: bool) -> Element<'a, Message> {
+    widget::container(
+        widget::text::caption(label)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center),
+    )
+    .width(Length::Fixed(32.0))
+    .padding([2, 0])
+    .class(theme::Container::custom(move |theme| {
+        let cosmic_theme = theme.cosmic();
+        let (background, text_color) = if available {
+            (cosmic_theme.accent_color(), cosmic_theme.on_accent_color())
+        } else {
+            (cosmic_theme.bg_component_color(), cosmic_theme.bg_divider())
+        };
+
+        cosmic::iced::widget::container::Style {
+            background: Some(cosmic::iced::Background::Color(background.into())),
+            text_color: Some(text_color.into()),
+            border: cosmic::iced::Border {
+                radius: 4.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }))
+    .into()
+}
+
+/// Color a type tints the details page with, its canonical color in the given palette.
+fn type_accent_color(type_name: &str, palette: ColorPalette) -> cosmic::iced::Color {
+    palette.type_color(type_chart::type_color(type_name))
+}
+
+/// Class of the cards of the details page, bordered with the type accent if there's one.
+fn details_card_class(accent: Option<cosmic::iced::Color>) -> theme::Container<'static> {
+    let Some(accent) = accent else {
+        return theme::Container::ContextDrawer;
+    };
+
+    theme::Container::custom(move |theme| {
+        let cosmic_theme = theme.cosmic();
+        cosmic::iced::widget::container::Style {
+            background: Some(cosmic::iced::Background::Color(
+                cosmic_theme.primary.base.into(),
+            )),
+            text_color: Some(cosmic_theme.primary.on.into()),
+            border: cosmic::iced::Border {
+                radius: cosmic_theme.corner_radii.radius_s.into(),
+                width: 1.0,
+                color: accent,
+            },
+            ..Default::default()
+        }
+    })
+}
+
+/// Class of the highlighted texts of the details page, the type accent if there's one.
+fn accent_text_class(accent: Option<cosmic::iced::Color>) -> theme::Text {
+    match accent {
+        Some(accent) => theme::Text::Color(accent),
+        None => theme::Text::Accent,
+    }
+}
+
+/// What screen readers say for a Pokémon of the grid or the table, its number, name and types
+/// read as one sentence instead of the separate labels of the card.
+fn pokemon_accessible_name(pokemon: &StarryPokemon) -> String {
+    fl!(
+        "pokemon-accessible-name",
+        number = pokemon.pokemon.id,
+        name = pokemon.display_name(),
+        types = pokemon
+            .pokemon
+            .types
+            .iter()
+            .map(|type_name| crate::i18n::type_label(type_name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// Opacity of a placeholder, the placeholders at the shimmer's position are the lightest and it
+/// moves one placeholder further each frame.
+fn skeleton_shade(frame: usize, index: usize) -> f32 {
+    let position =
+        (frame + SKELETON_WAVE_LENGTH - index % SKELETON_WAVE_LENGTH) % SKELETON_WAVE_LENGTH;
+    let distance =
+        position.min(SKELETON_WAVE_LENGTH - position) as f32 / SKELETON_WAVE_LENGTH as f32;
+    0.08 + 0.1 * (1.0 - 2.0 * distance)
+}
+
+/// Rounded block standing in for content that isn't ready yet.
+fn skeleton_block<'a>(width: f32, height: f32, shade: f32) -> Element<'a, Message> {
+    widget::container(widget::Space::new(Length::Fill, Length::Fill))
+        .width(Length::Fixed(width))
+        .height(Length::Fixed(height))
+        .class(theme::Container::custom(move |theme| {
+            let color: cosmic::iced::Color = theme.cosmic().on_bg_color().into();
+            cosmic::iced::widget::container::Style {
+                background: Some(cosmic::iced::Background::Color(cosmic::iced::Color {
+                    a: shade,
+                    ..color
+                })),
+                border: cosmic::iced::Border {
+                    radius: 4.0.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        }))
+        .into()
+}
+
+/// Type of a Pokémon as a small chip with the color of the type as background.
+fn type_chip<'a>(type_name: &str, palette: ColorPalette, text_scale: f32) -> Element<'a, Message> {
+    let color = type_accent_color(type_name, palette);
+
+    widget::container(
+        widget::text::caption(crate::i18n::type_label(type_name).to_uppercase())
+            .size(CAPTION_TEXT_SIZE * text_scale)
+            .line_height(LineHeight::Relative(1.4)),
+    )
+    .padding([0, 6])
+    .class(theme::Container::custom(move |_theme| {
+        cosmic::iced::widget::container::Style {
+            background: Some(cosmic::iced::Background::Color(color)),
+            text_color: Some(cosmic::iced::Color::WHITE),
+            border: cosmic::iced::Border {
+                radius: 4.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }))
+    .into()
+}
+
+/// Frame of the dialogs shown over the app.
+fn dialog_container<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+    let spacing = theme::active().cosmic().spacing;
+
+    widget::container(content)
+        .padding(spacing.space_s)
+        .width(Length::Fixed(480.0))
+        .class(theme::Container::custom(|theme| {
+            let cosmic_theme = theme.cosmic();
+            cosmic::iced::widget::container::Style {
+                background: Some(cosmic::iced::Background::Color(
+                    cosmic_theme.background.base.into(),
+                )),
+                border: cosmic::iced::Border {
+                    radius: cosmic_theme.corner_radii.radius_m.into(),
+                    width: 1.0,
+                    color: cosmic_theme.bg_divider().into(),
+                },
+                ..Default::default()
+            }
+        }))
+        .into()
+}
+
+/// Key binds of the app, shown next to their menu entries. The shortcuts changed by the user
+/// replace the default ones of their actions.
+fn key_binds(custom_key_binds: &BTreeMap<String, String>) -> HashMap<menu::KeyBind, MenuAction> {
+    let mut key_binds = HashMap::from([
+        (
+            menu::KeyBind {
+                modifiers: vec![menu::key_bind::Modifier::Ctrl],
+                key: Key::Character("k".into()),
+            },
+            MenuAction::CommandPalette,
+        ),
+        (
+            menu::KeyBind {
+                modifiers: vec![menu::key_bind::Modifier::Ctrl],
+                key: Key::Character("g".into()),
+            },
+            MenuAction::GoToNumber,
+        ),
+        (
+            menu::KeyBind {
+                modifiers: vec![menu::key_bind::Modifier::Ctrl],
+                key: Key::Character("f".into()),
+            },
+            MenuAction::FocusSearch,
+        ),
+    ]);
+
+    for action in MenuAction::REBINDABLE {
+        let Some(custom_key_bind) = custom_key_binds.get(action.name()) else {
+            continue;
+        };
+
+        key_binds.retain(|_, bound_action| *bound_action != action);
+        if let Some(key_bind) = parse_key_bind(custom_key_bind) {
+            key_binds.insert(key_bind, action);
+        }
+    }
+
+    key_binds
+}
+
+/// Encounters of a Pokémon as a table, with a row for each location and a column for each game
+/// it can be found in, the cells show the encounter methods abbreviated.
+fn encounter_matrix(encounter_info: &[StarryPokemonEncounterInfo]) -> Element<'_, Message> {
+    let game_order = |game: &str| {
+        MAIN_SERIES_GAMES
+            .iter()
+            .position(|(version, _)| capitalize_string(version) == game)
+            .unwrap_or(MAIN_SERIES_GAMES.len())
+    };
+
+    let mut games: Vec<&str> = encounter_info
+        .iter()
+        .flat_map(|encounter| encounter.methods_by_game().map(|(game, _)| game))
+        .collect::<BTreeSet<&str>>()
+        .into_iter()
+        .collect();
+    games.sort_by_key(|game| game_order(game));
+
+    let header = games.iter().fold(
+        widget::Row::new().push(widget::Space::with_width(Length::Fixed(
+            ENCOUNTER_LOCATION_WIDTH,
+        ))),
+        |row, game| {
+            let label = MAIN_SERIES_GAMES
+                .get(game_order(game))
+                .map(|(_, label)| label.to_string())
+                .unwrap_or_else(|| game.chars().take(3).collect());
+
+            row.push(widget::tooltip(
+                widget::text::caption(label)
+                    .width(Length::Fixed(ENCOUNTER_CELL_WIDTH))
+                    .align_x(Horizontal::Center),
+                widget::text(game.to_string()),
+                widget::tooltip::Position::Top,
+            ))
+        },
+    );
+
+    let rows = encounter_info.iter().map(|encounter| {
+        let methods_by_game: HashMap<&str, Vec<&str>> = encounter.methods_by_game().collect();
+
+        games
+            .iter()
+            .fold(
+                widget::Row::new().push(
+                    widget::text::caption(encounter.city.clone())
+                        .width(Length::Fixed(ENCOUNTER_LOCATION_WIDTH)),
+                ),
+                |row, game| match methods_by_game.get(game) {
+                    Some(methods) => row.push(widget::tooltip(
+                        encounter_cell(
+                            methods
+                                .iter()
+                                .map(|method| encounter_method_abbreviation(method))
+                                .collect::<Vec<String>>()
+                                .join(" "),
+                        ),
+                        widget::text(methods.join(", ")),
+                        widget::tooltip::Position::Top,
+                    )),
+                    None => row.push(encounter_cell(String::new())),
+                },
+            )
+            .spacing(2)
+            .align_y(Alignment::Center)
+            .into()
+    });
+
+    widget::scrollable(
+        Column::new()
+            .push(header.spacing(2))
+            .extend(rows)
+            .spacing(2),
+    )
+    .direction(cosmic::iced::widget::scrollable::Direction::Horizontal(
+        cosmic::iced::widget::scrollable::Scrollbar::default(),
+    ))
+    .into()
+}
+
+/// Cell of the encounter matrix, highlighted when the Pokémon can be found there.
+fn encounter_cell<'a>(methods: String) -> Element<'a, Message> {
+    let available = !methods.is_empty();
+
+    widget::container(
+        widget::text::caption(methods)
+            .width(Length::Fill)
+            .align_x(Horizontal::Center),
+    )
+    .width(Length::Fixed(ENCOUNTER_CELL_WIDTH))
+    .padding([2, 0])
+    .class(theme::Container::custom(move |theme| {
+        let cosmic_theme = theme.cosmic();
+        let (background, text_color) = if available {
+            (cosmic_theme.accent_color(), cosmic_theme.on_accent_color())
+        } else {
+            (cosmic_theme.bg_component_color(), cosmic_theme.bg_divider())
+        };
+
+        cosmic::iced::widget::container::Style {
+            background: Some(cosmic::iced::Background::Color(background.into())),
+            text_color: Some(text_color.into()),
+            border: cosmic::iced::Border {
+                radius: 4.0.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }))
+    .into()
+}
+
+/// Short form of an encounter method, its initials ("Old Rod" is "OR").
+fn encounter_method_abbreviation(method: &str) -> String {
+    method
+        .split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .collect()
+}
+
+/// Cell of the type chart, colored by how effective the attacking type is against the defending one.
+fn type_chart_cell<'a>(
+    row: usize,
+    column: usize,
+    multiplier: f32,
+    highlighted: bool,
+    palette: ColorPalette,
+    text_scale: f32,
+) -> Element<'a, Message> {
+    let (label, color) = if multiplier == 0.0 {
+        ("0", Some(cosmic::iced::Color::from_rgb(0.25, 0.25, 0.25)))
+    } else if multiplier < 1.0 {
+        ("½", Some(palette.negative_color()))
+    } else if multiplier > 1.0 {
+        ("2", Some(palette.positive_color()))
+    } else {
+        ("", None)
+    };
+
+    let cell = widget::container(
+        widget::text(label)
+            .size(BODY_TEXT_SIZE * text_scale)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(Horizontal::Center)
+            .align_y(Vertical::Center),
+    )
+    .width(Length::Fixed(TYPE_CHART_CELL_WIDTH * text_scale))
+    .height(Length::Fixed(TYPE_CHART_CELL_HEIGHT * text_scale))
+    .class(theme::Container::custom(move |theme| {
+        let cosmic_theme = theme.cosmic();
+        let background = match color {
+            Some(color) if highlighted => color,
+            Some(color) => cosmic::iced::Color { a: 0.7, ..color },
+            None if highlighted => cosmic_theme.bg_component_color().into(),
+            None => cosmic::iced::Color::TRANSPARENT,
+        };
+
+        cosmic::iced::widget::container::Style {
+            background: Some(cosmic::iced::Background::Color(background)),
+            text_color: color.map(|_| cosmic::iced::Color::WHITE),
+            border: cosmic::iced::Border {
+                color: cosmic_theme.bg_divider().into(),
+                width: 0.5,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }));
+
+    cosmic::iced::widget::mouse_area(cell)
+        .on_enter(Message::HoverTypeMatchup(Some((row, column))))
+        .into()
+}
+
+/// A row of the Pokémon grid: the header of a group or the Pokémon of a row of cards.
+enum GridRow<'a> {
+    Header(String),
+    Cards(Vec<&'a StarryPokemon>),
+}
+
+/// How the list of Pokémon is shown on the main page.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ViewMode {
+    #[default]
+    Grid,
+    Table,
+}
+
+/// Column the table view is sorted by.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TableColumn {
+    Number,
+    Name,
+    Types,
+    Stat(RankedStat),
+}
+
+/// Order of the shown list of Pokémon, every mode but `Number` groups the list under headers.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum SortMode {
+    #[default]
+    Number,
+    Generation,
+    Type,
+}
+
+/// The Dex shown on the main page.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DexMode {
+    #[default]
+    Pokemon,
+    Moves,
+    Items,
+    Abilities,
+    Locations,
+    Berries,
+    Natures,
+    TypeChart,
+    EggGroups,
+    Rankings,
+    WeeklyChallenge,
+    Trivia,
+}
+
+/// The dialog to display over the app.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DialogPage {
+    CommandPalette,
+    GoToNumber,
+    SpriteViewer,
+}
+
 /// The context page to display in the context drawer.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub enum ContextPage {
@@ -1015,12 +6693,111 @@ pub enum ContextPage {
     Settings,
     PokemonPage,
     FiltersPage,
+    MovePage,
+    ItemPage,
+    WatchlistChanges,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MenuAction {
     About,
     Settings,
+    ReportProblem,
+    CommandPalette,
+    GoToNumber,
+    FocusSearch,
+    ExportCsv,
+    ExportJson,
+    PokemonDex,
+    MovesDex,
+    ItemsDex,
+    AbilitiesDex,
+    LocationsDex,
+    Berries,
+    Natures,
+    TypeChart,
+    EggGroups,
+    Rankings,
+    WeeklyChallenge,
+    Trivia,
+}
+
+impl MenuAction {
+    /// Actions the user can change the shortcut of
+    pub const REBINDABLE: [MenuAction; 20] = [
+        MenuAction::CommandPalette,
+        MenuAction::GoToNumber,
+        MenuAction::FocusSearch,
+        MenuAction::ExportCsv,
+        MenuAction::ExportJson,
+        MenuAction::PokemonDex,
+        MenuAction::MovesDex,
+        MenuAction::ItemsDex,
+        MenuAction::AbilitiesDex,
+        MenuAction::LocationsDex,
+        MenuAction::WeeklyChallenge,
+        MenuAction::Trivia,
+        MenuAction::Berries,
+        MenuAction::Natures,
+        MenuAction::TypeChart,
+        MenuAction::EggGroups,
+        MenuAction::Rankings,
+        MenuAction::Settings,
+        MenuAction::About,
+        MenuAction::ReportProblem,
+    ];
+
+    /// Name the shortcut of the action is saved with in the config
+    pub fn name(&self) -> &'static str {
+        match self {
+            MenuAction::About => "about",
+            MenuAction::Settings => "settings",
+            MenuAction::ReportProblem => "report-problem",
+            MenuAction::CommandPalette => "command-palette",
+            MenuAction::GoToNumber => "go-to-number",
+            MenuAction::FocusSearch => "focus-search",
+            MenuAction::ExportCsv => "export-csv",
+            MenuAction::ExportJson => "export-json",
+            MenuAction::PokemonDex => "pokemon-dex",
+            MenuAction::MovesDex => "moves-dex",
+            MenuAction::ItemsDex => "items-dex",
+            MenuAction::AbilitiesDex => "abilities-dex",
+            MenuAction::LocationsDex => "locations-dex",
+            MenuAction::Berries => "berries",
+            MenuAction::Natures => "natures",
+            MenuAction::TypeChart => "type-chart",
+            MenuAction::EggGroups => "egg-groups",
+            MenuAction::Rankings => "rankings",
+            MenuAction::WeeklyChallenge => "weekly-challenge",
+            MenuAction::Trivia => "trivia",
+        }
+    }
+
+    /// Localized name of the action
+    pub fn label(&self) -> String {
+        match self {
+            MenuAction::About => fl!("about"),
+            MenuAction::Settings => fl!("settings"),
+            MenuAction::ReportProblem => fl!("report-problem"),
+            MenuAction::CommandPalette => fl!("command-palette"),
+            MenuAction::GoToNumber => fl!("go-to-number"),
+            MenuAction::FocusSearch => fl!("focus-search"),
+            MenuAction::ExportCsv => fl!("export-list-csv"),
+            MenuAction::ExportJson => fl!("export-list-json"),
+            MenuAction::PokemonDex => fl!("pokemon-dex"),
+            MenuAction::MovesDex => fl!("moves-dex"),
+            MenuAction::ItemsDex => fl!("items-dex"),
+            MenuAction::AbilitiesDex => fl!("abilities-dex"),
+            MenuAction::LocationsDex => fl!("locations-dex"),
+            MenuAction::Berries => fl!("berries"),
+            MenuAction::Natures => fl!("natures"),
+            MenuAction::TypeChart => fl!("type-chart"),
+            MenuAction::EggGroups => fl!("egg-groups"),
+            MenuAction::Rankings => fl!("rankings"),
+            MenuAction::WeeklyChallenge => fl!("weekly-challenge"),
+            MenuAction::Trivia => fl!("stat-trivia"),
+        }
+    }
 }
 
 impl menu::action::MenuAction for MenuAction {
@@ -1030,6 +6807,24 @@ impl menu::action::MenuAction for MenuAction {
         match self {
             MenuAction::About => Message::ToggleContextPage(ContextPage::About),
             MenuAction::Settings => Message::ToggleContextPage(ContextPage::Settings),
+            MenuAction::ReportProblem => Message::ReportProblem,
+            MenuAction::CommandPalette => Message::OpenCommandPalette,
+            MenuAction::GoToNumber => Message::OpenGoToNumber,
+            MenuAction::FocusSearch => Message::FocusSearch,
+            MenuAction::ExportCsv => Message::ExportPokemonList(ListFormat::Csv),
+            MenuAction::ExportJson => Message::ExportPokemonList(ListFormat::Json),
+            MenuAction::PokemonDex => Message::ChangeDexMode(DexMode::Pokemon),
+            MenuAction::MovesDex => Message::ChangeDexMode(DexMode::Moves),
+            MenuAction::ItemsDex => Message::ChangeDexMode(DexMode::Items),
+            MenuAction::AbilitiesDex => Message::ChangeDexMode(DexMode::Abilities),
+            MenuAction::LocationsDex => Message::ChangeDexMode(DexMode::Locations),
+            MenuAction::Berries => Message::ChangeDexMode(DexMode::Berries),
+            MenuAction::Natures => Message::ChangeDexMode(DexMode::Natures),
+            MenuAction::TypeChart => Message::ChangeDexMode(DexMode::TypeChart),
+            MenuAction::EggGroups => Message::ChangeDexMode(DexMode::EggGroups),
+            MenuAction::Rankings => Message::ChangeDexMode(DexMode::Rankings),
+            MenuAction::WeeklyChallenge => Message::ChangeDexMode(DexMode::WeeklyChallenge),
+            MenuAction::Trivia => Message::ChangeDexMode(DexMode::Trivia),
         }
     }
 }
@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::{
+    challenges::ChallengeHistory, config::Config, export::write_export, key_binds::parse_key_bind,
+};
+
+/// What the backup files say they are, files without it aren't imported
+const BACKUP_FORMAT: &str = "starrydex-backup";
+/// Version of the backup files, raised when they change in a way older versions can't read
+const BACKUP_VERSION: u32 = 1;
+const BACKUP_FILE_NAME: &str = "starrydex-backup.json";
+
+/// The data of the user, the settings (with the aliases, shortcuts and watchlist) and the
+/// Pokémon caught for the weekly challenges, to move it to another system
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Backup {
+    format: String,
+    version: u32,
+    pub config: Config,
+    pub challenge_history: ChallengeHistory,
+}
+
+/// The fields read before the rest of a backup, to tell why it can't be imported
+#[derive(Deserialize)]
+struct BackupHeader {
+    format: Option<String>,
+    version: Option<u32>,
+}
+
+impl Backup {
+    pub fn new(config: &Config, challenge_history: &ChallengeHistory) -> Backup {
+        Backup {
+            format: String::from(BACKUP_FORMAT),
+            version: BACKUP_VERSION,
+            config: config.clone(),
+            challenge_history: challenge_history.clone(),
+        }
+    }
+
+    /// Reads a backup, checking it's one StarryDex made and this version can import
+    fn parse(backup_data: &[u8]) -> Result<Backup, String> {
+        let header: BackupHeader = serde_json::from_slice(backup_data)
+            .map_err(|_| String::from("The file isn't a StarryDex backup"))?;
+        if header.format.as_deref() != Some(BACKUP_FORMAT) {
+            return Err(String::from("The file isn't a StarryDex backup"));
+        }
+        match header.version {
+            Some(version) if version > BACKUP_VERSION => {
+                return Err(String::from(
+                    "The backup was made by a newer version of StarryDex",
+                ))
+            }
+            Some(0) | None => return Err(String::from("The backup has no valid version")),
+            Some(_) => {}
+        }
+
+        let backup: Backup = serde_json::from_slice(backup_data)
+            .map_err(|e| format!("The backup is damaged: {}", e))?;
+
+        if let Some(id) = backup.config.watchlist.iter().find(|id| **id <= 0) {
+            return Err(format!("The watchlist has an invalid Pokémon: {}", id));
+        }
+        // An empty shortcut unbinds its action
+        if let Some(shortcut) = backup
+            .config
+            .custom_key_binds
+            .values()
+            .find(|shortcut| !shortcut.is_empty() && parse_key_bind(shortcut).is_none())
+        {
+            return Err(format!("The backup has an invalid shortcut: {}", shortcut));
+        }
+
+        Ok(backup)
+    }
+}
+
+/// Writes a backup to the documents directory and returns its path
+pub async fn export_backup(backup: Backup) -> Result<PathBuf, String> {
+    let contents = serde_json::to_string_pretty(&backup).map_err(|e| e.to_string())?;
+    write_export(String::from(BACKUP_FILE_NAME), contents).await
+}
+
+/// Reads the backup at the given path, it's only returned if it can be imported
+pub async fn import_backup(path: PathBuf) -> Result<Backup, String> {
+    let backup_data = tokio::fs::read(&path).await.map_err(|e| e.to_string())?;
+    Backup::parse(&backup_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::challenges::ChallengeWeek;
+
+    fn backup_json(config: &Config) -> serde_json::Value {
+        serde_json::to_value(Backup::new(config, &ChallengeHistory::default())).unwrap()
+    }
+
+    fn parse(backup: &serde_json::Value) -> Result<Backup, String> {
+        Backup::parse(&serde_json::to_vec(backup).unwrap())
+    }
+
+    #[test]
+    fn round_trips_the_config_and_challenge_history() {
+        let mut config = Config::default();
+        config.watchlist.insert(25);
+        config
+            .custom_key_binds
+            .insert(String::from("focus-search"), String::from("Ctrl+Shift+K"));
+        config
+            .custom_key_binds
+            .insert(String::from("refresh"), String::new());
+        let week = ChallengeWeek::current();
+        let mut challenge_history = ChallengeHistory::default();
+        challenge_history.toggle_caught(week, 150);
+
+        let contents = serde_json::to_vec(&Backup::new(&config, &challenge_history)).unwrap();
+        let backup = Backup::parse(&contents).unwrap();
+        assert_eq!(backup.config, config);
+        assert_eq!(
+            backup.challenge_history.caught(week),
+            challenge_history.caught(week)
+        );
+    }
+
+    #[test]
+    fn rejects_files_that_arent_backups() {
+        assert!(Backup::parse(b"not json").is_err());
+
+        let mut backup = backup_json(&Config::default());
+        backup["format"] = serde_json::json!("something-else");
+        assert!(parse(&backup).is_err());
+
+        backup.as_object_mut().unwrap().remove("format");
+        assert!(parse(&backup).is_err());
+    }
+
+    #[test]
+    fn rejects_newer_missing_and_zero_versions() {
+        let mut backup = backup_json(&Config::default());
+        assert!(parse(&backup).is_ok());
+
+        backup["version"] = serde_json::json!(BACKUP_VERSION + 1);
+        assert_eq!(
+            parse(&backup).unwrap_err(),
+            "The backup was made by a newer version of StarryDex"
+        );
+
+        backup["version"] = serde_json::json!(0);
+        assert_eq!(
+            parse(&backup).unwrap_err(),
+            "The backup has no valid version"
+        );
+
+        backup.as_object_mut().unwrap().remove("version");
+        assert_eq!(
+            parse(&backup).unwrap_err(),
+            "The backup has no valid version"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_watchlist_ids() {
+        for id in [0, -3] {
+            let mut config = Config::default();
+            config.watchlist.insert(id);
+            assert!(parse(&backup_json(&config)).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_shortcuts() {
+        for shortcut in ["Ctrl+", "Hyper+K", "NotAKey"] {
+            let mut config = Config::default();
+            config
+                .custom_key_binds
+                .insert(String::from("refresh"), String::from(shortcut));
+            assert!(parse(&backup_json(&config)).is_err());
+        }
+    }
+}
@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    app::StarryPokemon,
+    config::MeasurementUnits,
+    data_dir::app_data_dir,
+    fl,
+    rankings::{RankedStat, GENERATIONS},
+    type_chart::ALL_TYPES,
+    utils::{format_height, format_weight},
+};
+
+const CHALLENGES_FILE: &str = "challenges.json";
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// An ISO week, the challenge changes every week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChallengeWeek {
+    pub year: i32,
+    pub week: u32,
+    /// Days between the epoch and the Monday of the week, identifies the week
+    monday: i64,
+}
+
+impl ChallengeWeek {
+    /// The week we are in
+    pub fn current() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() / SECONDS_PER_DAY)
+            .unwrap_or_default();
+        Self::containing(days as i64)
+    }
+
+    /// The week the given day (in days since the epoch) is in
+    fn containing(days: i64) -> Self {
+        // The epoch was a Thursday, weekdays are counted from Monday
+        let weekday = (days + 3).rem_euclid(7);
+        let monday = days - weekday;
+
+        // The ISO year of a week is the year its Thursday is in
+        let thursday = monday + 3;
+        let year = civil_year(thursday);
+        let week = ((thursday - days_from_civil(year, 1, 1)) / 7 + 1) as u32;
+
+        Self { year, week, monday }
+    }
+
+    /// The week before this one
+    pub fn previous(&self) -> Self {
+        Self::containing(self.monday - 7)
+    }
+
+    fn seed(&self) -> u64 {
+        (self.year as u64) * 100 + self.week as u64
+    }
+}
+
+/// Days since the epoch of the given date, from Howard Hinnant's civil calendar algorithms
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = (if month <= 2 { year - 1 } else { year }) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year of the given day (in days since the epoch)
+fn civil_year(days: i64) -> i32 {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400;
+    (if month_index >= 10 { year + 1 } else { year }) as i32
+}
+
+/// SplitMix64, enough to pick the challenge of a week or a trivia question
+pub fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+fn pick<T: Copy>(state: &mut u64, values: &[T]) -> T {
+    values[(next_random(state) % values.len() as u64) as usize]
+}
+
+/// The challenge of a week, completed by catching the given amount of matching Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Challenge {
+    CatchType {
+        type_name: &'static str,
+        count: usize,
+    },
+    CatchGeneration {
+        generation: &'static str,
+        count: usize,
+    },
+    /// Weight in hectograms, as PokéApi gives it
+    HeavierThan {
+        weight: i64,
+    },
+    /// Height in decimeters, as PokéApi gives it
+    TallerThan {
+        height: i64,
+    },
+    StatAtLeast {
+        stat: RankedStat,
+        value: i64,
+    },
+}
+
+impl Challenge {
+    /// The challenge of the given week, always the same for the same week
+    pub fn of_week(week: ChallengeWeek) -> Self {
+        let mut state = week.seed();
+        let count = pick(&mut state, &[3, 4, 5, 6]);
+
+        match next_random(&mut state) % 5 {
+            0 => Challenge::CatchType {
+                type_name: pick(&mut state, &ALL_TYPES),
+                count,
+            },
+            1 => Challenge::CatchGeneration {
+                generation: pick(&mut state, &GENERATIONS),
+                count,
+            },
+            2 => Challenge::HeavierThan {
+                weight: pick(&mut state, &[1000, 2000, 3000, 4000]),
+            },
+            3 => Challenge::TallerThan {
+                height: pick(&mut state, &[20, 25, 30, 40]),
+            },
+            _ => Challenge::StatAtLeast {
+                stat: pick(&mut state, &RankedStat::ALL[1..]),
+                value: pick(&mut state, &[100, 110, 120, 130]),
+            },
+        }
+    }
+
+    /// How many matching Pokémon have to be caught
+    pub fn goal(&self) -> usize {
+        match self {
+            Challenge::CatchType { count, .. } | Challenge::CatchGeneration { count, .. } => *count,
+            _ => 1,
+        }
+    }
+
+    /// Localized description of the challenge, with heights and weights in the given units
+    pub fn description(&self, units: MeasurementUnits) -> String {
+        match self {
+            Challenge::CatchType { type_name, count } => fl!(
+                "challenge-catch-type",
+                count = count,
+                type_name = crate::i18n::type_label(type_name)
+            ),
+            Challenge::CatchGeneration { generation, count } => fl!(
+                "challenge-catch-generation",
+                count = count,
+                generation = crate::i18n::generation_label(generation)
+            ),
+            Challenge::HeavierThan { weight } => {
+                fl!(
+                    "challenge-heavier-than",
+                    weight = format_weight(*weight, units)
+                )
+            }
+            Challenge::TallerThan { height } => {
+                fl!(
+                    "challenge-taller-than",
+                    height = format_height(*height, units)
+                )
+            }
+            Challenge::StatAtLeast { stat, value } => {
+                fl!(
+                    "challenge-stat-at-least",
+                    stat = stat.label(),
+                    value = value
+                )
+            }
+        }
+    }
+
+    /// Whether catching the given Pokémon counts for the challenge
+    pub fn matches(&self, starry_pokemon: &StarryPokemon) -> bool {
+        let pokemon = &starry_pokemon.pokemon;
+        match self {
+            Challenge::CatchType { type_name, .. } => pokemon.types.iter().any(|t| t == type_name),
+            Challenge::CatchGeneration { generation, .. } => starry_pokemon
+                .specie
+                .as_ref()
+                .and_then(|specie| specie.generation.as_deref())
+                .is_some_and(|g| g == *generation),
+            Challenge::HeavierThan { weight } => pokemon.weight > *weight,
+            Challenge::TallerThan { height } => pokemon.height > *height,
+            Challenge::StatAtLeast { stat, value } => stat.value(&pokemon.stats) >= *value,
+        }
+    }
+}
+
+/// Pokémon caught for the challenge of each week, keyed by the Monday of the week
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChallengeHistory {
+    weeks: BTreeMap<i64, BTreeSet<i64>>,
+}
+
+impl ChallengeHistory {
+    /// Pokémon caught for the challenge of the given week
+    pub fn caught(&self, week: ChallengeWeek) -> Option<&BTreeSet<i64>> {
+        self.weeks.get(&week.monday)
+    }
+
+    /// Marks the Pokémon as caught for the challenge of the given week, or unmarks it if it was
+    pub fn toggle_caught(&mut self, week: ChallengeWeek, pokemon_id: i64) {
+        let caught = self.weeks.entry(week.monday).or_default();
+        if !caught.remove(&pokemon_id) {
+            caught.insert(pokemon_id);
+        }
+    }
+
+    /// Whether enough matching Pokémon were caught on the given week
+    pub fn is_completed(
+        &self,
+        week: ChallengeWeek,
+        pokemon_list: &BTreeMap<i64, StarryPokemon>,
+    ) -> bool {
+        let challenge = Challenge::of_week(week);
+        let caught = self
+            .caught(week)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| pokemon_list.get(id))
+            .filter(|p| challenge.matches(p))
+            .count();
+        caught >= challenge.goal()
+    }
+
+    /// How many weeks in a row the challenge has been completed, the current week only breaks
+    /// the streak once it's over
+    pub fn streak(&self, pokemon_list: &BTreeMap<i64, StarryPokemon>) -> usize {
+        let current = ChallengeWeek::current();
+        let mut week = if self.is_completed(current, pokemon_list) {
+            current
+        } else {
+            current.previous()
+        };
+
+        let mut streak = 0;
+        while self.is_completed(week, pokemon_list) {
+            streak += 1;
+            week = week.previous();
+        }
+        streak
+    }
+}
+
+fn challenges_file() -> PathBuf {
+    app_data_dir().join(CHALLENGES_FILE)
+}
+
+/// Loads the challenge history, empty if there is none yet
+pub fn load_history() -> ChallengeHistory {
+    std::fs::read(challenges_file())
+        .ok()
+        .and_then(|history_data| serde_json::from_slice(&history_data).ok())
+        .unwrap_or_default()
+}
+
+/// Saves the challenge history, replacing the previous one
+pub fn save_history(history: &ChallengeHistory) {
+    let challenges_file = challenges_file();
+    let tmp_challenges_file = challenges_file.with_extension("json.part");
+
+    let result = serde_json::to_vec(history)
+        .map_err(std::io::Error::from)
+        .and_then(|history_data| std::fs::write(&tmp_challenges_file, history_data))
+        .and_then(|()| std::fs::rename(&tmp_challenges_file, &challenges_file));
+
+    if let Err(e) = result {
+        eprintln!("Failed to save the challenge history: {}", e);
+    }
+}
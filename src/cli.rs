@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use cosmic::app::CosmicFlags;
+use cosmic::cosmic_config::{self, CosmicConfigEntry};
+use cosmic::Application;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    api::Api,
+    app::{StarryDex, StarryPokemon},
+    challenges::next_random,
+    config::Config,
+    export::{render_pokemon_list, ListFormat},
+    search::SearchQuery,
+};
+
+/// Usage shown by `--help` and when the arguments can't be parsed
+pub const USAGE: &str =
+    "Usage: starry-dex [--data-dir PATH] [--pokemon NAME | --id NUMBER | --random]
+       starry-dex [--data-dir PATH] query SEARCH [--json | --csv]
+
+Options:
+  --pokemon NAME   Open the details of the Pokémon with the given name
+  --id NUMBER      Open the details of the Pokémon with the given Pokédex number
+  --random         Open the details of a random Pokémon
+  --data-dir PATH  Keep the cache, the sprites and the rest of the data in PATH
+  -h, --help       Show this help
+
+Query:
+  Prints the Pokémon matching a search, like \"type:dragon gen:1\", without opening the app.
+  The names are printed one per line unless --json or --csv is given.
+  The Pokédex has to be downloaded by opening the app once first.";
+
+/// Pokémon to open once the Pokémon list is loaded, as asked in the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenRequest {
+    Name(String),
+    Id(i64),
+    Random,
+}
+
+/// Flags the app is started with
+#[derive(Debug, Clone, Default)]
+pub struct Flags {
+    pub open_request: Option<OpenRequest>,
+    /// The open request as a D-Bus action, sent to the running instance if there's one
+    activation_action: Option<String>,
+}
+
+impl Flags {
+    pub fn new(open_request: Option<OpenRequest>) -> Flags {
+        let activation_action = open_request.as_ref().map(|request| match request {
+            OpenRequest::Id(id) => format!("show-pokemon={id}"),
+            OpenRequest::Name(name) => format!("open-pokemon={name}"),
+            OpenRequest::Random => String::from("random-pokemon"),
+        });
+
+        Flags {
+            open_request,
+            activation_action,
+        }
+    }
+}
+
+impl CosmicFlags for Flags {
+    type SubCommand = String;
+    type Args = Vec<String>;
+
+    fn action(&self) -> Option<&String> {
+        self.activation_action.as_ref()
+    }
+}
+
+/// Request another program sends to the running instance through D-Bus, as a method of the
+/// dev.mariinkys.StarryDex interface or an action of its org.freedesktop.Application interface
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivationRequest {
+    /// `search` action, with the search as argument
+    Search(String),
+    /// `show-pokemon` action with the Pokédex number as argument, `open-pokemon` with the name
+    /// and `random-pokemon` without argument
+    Open(OpenRequest),
+}
+
+/// Parses an action activated through D-Bus, its argument may also follow the name after a `=`
+/// (`show-pokemon=25`) for callers that can't pass arguments
+pub fn parse_activation(action: &str, args: &[String]) -> Option<ActivationRequest> {
+    if action == "random-pokemon" {
+        return Some(ActivationRequest::Open(OpenRequest::Random));
+    }
+
+    let (name, argument) = match action.split_once('=') {
+        Some((name, argument)) => (name, argument.to_string()),
+        None => (action, args.first()?.clone()),
+    };
+
+    match name {
+        "search" => Some(ActivationRequest::Search(argument)),
+        "show-pokemon" => argument
+            .trim()
+            .trim_start_matches('#')
+            .parse()
+            .ok()
+            .map(|id| ActivationRequest::Open(OpenRequest::Id(id))),
+        "open-pokemon" => Some(ActivationRequest::Open(OpenRequest::Name(argument))),
+        _ => None,
+    }
+}
+
+/// What the command line asks the app to do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliAction {
+    Run(Option<OpenRequest>),
+    /// Print the Pokémon matching the search, as names or in the given format
+    Query {
+        search: String,
+        format: Option<ListFormat>,
+    },
+    Help,
+}
+
+/// Parses the command line arguments, without the program name, along with the data directory
+/// given with `--data-dir`
+pub fn parse_args(
+    args: impl IntoIterator<Item = String>,
+) -> Result<(CliAction, Option<PathBuf>), String> {
+    let mut args: Vec<String> = args.into_iter().collect();
+    let data_dir = match args.iter().position(|arg| arg == "--data-dir") {
+        Some(index) if index + 1 < args.len() => {
+            let dir = PathBuf::from(args.remove(index + 1));
+            args.remove(index);
+            Some(dir)
+        }
+        Some(_) => return Err(String::from("--data-dir needs a folder")),
+        None => None,
+    };
+
+    parse_action(args).map(|action| (action, data_dir))
+}
+
+/// Parses the arguments that choose what to do
+fn parse_action(args: Vec<String>) -> Result<CliAction, String> {
+    let mut args = args.into_iter().peekable();
+    if args.peek().map(String::as_str) == Some("query") {
+        args.next();
+        return parse_query_args(args);
+    }
+
+    let mut open_request = None;
+
+    while let Some(arg) = args.next() {
+        let request = match arg.as_str() {
+            "-h" | "--help" => return Ok(CliAction::Help),
+            "--random" => OpenRequest::Random,
+            "--pokemon" => {
+                let name = args
+                    .next()
+                    .ok_or_else(|| String::from("--pokemon needs a Pokémon name"))?;
+                OpenRequest::Name(name)
+            }
+            "--id" => {
+                let id = args
+                    .next()
+                    .ok_or_else(|| String::from("--id needs a Pokédex number"))?;
+                OpenRequest::Id(
+                    id.trim_start_matches('#')
+                        .parse()
+                        .map_err(|_| format!("Invalid Pokédex number: {id}"))?,
+                )
+            }
+            _ => return Err(format!("Unknown argument: {arg}")),
+        };
+
+        if open_request.replace(request).is_some() {
+            return Err(String::from(
+                "Only one of --pokemon, --id or --random can be given",
+            ));
+        }
+    }
+
+    Ok(CliAction::Run(open_request))
+}
+
+/// Parses the arguments following `query`
+fn parse_query_args(args: impl Iterator<Item = String>) -> Result<CliAction, String> {
+    let mut search = None;
+    let mut format = None;
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(CliAction::Help),
+            "--json" => format = Some(ListFormat::Json),
+            "--csv" => format = Some(ListFormat::Csv),
+            _ if arg.starts_with("--") => return Err(format!("Unknown argument: {arg}")),
+            _ if search.is_none() => search = Some(arg),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    Ok(CliAction::Query {
+        search: search.ok_or_else(|| String::from("query needs a search"))?,
+        format,
+    })
+}
+
+/// Renders the cached Pokémon matching the search, custom search aliases of the config included
+pub fn run_query(search: &str, format: Option<ListFormat>) -> Result<String, String> {
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| e.to_string())?;
+    let pokemon_list = runtime
+        .block_on(Api::new().load_cached_pokemon())
+        .ok_or_else(|| String::from("No Pokédex found, open StarryDex once to download it"))?;
+
+    let config = cosmic_config::Config::new(StarryDex::APP_ID, Config::VERSION)
+        .map(|context| Config::get_entry(&context).unwrap_or_else(|(_errors, config)| config))
+        .unwrap_or_default();
+
+    // The cache may start with an empty Pokémon, the app drops it too
+    let query = SearchQuery::parse(search, &config.custom_aliases);
+    let matching: Vec<StarryPokemon> = pokemon_list
+        .into_values()
+        .filter(|pokemon| pokemon.pokemon.id > 0 && query.matches(pokemon))
+        .collect();
+
+    Ok(match format {
+        Some(format) => render_pokemon_list(&matching, format),
+        None => matching
+            .iter()
+            .map(|pokemon| pokemon.pokemon.name.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n"),
+    })
+}
+
+impl OpenRequest {
+    /// Id of the requested Pokémon, none if no Pokémon of the list matches
+    pub fn resolve(&self, pokemon_list: &BTreeMap<i64, StarryPokemon>) -> Option<i64> {
+        match self {
+            OpenRequest::Id(id) => pokemon_list.contains_key(id).then_some(*id),
+            OpenRequest::Name(name) => {
+                let name = name.trim().to_lowercase().replace(' ', "-");
+                pokemon_list
+                    .values()
+                    .find(|p| p.pokemon.name == name)
+                    .map(|p| p.pokemon.id)
+            }
+            OpenRequest::Random => {
+                let mut random_state = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_nanos() as u64)
+                    .unwrap_or_default();
+                let index = next_random(&mut random_state) % pokemon_list.len().max(1) as u64;
+                pokemon_list.keys().nth(index as usize).copied()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<(CliAction, Option<PathBuf>), String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_queries() {
+        assert_eq!(
+            parse(&["query", "type:dragon gen:1"]),
+            Ok((
+                CliAction::Query {
+                    search: String::from("type:dragon gen:1"),
+                    format: None,
+                },
+                None
+            ))
+        );
+        assert_eq!(
+            parse(&["query", "--json", "gen:1"]),
+            Ok((
+                CliAction::Query {
+                    search: String::from("gen:1"),
+                    format: Some(ListFormat::Json),
+                },
+                None
+            ))
+        );
+        assert_eq!(
+            parse(&["--data-dir", "/tmp/dex", "query", "gen:1", "--csv"]),
+            Ok((
+                CliAction::Query {
+                    search: String::from("gen:1"),
+                    format: Some(ListFormat::Csv),
+                },
+                Some(PathBuf::from("/tmp/dex"))
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_queries() {
+        assert!(parse(&["query"]).is_err());
+        assert!(parse(&["query", "--xml", "gen:1"]).is_err());
+        assert!(parse(&["query", "gen:1", "type:fire"]).is_err());
+    }
+}
@@ -2,17 +2,49 @@
 
 use cosmic::{
     cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry},
+    iced::Color,
     theme,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
-#[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq, Serialize, Deserialize)]
 #[version = 1]
+#[serde(default)]
 pub struct Config {
     pub app_theme: AppTheme,
+    /// Left out of backups, each system downloads its own Pokémon data
+    #[serde(skip)]
     pub first_run_completed: bool,
     pub pokemon_per_row: usize,
     pub type_filtering_mode: TypeFilteringMode,
+    pub custom_aliases: BTreeMap<String, String>,
+    pub watchlist: BTreeSet<i64>,
+    /// Shortcuts changed by the user, by action name, an empty shortcut unbinds the action
+    pub custom_key_binds: BTreeMap<String, String>,
+    pub keyboard_scheme: KeyboardScheme,
+    /// Whether sprites missing from the resources directory are downloaded when found
+    pub fetch_missing_sprites: bool,
+    pub sprite_set: SpriteSet,
+    /// Data packs (by file name) the user turned off
+    pub disabled_data_packs: BTreeSet<String>,
+    /// Whether the details page is tinted with the color of the Pokémon's primary type
+    pub type_accents: bool,
+    /// Whether the list sorted by number is split under a header for each generation
+    pub generation_headers: bool,
+    pub card_size: CardSize,
+    /// Whether the grid loads its rows while scrolling, keeping only the ones around the view
+    pub infinite_scroll: bool,
+    /// Memory the decoded sprites may take, in megabytes (0 for the default)
+    pub sprite_cache_mb: usize,
+    /// Units the heights and weights are shown in
+    pub measurement_units: MeasurementUnits,
+    pub color_palette: ColorPalette,
+    /// Size of the text of the grid, the details page and the type chart, in percent (0 for the
+    /// default)
+    pub text_scale: usize,
+    /// Whether the placeholders shown while loading stay still instead of shimmering
+    pub reduce_motion: bool,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
@@ -39,3 +71,123 @@ pub enum TypeFilteringMode {
     #[default]
     Exclusive,
 }
+
+/// Extra keys layered on top of the key binds
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum KeyboardScheme {
+    #[default]
+    Default,
+    Vim,
+}
+
+/// Set of sprites the Pokémon are shown with
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SpriteSet {
+    #[default]
+    Default,
+    Emerald,
+    Home,
+    OfficialArtwork,
+}
+
+/// Units heights and weights are shown in
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum MeasurementUnits {
+    #[default]
+    Metric,
+    Imperial,
+    /// Metric followed by imperial
+    Both,
+}
+
+impl MeasurementUnits {
+    /// Every choice of units, in the order the settings show them
+    pub const ALL: [MeasurementUnits; 3] = [
+        MeasurementUnits::Metric,
+        MeasurementUnits::Imperial,
+        MeasurementUnits::Both,
+    ];
+}
+
+/// Colors the raised and lowered stats, the type chart and the types are shown with
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ColorPalette {
+    #[default]
+    Default,
+    /// Blue and orange instead of green and red
+    ColorBlindSafe,
+    /// Darker colors that stand out more against the background and the text on them
+    HighContrast,
+}
+
+impl ColorPalette {
+    /// Every palette, in the order the settings show them
+    pub const ALL: [ColorPalette; 3] = [
+        ColorPalette::Default,
+        ColorPalette::ColorBlindSafe,
+        ColorPalette::HighContrast,
+    ];
+
+    /// Color of raised stats, right answers and super effective types
+    pub fn positive_color(&self) -> Color {
+        match self {
+            ColorPalette::Default => Color::from_rgb(0.30, 0.69, 0.31),
+            ColorPalette::ColorBlindSafe => Color::from_rgb8(0, 114, 178),
+            ColorPalette::HighContrast => Color::from_rgb8(0, 90, 181),
+        }
+    }
+
+    /// Color of lowered stats, wrong answers and not very effective types
+    pub fn negative_color(&self) -> Color {
+        match self {
+            ColorPalette::Default => Color::from_rgb(0.90, 0.29, 0.24),
+            ColorPalette::ColorBlindSafe => Color::from_rgb8(230, 159, 0),
+            ColorPalette::HighContrast => Color::from_rgb8(220, 50, 32),
+        }
+    }
+
+    /// Color of a type from its canonical color. The types are told apart by their name, so the
+    /// color-blind palette keeps their colors, the high contrast one darkens them so the white
+    /// names on them are easier to read.
+    pub fn type_color(&self, [r, g, b]: [u8; 3]) -> Color {
+        match self {
+            ColorPalette::Default | ColorPalette::ColorBlindSafe => Color::from_rgb8(r, g, b),
+            ColorPalette::HighContrast => {
+                let darken = |channel: u8| (channel as f32 * 0.6) as u8;
+                Color::from_rgb8(darken(r), darken(g), darken(b))
+            }
+        }
+    }
+}
+
+/// Size of the cards of the Pokémon grid
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CardSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl CardSize {
+    /// Every card size, in the order the settings show them
+    pub const ALL: [CardSize; 3] = [CardSize::Small, CardSize::Medium, CardSize::Large];
+
+    /// Width and height of the cards
+    pub fn card_dimensions(&self) -> (f32, f32) {
+        match self {
+            CardSize::Small => (150.0, 120.0),
+            CardSize::Medium => (200.0, 155.0),
+            CardSize::Large => (260.0, 210.0),
+        }
+    }
+
+    /// Width and height of the sprites of the cards
+    pub fn sprite_size(&self) -> f32 {
+        match self {
+            CardSize::Small => 64.0,
+            CardSize::Medium => 100.0,
+            CardSize::Large => 150.0,
+        }
+    }
+}
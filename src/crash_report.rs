@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::backtrace::Backtrace;
+use std::path::PathBuf;
+
+use crate::{api::CACHE_VERSION, data_dir::app_data_dir};
+
+const CRASH_REPORT_FILE: &str = "crash_report.txt";
+
+fn crash_report_file() -> PathBuf {
+    app_data_dir().join(CRASH_REPORT_FILE)
+}
+
+/// Saves a report of any panic to the data directory before the usual panic output, so it can
+/// be offered for reporting on the next launch
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        // The localizations may be what panicked, so the locale is read from the environment
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .find_map(|variable| {
+                std::env::var(variable)
+                    .ok()
+                    .filter(|value| !value.is_empty())
+            })
+            .unwrap_or_else(|| String::from("unknown"));
+
+        let report = format!(
+            "- Version: {}\n- OS: {} ({})\n- Locale: {}\n- Cache version: {}\n\n{}\n\nBacktrace:\n{}",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            locale,
+            CACHE_VERSION,
+            info,
+            Backtrace::force_capture()
+        );
+
+        let crash_report_file = crash_report_file();
+        if let Err(e) = std::fs::create_dir_all(app_data_dir())
+            .and_then(|()| std::fs::write(&crash_report_file, report))
+        {
+            eprintln!("Failed to save the crash report: {}", e);
+        }
+
+        default_hook(info);
+    }));
+}
+
+/// Report of the panic that ended the previous run, if any. It's only offered once, so it's
+/// removed as it's read.
+pub fn take_crash_report() -> Option<String> {
+    let crash_report_file = crash_report_file();
+    let report = std::fs::read_to_string(&crash_report_file).ok()?;
+    if let Err(e) = std::fs::remove_file(&crash_report_file) {
+        eprintln!("Failed to remove the crash report: {}", e);
+    }
+    Some(report)
+}
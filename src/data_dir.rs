@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::utils::remove_path;
+
+const APP_ID: &str = "dev.mariinkys.StarryDex";
+/// File next to the executable that turns on the portable mode
+const PORTABLE_MARKER: &str = "portable";
+/// Folder next to the executable where the data is kept in portable mode
+const PORTABLE_DATA_DIR: &str = "data";
+/// File of the default data directory with the path of the data directory chosen in the settings
+const DATA_DIR_POINTER: &str = "data_dir_location";
+
+/// The data directory and what chose it, set on startup
+static DATA_DIR: RwLock<Option<(PathBuf, DataDirSource)>> = RwLock::new(None);
+
+/// What chose the data directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataDirSource {
+    Default,
+    /// Moved from the settings
+    Settings,
+    /// Given with `--data-dir`
+    CommandLine,
+    /// Next to the executable, because there's a `portable` file next to it
+    Portable,
+}
+
+/// Data directory of the system for StarryDex
+pub fn default_data_dir() -> PathBuf {
+    dirs::data_dir().unwrap().join(APP_ID)
+}
+
+/// Data directory of the portable mode, none if it's not turned on
+fn portable_data_dir() -> Option<PathBuf> {
+    let executable_dir = std::env::current_exe().ok()?.parent()?.to_path_buf();
+    executable_dir
+        .join(PORTABLE_MARKER)
+        .exists()
+        .then(|| executable_dir.join(PORTABLE_DATA_DIR))
+}
+
+/// Data directory chosen in the settings, none if it wasn't moved
+fn configured_data_dir() -> Option<PathBuf> {
+    let location = fs::read_to_string(default_data_dir().join(DATA_DIR_POINTER)).ok()?;
+    let location = location.trim();
+    (!location.is_empty()).then(|| PathBuf::from(location))
+}
+
+/// Chooses the data directory: the one given in the command line, the portable one, the one
+/// chosen in the settings or the default one, in that order
+pub fn init(command_line_dir: Option<PathBuf>) {
+    let data_dir = command_line_dir
+        .map(|dir| (dir, DataDirSource::CommandLine))
+        .or_else(|| portable_data_dir().map(|dir| (dir, DataDirSource::Portable)))
+        .or_else(|| configured_data_dir().map(|dir| (dir, DataDirSource::Settings)))
+        .unwrap_or_else(|| (default_data_dir(), DataDirSource::Default));
+
+    if let Ok(mut current) = DATA_DIR.write() {
+        *current = Some(data_dir);
+    }
+}
+
+/// Where the cache, sprites and the rest of the data of StarryDex are kept
+pub fn app_data_dir() -> PathBuf {
+    DATA_DIR
+        .read()
+        .ok()
+        .and_then(|current| current.as_ref().map(|(dir, _)| dir.clone()))
+        .unwrap_or_else(default_data_dir)
+}
+
+/// What chose the current data directory
+pub fn data_dir_source() -> DataDirSource {
+    DATA_DIR
+        .read()
+        .ok()
+        .and_then(|current| current.as_ref().map(|(_, source)| *source))
+        .unwrap_or(DataDirSource::Default)
+}
+
+/// Moves the data to another directory, which has to be empty, and keeps it there from now on.
+/// The data is copied first and only removed from the current directory once all of it is
+/// copied, so a move that fails leaves it where it was.
+pub fn move_data_dir(to: &Path) -> Result<(), String> {
+    let from = app_data_dir();
+    let default_dir = default_data_dir();
+    if to == from {
+        return Ok(());
+    }
+    if to.starts_with(&from) || from.starts_with(to) {
+        return Err(String::from(
+            "The new folder can't be inside the current one or contain it",
+        ));
+    }
+
+    let is_empty = fs::read_dir(to)
+        .map(|entries| {
+            entries
+                .flatten()
+                .all(|entry| entry.file_name() == DATA_DIR_POINTER)
+        })
+        .unwrap_or(true);
+    if !is_empty {
+        return Err(String::from("The new folder isn't empty"));
+    }
+
+    fs::create_dir_all(to).map_err(|e| e.to_string())?;
+    if let Err(e) = copy_dir_contents(&from, to) {
+        let _ = clear_dir(to);
+        return Err(e.to_string());
+    }
+
+    let pointer_file = default_dir.join(DATA_DIR_POINTER);
+    let switched = if to == default_dir {
+        fs::remove_file(&pointer_file)
+    } else {
+        fs::create_dir_all(&default_dir)
+            .and_then(|()| fs::write(&pointer_file, to.to_string_lossy().as_bytes()))
+    };
+    if let Err(e) = switched {
+        let _ = clear_dir(to);
+        return Err(e.to_string());
+    }
+
+    let source = if to == default_dir {
+        DataDirSource::Default
+    } else {
+        DataDirSource::Settings
+    };
+    if let Ok(mut current) = DATA_DIR.write() {
+        *current = Some((to.to_path_buf(), source));
+    }
+
+    // The data is already in the new directory, failing to remove it only leaves a copy behind
+    if let Err(e) = clear_dir(&from) {
+        eprintln!("Failed to remove the data from {}: {}", from.display(), e);
+    }
+    Ok(())
+}
+
+/// Copies everything inside a directory to another one, but the pointer to the data directory
+fn copy_dir_contents(from: &Path, to: &Path) -> std::io::Result<()> {
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.file_name() == DATA_DIR_POINTER {
+            continue;
+        }
+
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            fs::create_dir_all(&target)?;
+            copy_dir_contents(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes everything inside a directory, but the pointer to the data directory
+fn clear_dir(dir: &Path) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_name() != DATA_DIR_POINTER {
+            remove_path(entry.path())?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+use crate::{app::StarryPokemon, data_dir::app_data_dir};
+
+/// First id given to the Pokémon of data packs, so they never clash with the ones of PokéApi
+pub const DATA_PACK_ID_START: i64 = 1_000_000;
+/// Ids reserved for the Pokémon of each data pack
+const DATA_PACK_ID_RANGE: i64 = 100_000;
+
+/// A data pack file: a JSON file with the name of the pack and its Pokémon, in the same format
+/// as the Pokémon of the cache
+#[derive(Debug, Deserialize)]
+struct DataPackFile {
+    name: String,
+    pokemon: Vec<StarryPokemon>,
+}
+
+/// A fan-made dex (fakemon, ROM hacks...) loaded from the data packs folder
+#[derive(Debug, Clone)]
+pub struct DataPack {
+    /// Name of the pack file without its extension, used to enable or disable it
+    pub id: String,
+    pub name: String,
+    pub pokemon: Vec<StarryPokemon>,
+}
+
+/// Folder where users put their data packs
+pub fn data_packs_dir() -> PathBuf {
+    app_data_dir().join("data_packs")
+}
+
+/// Reads every data pack of the data packs folder, sorted by file name. Packs that can't be
+/// read, or have Pokémon with ids out of the range of a pack, are skipped.
+pub fn load_data_packs() -> Vec<DataPack> {
+    let Ok(entries) = std::fs::read_dir(data_packs_dir()) else {
+        return Vec::new();
+    };
+
+    let mut pack_files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        })
+        .collect();
+    pack_files.sort();
+
+    pack_files
+        .iter()
+        .filter_map(|path| match read_data_pack(path) {
+            Ok(data_pack) => Some(data_pack),
+            Err(e) => {
+                eprintln!("Failed to read the data pack {:?}: {}", path, e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn read_data_pack(path: &Path) -> Result<DataPack, Box<dyn std::error::Error>> {
+    let pack_file: DataPackFile = serde_json::from_slice(&std::fs::read(path)?)?;
+    let pack_dir = path.parent().unwrap_or(Path::new("."));
+
+    // The ids are moved to the range of the pack, so they have to fit in it without repeating
+    let mut ids = std::collections::BTreeSet::new();
+    for starry_pokemon in &pack_file.pokemon {
+        let id = starry_pokemon.pokemon.id;
+        if !(1..DATA_PACK_ID_RANGE).contains(&id) {
+            return Err(format!(
+                "{} has the id {}, the ids go from 1 to {}",
+                starry_pokemon.pokemon.name,
+                id,
+                DATA_PACK_ID_RANGE - 1
+            )
+            .into());
+        }
+        if !ids.insert(id) {
+            return Err(format!("More than one Pokémon has the id {}", id).into());
+        }
+    }
+
+    // Sprite paths may be relative to the pack file
+    let pokemon = pack_file
+        .pokemon
+        .into_iter()
+        .map(|mut starry_pokemon| {
+            starry_pokemon.sprite_path = starry_pokemon.sprite_path.map(|sprite_path| {
+                pack_dir
+                    .join(&sprite_path)
+                    .to_str()
+                    .map(String::from)
+                    .unwrap_or(sprite_path)
+            });
+            starry_pokemon
+        })
+        .collect();
+
+    Ok(DataPack {
+        id: path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string(),
+        name: pack_file.name,
+        pokemon,
+    })
+}
+
+impl DataPack {
+    /// Pokémon of the pack with their ids moved to the range of the pack, given its position
+    /// among the loaded packs. The ids of a loaded pack always fit in its range.
+    pub fn namespaced_pokemon(&self, index: usize) -> impl Iterator<Item = StarryPokemon> + '_ {
+        let id_start = DATA_PACK_ID_START + index as i64 * DATA_PACK_ID_RANGE;
+        self.pokemon.iter().cloned().map(move |mut starry_pokemon| {
+            starry_pokemon.pokemon.id += id_start;
+            starry_pokemon
+        })
+    }
+}
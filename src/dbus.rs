@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use futures::{channel::mpsc::UnboundedSender, Stream, StreamExt};
+
+use crate::cli::{ActivationRequest, OpenRequest};
+
+/// Object the interface is served at, the one the single instance activation is served at too
+const DBUS_PATH: &str = "/dev/mariinkys/StarryDex";
+
+/// The dev.mariinkys.StarryDex interface, with which other programs drive the running instance
+struct StarryDexInterface {
+    requests: UnboundedSender<ActivationRequest>,
+}
+
+#[zbus::interface(name = "dev.mariinkys.StarryDex")]
+impl StarryDexInterface {
+    /// Shows the Pokémon matching the search, with the same operators as the search bar
+    async fn search(&self, query: String) {
+        let _ = self
+            .requests
+            .unbounded_send(ActivationRequest::Search(query));
+    }
+
+    /// Opens the details of the Pokémon with the given Pokédex number
+    async fn show_pokemon(&self, id: i64) -> zbus::fdo::Result<()> {
+        if id <= 0 {
+            return Err(zbus::fdo::Error::InvalidArgs(format!(
+                "Invalid Pokédex number: {}",
+                id
+            )));
+        }
+
+        let _ = self
+            .requests
+            .unbounded_send(ActivationRequest::Open(OpenRequest::Id(id)));
+        Ok(())
+    }
+}
+
+/// Serves the interface on the connection owning the name of the app, and returns the requests
+/// made through it
+pub fn serve_requests(connection: zbus::Connection) -> impl Stream<Item = ActivationRequest> {
+    let (sender, receiver) = futures::channel::mpsc::unbounded();
+
+    futures::stream::once(async move {
+        let interface = StarryDexInterface { requests: sender };
+        if let Err(e) = connection.object_server().at(DBUS_PATH, interface).await {
+            eprintln!("Failed to serve the D-Bus interface: {}", e);
+        }
+    })
+    .filter_map(|()| futures::future::ready(None::<ActivationRequest>))
+    .chain(receiver)
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::i18n::LANGUAGE_LOADER;
+use i18n_embed::LanguageLoader;
+
+const NEW_ISSUE_URL: &str = "https://github.com/mariinkys/starrydex/issues/new";
+/// Longest crash report put in an issue url, longer urls are refused by GitHub
+const MAX_CRASH_REPORT_LENGTH: usize = 6000;
+
+/// Details of the running application, useful to make sense of a bug report
+pub struct Diagnostics {
+    /// Files of the cache and whether they exist
+    pub cache_files: Vec<(String, bool)>,
+    /// Amount of Pokémon that have been loaded
+    pub pokemon_count: usize,
+}
+
+impl Diagnostics {
+    /// Human readable report of the diagnostics
+    pub fn report(&self) -> String {
+        let cache_files = self
+            .cache_files
+            .iter()
+            .map(|(file, exists)| {
+                format!(
+                    "- {}: {}",
+                    file,
+                    if *exists { "present" } else { "missing" }
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            "- Version: {}\n- OS: {} ({})\n- Locale: {}\n- Loaded Pokémon: {}\n\nCache:\n{}",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH,
+            LANGUAGE_LOADER.current_language(),
+            self.pokemon_count,
+            cache_files
+        )
+    }
+
+    /// Url of a new GitHub issue prefilled with the diagnostics
+    pub fn issue_url(&self) -> String {
+        let body = format!(
+            "**Describe the problem**\n\n\n**Steps to reproduce**\n\n\n**Terminal output (if any)**\n\n```\n\n```\n\n**Diagnostics**\n\n{}\n",
+            self.report()
+        );
+
+        prefilled_issue_url(body)
+    }
+}
+
+/// Url of a new GitHub issue prefilled with the report of a crash, cut short if it's too long
+pub fn crash_issue_url(crash_report: &str) -> String {
+    let crash_report = match crash_report.char_indices().nth(MAX_CRASH_REPORT_LENGTH) {
+        Some((end, _)) => format!("{}\n...", &crash_report[..end]),
+        None => crash_report.to_string(),
+    };
+    let body = format!(
+        "**What were you doing when StarryDex crashed?**\n\n\n**Crash report**\n\n```\n{}\n```\n",
+        crash_report
+    );
+
+    prefilled_issue_url(body)
+}
+
+fn prefilled_issue_url(body: String) -> String {
+    match reqwest::Url::parse_with_params(NEW_ISSUE_URL, &[("body", body)]) {
+        Ok(url) => url.to_string(),
+        Err(_) => NEW_ISSUE_URL.to_string(),
+    }
+}
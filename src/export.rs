@@ -0,0 +1,382 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::{
+    app::{StarryMove, StarryPokemon, StarryPokemonDetails},
+    config::MeasurementUnits,
+    fl,
+    natures::Nature,
+    rankings::RankedStat,
+    stat_calculator::{StatCalculator, MAX_IV, MAX_LEVEL},
+    utils::{capitalize_string, format_height, format_weight},
+};
+
+/// Stat names as Pokémon Showdown writes them, in the `CALCULATOR_STATS` order
+const SHOWDOWN_STATS: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// Printable page of a Pokémon, `{{key}}` placeholders are replaced when rendering
+const DETAILS_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{{name}}</title>
+<style>
+body { font-family: sans-serif; max-width: 800px; margin: 2em auto; color: #222; }
+h1 { margin-bottom: 0; }
+h2 { border-bottom: 1px solid #999; padding-bottom: 0.2em; }
+.genus { color: #666; margin-top: 0; }
+img { float: right; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.2em 0.5em; border-bottom: 1px solid #ddd; }
+@media print { h2 { break-after: avoid; } table { break-inside: auto; } }
+</style>
+</head>
+<body>
+<img src="{{sprite}}" alt="">
+<h1>#{{id}} {{name}}</h1>
+<p class="genus">{{genus}}</p>
+<p>{{flavor_text}}</p>
+<table>
+{{info}}
+</table>
+<h2>{{stats_title}}</h2>
+<table>
+{{stats}}
+</table>
+<h2>{{held_items_title}}</h2>
+<table>
+{{held_items}}
+</table>
+<h2>{{encounters_title}}</h2>
+<table>
+{{encounters}}
+</table>
+<h2>{{moves_title}}</h2>
+<table>
+{{moves}}
+</table>
+</body>
+</html>
+"#;
+
+/// Replaces every `{{key}}` placeholder of the template with its value
+fn render(template: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |rendered, (key, value)| {
+            rendered.replace(&format!("{{{{{}}}}}", key), value)
+        })
+}
+
+/// Escapes the characters that have a meaning in HTML
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Table rows for the given label/value pairs
+fn table_rows(rows: impl IntoIterator<Item = (String, String)>) -> String {
+    rows.into_iter()
+        .map(|(label, value)| {
+            format!(
+                "<tr><th>{}</th><td>{}</td></tr>",
+                escape(&label),
+                escape(&value)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Renders the printable page of a Pokémon, the moves are only listed if they have been loaded
+pub fn render_pokemon_details(
+    starry_pokemon: &StarryPokemon,
+    details: &StarryPokemonDetails,
+    moves: Option<&[StarryMove]>,
+    units: MeasurementUnits,
+) -> String {
+    let pokemon = &starry_pokemon.pokemon;
+
+    let info = table_rows([
+        (
+            fl!("types"),
+            pokemon
+                .types
+                .iter()
+                .map(|t| capitalize_string(t))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ),
+        (
+            fl!("abilities"),
+            pokemon
+                .abilities
+                .iter()
+                .map(|a| capitalize_string(a))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ),
+        (fl!("height"), format_height(pokemon.height, units)),
+        (fl!("weight"), format_weight(pokemon.weight, units)),
+    ]);
+
+    let stats = table_rows([
+        (fl!("hp"), pokemon.stats.hp.to_string()),
+        (fl!("attack"), pokemon.stats.attack.to_string()),
+        (fl!("defense"), pokemon.stats.defense.to_string()),
+        (fl!("sp-a"), pokemon.stats.sp_attack.to_string()),
+        (fl!("sp-d"), pokemon.stats.sp_defense.to_string()),
+        (fl!("spd"), pokemon.stats.speed.to_string()),
+    ]);
+
+    let held_items = table_rows(details.held_items.iter().map(|held_item| {
+        (
+            capitalize_string(&held_item.item),
+            held_item
+                .version_details
+                .iter()
+                .map(|v| format!("{} ({}%)", capitalize_string(&v.version), v.rarity))
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }));
+
+    let encounters = table_rows(
+        details
+            .encounter_info
+            .iter()
+            .map(|encounter| (encounter.city.clone(), encounter.games_method.join("; "))),
+    );
+
+    let moves = table_rows(
+        moves
+            .unwrap_or_default()
+            .iter()
+            .filter(|pokemon_move| pokemon_move.learned_by_pokemon.contains(&pokemon.name))
+            .map(|pokemon_move| {
+                (
+                    capitalize_string(&pokemon_move.name),
+                    format!(
+                        "{} · {}",
+                        capitalize_string(&pokemon_move.type_name),
+                        capitalize_string(&pokemon_move.damage_class)
+                    ),
+                )
+            }),
+    );
+
+    let genus = starry_pokemon
+        .specie
+        .as_ref()
+        .and_then(|specie| specie.localized_genus())
+        .unwrap_or_default();
+
+    render(
+        DETAILS_TEMPLATE,
+        &[
+            ("name", escape(&starry_pokemon.display_name())),
+            ("id", pokemon.id.to_string()),
+            (
+                "sprite",
+                escape(starry_pokemon.sprite_path.as_deref().unwrap_or_default()),
+            ),
+            ("genus", escape(genus)),
+            (
+                "flavor_text",
+                escape(details.localized_flavor_text().unwrap_or_default()),
+            ),
+            ("info", info),
+            ("stats_title", escape(&fl!("stats"))),
+            ("stats", stats),
+            ("held_items_title", escape(&fl!("held-items"))),
+            ("held_items", held_items),
+            ("encounters_title", escape(&fl!("encounters"))),
+            ("encounters", encounters),
+            ("moves_title", escape(&fl!("moves-dex"))),
+            ("moves", moves),
+        ],
+    )
+}
+
+/// Writes the printable page of a Pokémon to the documents directory and returns its path
+pub async fn export_pokemon_details(
+    file_name: String,
+    contents: String,
+) -> Result<PathBuf, String> {
+    write_export(format!("{}.html", file_name), contents).await
+}
+
+/// Format of an exported Pokémon list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    Csv,
+    Json,
+}
+
+/// A row of an exported Pokémon list
+#[derive(Debug, Serialize)]
+struct ListRow<'a> {
+    id: i64,
+    name: &'a str,
+    types: &'a [String],
+    generation: Option<&'a str>,
+    hp: i64,
+    attack: i64,
+    defense: i64,
+    sp_attack: i64,
+    sp_defense: i64,
+    speed: i64,
+    total: i64,
+}
+
+/// Quotes a CSV field if it has characters with a meaning in CSV
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders the given Pokémon with their stats, types and generation in the given format
+pub fn render_pokemon_list(pokemon_list: &[StarryPokemon], format: ListFormat) -> String {
+    let rows: Vec<ListRow> = pokemon_list
+        .iter()
+        .map(|starry_pokemon| {
+            let pokemon = &starry_pokemon.pokemon;
+            ListRow {
+                id: pokemon.id,
+                name: &pokemon.name,
+                types: &pokemon.types,
+                generation: starry_pokemon
+                    .specie
+                    .as_ref()
+                    .and_then(|specie| specie.generation.as_deref()),
+                hp: pokemon.stats.hp,
+                attack: pokemon.stats.attack,
+                defense: pokemon.stats.defense,
+                sp_attack: pokemon.stats.sp_attack,
+                sp_defense: pokemon.stats.sp_defense,
+                speed: pokemon.stats.speed,
+                total: RankedStat::Total.value(&pokemon.stats),
+            }
+        })
+        .collect();
+
+    match format {
+        ListFormat::Json => serde_json::to_string_pretty(&rows).unwrap_or_default(),
+        ListFormat::Csv => std::iter::once(String::from(
+            "id,name,types,generation,hp,attack,defense,sp_attack,sp_defense,speed,total",
+        ))
+        .chain(rows.iter().map(|row| {
+            format!(
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                row.id,
+                csv_field(row.name),
+                csv_field(&row.types.join("/")),
+                csv_field(row.generation.unwrap_or_default()),
+                row.hp,
+                row.attack,
+                row.defense,
+                row.sp_attack,
+                row.sp_defense,
+                row.speed,
+                row.total
+            )
+        }))
+        .collect::<Vec<String>>()
+        .join("\n"),
+    }
+}
+
+/// Writes a Pokémon list, rendered with `render_pokemon_list`, to the documents directory and returns its path
+pub async fn export_pokemon_list(contents: String, format: ListFormat) -> Result<PathBuf, String> {
+    let extension = match format {
+        ListFormat::Csv => "csv",
+        ListFormat::Json => "json",
+    };
+    write_export(format!("pokemon-list.{}", extension), contents).await
+}
+
+/// Writes an exported file to the StarryDex folder of the documents directory and returns its path
+pub async fn write_export(file_name: String, contents: String) -> Result<PathBuf, String> {
+    let export_dir = dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| String::from("No documents directory"))?
+        .join("StarryDex");
+
+    tokio::fs::create_dir_all(&export_dir)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let export_file = export_dir.join(file_name);
+    tokio::fs::write(&export_file, contents)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(export_file)
+}
+
+/// Name as Pokémon Showdown writes it ("landorus-therian" is "Landorus-Therian")
+fn showdown_name(name: &str) -> String {
+    capitalize_string(name).replace(' ', "-")
+}
+
+/// Pokémon Showdown set of a Pokémon, with the level, nature, EVs and IVs of the stat calculator.
+/// Values left at their default (level 100, 0 EVs, 31 IVs) are omitted, like Showdown does.
+pub fn render_showdown_set(
+    starry_pokemon: &StarryPokemon,
+    calculator: &StatCalculator,
+    nature: Option<&Nature>,
+) -> String {
+    let pokemon = &starry_pokemon.pokemon;
+    let mut lines = vec![showdown_name(&pokemon.name)];
+
+    if let Some(ability) = pokemon.abilities.iter().find(|a| !a.ends_with("(HIDDEN)")) {
+        lines.push(format!("Ability: {}", capitalize_string(ability)));
+    }
+    if calculator.level != MAX_LEVEL {
+        lines.push(format!("Level: {}", calculator.level));
+    }
+
+    let spread = |values: Vec<(u16, &str)>| {
+        values
+            .into_iter()
+            .map(|(value, stat)| format!("{} {}", value, stat))
+            .collect::<Vec<String>>()
+            .join(" / ")
+    };
+
+    let evs: Vec<(u16, &str)> = calculator
+        .evs
+        .iter()
+        .zip(SHOWDOWN_STATS)
+        .filter(|(evs, _)| **evs > 0)
+        .map(|(evs, stat)| (*evs, stat))
+        .collect();
+    if !evs.is_empty() {
+        lines.push(format!("EVs: {}", spread(evs)));
+    }
+
+    if let Some(nature) = nature {
+        lines.push(format!("{} Nature", capitalize_string(nature.name)));
+    }
+
+    let ivs: Vec<(u16, &str)> = calculator
+        .ivs
+        .iter()
+        .zip(SHOWDOWN_STATS)
+        .filter(|(ivs, _)| **ivs != MAX_IV)
+        .map(|(ivs, stat)| (*ivs as u16, stat))
+        .collect();
+    if !ivs.is_empty() {
+        lines.push(format!("IVs: {}", spread(ivs)));
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+/// Main series games (as PokéApi names their versions) with a short label, from the oldest to the newest
+pub const MAIN_SERIES_GAMES: &[(&str, &str)] = &[
+    ("red", "R"),
+    ("blue", "B"),
+    ("yellow", "Y"),
+    ("gold", "G"),
+    ("silver", "S"),
+    ("crystal", "C"),
+    ("ruby", "R"),
+    ("sapphire", "S"),
+    ("emerald", "E"),
+    ("firered", "FR"),
+    ("leafgreen", "LG"),
+    ("diamond", "D"),
+    ("pearl", "P"),
+    ("platinum", "Pt"),
+    ("heartgold", "HG"),
+    ("soulsilver", "SS"),
+    ("black", "B"),
+    ("white", "W"),
+    ("black-2", "B2"),
+    ("white-2", "W2"),
+    ("x", "X"),
+    ("y", "Y"),
+    ("omega-ruby", "OR"),
+    ("alpha-sapphire", "AS"),
+    ("sun", "Su"),
+    ("moon", "Mo"),
+    ("ultra-sun", "US"),
+    ("ultra-moon", "UM"),
+    ("lets-go-pikachu", "LGP"),
+    ("lets-go-eevee", "LGE"),
+    ("sword", "Sw"),
+    ("shield", "Sh"),
+    ("brilliant-diamond", "BD"),
+    ("shining-pearl", "SP"),
+    ("legends-arceus", "LA"),
+    ("scarlet", "Sc"),
+    ("violet", "Vi"),
+];
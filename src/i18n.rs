@@ -8,15 +8,47 @@ use i18n_embed::{
     DefaultLocalizer, LanguageLoader, Localizer,
 };
 use rust_embed::RustEmbed;
-use std::sync::LazyLock;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{LazyLock, OnceLock, RwLock};
+
+use crate::{fl, utils::capitalize_string};
+
+/// Why the requested language isn't the one shown, set by `init`
+static LOCALIZATION_WARNING: OnceLock<String> = OnceLock::new();
 
 /// Applies the requested language(s) to requested translations from the `fl!()` macro.
+/// Languages without translations fall back to the embedded English ones instead of failing.
 pub fn init(requested_languages: &[LanguageIdentifier]) {
-    if let Err(why) = localizer().select(requested_languages) {
-        eprintln!("error while loading fluent localizations: {why}");
+    let warning = match localizer().select(requested_languages) {
+        Ok(selected) => requested_languages
+            .first()
+            .filter(|requested| {
+                !selected
+                    .iter()
+                    .any(|language| language.language == requested.language)
+            })
+            .map(|requested| fl!("missing-translation", language = requested.to_string())),
+        Err(why) => {
+            eprintln!("error while loading fluent localizations: {why}");
+
+            // Whatever was loaded may be incomplete, English is always embedded in the binary
+            if let Err(why) = LANGUAGE_LOADER.load_fallback_language(&Localizations) {
+                eprintln!("error while loading the fallback localizations: {why}");
+            }
+            Some(fl!("translation-failed"))
+        }
+    };
+
+    if let Some(warning) = warning {
+        let _ = LOCALIZATION_WARNING.set(warning);
     }
 }
 
+/// Why the requested language isn't the one shown, none if it is
+pub fn localization_warning() -> Option<&'static str> {
+    LOCALIZATION_WARNING.get().map(String::as_str)
+}
+
 // Get the `Localizer` to be used for localizing this library.
 #[must_use]
 pub fn localizer() -> Box<dyn Localizer> {
@@ -37,6 +69,85 @@ pub static LANGUAGE_LOADER: LazyLock<FluentLanguageLoader> = LazyLock::new(|| {
     loader
 });
 
+/// Codes of the language in use, as PokéApi names them, from the most to the least specific.
+pub static POKEAPI_LANGUAGES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    let current_language = LANGUAGE_LOADER.current_language();
+    let language = current_language.language.as_str().to_string();
+
+    match current_language.script {
+        Some(script) => vec![format!("{}-{}", language, script.as_str()), language],
+        None => vec![language],
+    }
+});
+
+/// Picks the entry for the language in use from a map keyed by PokéApi language codes,
+/// falling back to English if the language in use is not available.
+pub fn pick_localized(entries: &BTreeMap<String, String>) -> Option<&str> {
+    POKEAPI_LANGUAGES
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once("en"))
+        .find_map(|language| entries.get(language))
+        .map(String::as_str)
+}
+
+/// Labels shown many times on every frame (types, generations), localized once per language
+struct LabelCache {
+    language: LanguageIdentifier,
+    labels: HashMap<String, String>,
+}
+
+static LABEL_CACHE: LazyLock<RwLock<LabelCache>> = LazyLock::new(|| {
+    RwLock::new(LabelCache {
+        language: LANGUAGE_LOADER.current_language(),
+        labels: HashMap::new(),
+    })
+});
+
+/// Returns the cached label for the key, localizing it if it's not cached for the language in use
+fn cached_label(key: &str, localize: impl FnOnce() -> String) -> String {
+    let language = LANGUAGE_LOADER.current_language();
+
+    if let Ok(cache) = LABEL_CACHE.read() {
+        if cache.language == language {
+            if let Some(label) = cache.labels.get(key) {
+                return label.clone();
+            }
+        }
+    }
+
+    let label = localize();
+    if let Ok(mut cache) = LABEL_CACHE.write() {
+        // The language changed, every cached label is outdated
+        if cache.language != language {
+            cache.language = language;
+            cache.labels.clear();
+        }
+        cache.labels.insert(key.to_string(), label.clone());
+    }
+
+    label
+}
+
+/// Localized name of a Pokémon type, as PokéApi names it ("fire")
+pub fn type_label(type_name: &str) -> String {
+    cached_label(type_name, || {
+        if LANGUAGE_LOADER.has(type_name) {
+            LANGUAGE_LOADER.get(type_name)
+        } else {
+            capitalize_string(type_name)
+        }
+    })
+}
+
+/// Localized name of a generation, as PokéApi names it ("generation-iii")
+pub fn generation_label(generation: &str) -> String {
+    cached_label(generation, || {
+        let numeral = generation.trim_start_matches("generation-").to_uppercase();
+        LANGUAGE_LOADER.get_args("generation", HashMap::from([("generation", numeral)]))
+    })
+}
+
 /// Request a localized string by ID from the i18n/ directory.
 #[macro_export]
 macro_rules! fl {
@@ -1,9 +1,15 @@
 // SPDX-License-Identifier: GPL-3.0-only
 use cosmic::iced_core::image;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::{Mutex, OnceLock};
+use tokio::sync::Notify;
 
 pub(crate) static IMAGE_CACHE: OnceLock<Mutex<ImageCache>> = OnceLock::new();
+/// Notified when a sprite that isn't decoded is asked for, so it's decoded in the background
+static SPRITES_QUEUED: Notify = Notify::const_new();
+
+/// Memory the decoded sprites may take when the config doesn't set a limit
+pub const DEFAULT_SPRITE_CACHE_MB: usize = 64;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct ImageCacheKey {
@@ -12,6 +18,28 @@ pub struct ImageCacheKey {
 
 pub struct ImageCache {
     cache: HashMap<ImageCacheKey, image::Handle>,
+    sprites: SpriteCache,
+    /// Sprites asked for before they were decoded, waiting for the background decoding
+    queued: BTreeSet<String>,
+    /// Sprites that couldn't be decoded, left for the renderer to show as it can
+    undecodable: HashSet<String>,
+}
+
+/// Decoded sprites by path, the least recently used ones are dropped once they take more memory
+/// than the limit
+struct SpriteCache {
+    sprites: HashMap<String, CachedSprite>,
+    /// Paths of the sprites by the time they were last used, oldest first
+    last_used: BTreeMap<u64, String>,
+    clock: u64,
+    size: usize,
+    limit: usize,
+}
+
+struct CachedSprite {
+    handle: image::Handle,
+    size: usize,
+    last_used: u64,
 }
 
 impl ImageCache {
@@ -30,7 +58,26 @@ impl ImageCache {
 
         bundle!("fallback");
 
-        Self { cache }
+        // Transparent pixel shown where a sprite goes until it's decoded
+        cache.insert(
+            ImageCacheKey {
+                name: "placeholder",
+            },
+            image::Handle::from_rgba(1, 1, vec![0; 4]),
+        );
+
+        Self {
+            cache,
+            sprites: SpriteCache {
+                sprites: HashMap::new(),
+                last_used: BTreeMap::new(),
+                clock: 0,
+                size: 0,
+                limit: DEFAULT_SPRITE_CACHE_MB * 1024 * 1024,
+            },
+            queued: BTreeSet::new(),
+            undecodable: HashSet::new(),
+        }
     }
 
     fn get_image(&mut self, name: &'static str) -> image::Handle {
@@ -44,4 +91,134 @@ impl ImageCache {
         let mut image_cache = IMAGE_CACHE.get().unwrap().lock().unwrap();
         image_cache.get_image(name)
     }
+
+    /// Handle shown where a sprite goes until it's decoded
+    pub fn placeholder() -> image::Handle {
+        ImageCache::get("placeholder")
+    }
+
+    /// Decoded sprite of the given file, kept until it's among the least recently used ones once
+    /// the sprites go over the memory limit. It's never decoded here, a sprite that isn't decoded
+    /// yet is queued for `preload_sprites` and none is returned.
+    pub fn sprite(path: &str) -> Option<image::Handle> {
+        let mut image_cache = IMAGE_CACHE.get().unwrap().lock().unwrap();
+        if let Some(handle) = image_cache.sprites.get(path) {
+            return Some(handle);
+        }
+        if image_cache.undecodable.contains(path) {
+            return Some(image::Handle::from_path(path));
+        }
+
+        if image_cache.queued.insert(path.to_string()) {
+            SPRITES_QUEUED.notify_one();
+        }
+        None
+    }
+
+    /// Takes the sprites queued for decoding by `sprite`
+    pub fn take_queued_sprites() -> Vec<String> {
+        let mut image_cache = IMAGE_CACHE.get().unwrap().lock().unwrap();
+        std::mem::take(&mut image_cache.queued)
+            .into_iter()
+            .collect()
+    }
+
+    /// Paths of the given sprites that aren't decoded yet, nor known to be undecodable
+    pub fn uncached_sprites(paths: impl IntoIterator<Item = String>) -> Vec<String> {
+        let image_cache = IMAGE_CACHE.get().unwrap().lock().unwrap();
+        paths
+            .into_iter()
+            .filter(|path| {
+                !image_cache.sprites.sprites.contains_key(path)
+                    && !image_cache.undecodable.contains(path)
+            })
+            .collect()
+    }
+
+    /// Decodes the given sprites ahead of time, the cache is only locked to store each one so
+    /// the sprites being shown aren't held up
+    pub fn preload_sprites(paths: Vec<String>) {
+        for path in paths {
+            let decoded = decode_sprite(&path);
+            let mut image_cache = IMAGE_CACHE.get().unwrap().lock().unwrap();
+            match decoded {
+                Some((handle, size)) => image_cache.sprites.insert(&path, handle, size),
+                None => {
+                    image_cache.undecodable.insert(path);
+                }
+            }
+        }
+    }
+
+    /// Changes how much memory the decoded sprites may take, in megabytes (0 for the default)
+    pub fn set_sprite_cache_limit(megabytes: usize) {
+        let megabytes = if megabytes == 0 {
+            DEFAULT_SPRITE_CACHE_MB
+        } else {
+            megabytes
+        };
+
+        let mut image_cache = IMAGE_CACHE.get().unwrap().lock().unwrap();
+        image_cache.sprites.limit = megabytes * 1024 * 1024;
+        image_cache.sprites.evict();
+    }
+}
+
+impl SpriteCache {
+    fn get(&mut self, path: &str) -> Option<image::Handle> {
+        let sprite = self.sprites.get_mut(path)?;
+        self.clock += 1;
+        self.last_used.remove(&sprite.last_used);
+        sprite.last_used = self.clock;
+        self.last_used.insert(self.clock, path.to_string());
+        Some(sprite.handle.clone())
+    }
+
+    fn insert(&mut self, path: &str, handle: image::Handle, size: usize) {
+        self.clock += 1;
+        if let Some(replaced) = self.sprites.insert(
+            path.to_string(),
+            CachedSprite {
+                handle,
+                size,
+                last_used: self.clock,
+            },
+        ) {
+            self.last_used.remove(&replaced.last_used);
+            self.size -= replaced.size;
+        }
+        self.last_used.insert(self.clock, path.to_string());
+        self.size += size;
+        self.evict();
+    }
+
+    /// Drops the least recently used sprites until they fit in the limit
+    fn evict(&mut self) {
+        while self.size > self.limit {
+            let Some((_, path)) = self.last_used.pop_first() else {
+                break;
+            };
+            if let Some(sprite) = self.sprites.remove(&path) {
+                self.size -= sprite.size;
+            }
+        }
+    }
+}
+
+/// Stream with an item each time sprites are queued for decoding, for a subscription
+pub fn queued_sprites() -> impl futures::Stream<Item = ()> {
+    futures::stream::unfold((), |()| async {
+        SPRITES_QUEUED.notified().await;
+        Some(((), ()))
+    })
+}
+
+/// Decodes a sprite file, returns its handle and the memory its pixels take
+fn decode_sprite(path: &str) -> Option<(image::Handle, usize)> {
+    let decoded = ::image::open(path).ok()?.to_rgba8();
+    let (width, height) = decoded.dimensions();
+    let pixels = decoded.into_raw();
+    let size = pixels.len();
+
+    Some((image::Handle::from_rgba(width, height, pixels), size))
 }
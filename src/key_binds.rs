@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use cosmic::iced::keyboard::{key::Named, Key, Modifiers};
+use cosmic::widget::menu::{key_bind::Modifier, KeyBind};
+
+/// Named keys a shortcut can use, with the name they are saved as
+const NAMED_KEYS: [(Named, &str); 25] = [
+    (Named::Enter, "Enter"),
+    (Named::Space, "Space"),
+    (Named::Tab, "Tab"),
+    (Named::Backspace, "Backspace"),
+    (Named::Delete, "Delete"),
+    (Named::Home, "Home"),
+    (Named::End, "End"),
+    (Named::PageUp, "PageUp"),
+    (Named::PageDown, "PageDown"),
+    (Named::ArrowUp, "Up"),
+    (Named::ArrowDown, "Down"),
+    (Named::ArrowLeft, "Left"),
+    (Named::ArrowRight, "Right"),
+    (Named::F1, "F1"),
+    (Named::F2, "F2"),
+    (Named::F3, "F3"),
+    (Named::F4, "F4"),
+    (Named::F5, "F5"),
+    (Named::F6, "F6"),
+    (Named::F7, "F7"),
+    (Named::F8, "F8"),
+    (Named::F9, "F9"),
+    (Named::F10, "F10"),
+    (Named::F11, "F11"),
+    (Named::F12, "F12"),
+];
+
+/// Modifiers a shortcut can use, with the name they are saved as
+const MODIFIERS: [(Modifier, &str); 4] = [
+    (Modifier::Super, "Super"),
+    (Modifier::Ctrl, "Ctrl"),
+    (Modifier::Alt, "Alt"),
+    (Modifier::Shift, "Shift"),
+];
+
+/// Parses a shortcut saved in the config, like "Ctrl+Shift+K" or "F5"
+pub fn parse_key_bind(text: &str) -> Option<KeyBind> {
+    let mut parts: Vec<&str> = text.split('+').map(str::trim).collect();
+    let key_name = parts.pop().filter(|name| !name.is_empty())?;
+
+    let modifiers = parts
+        .into_iter()
+        .map(|part| {
+            MODIFIERS
+                .iter()
+                .find(|(_, name)| name.eq_ignore_ascii_case(part))
+                .map(|(modifier, _)| *modifier)
+        })
+        .collect::<Option<Vec<Modifier>>>()?;
+
+    let key = match NAMED_KEYS
+        .iter()
+        .find(|(_, name)| name.eq_ignore_ascii_case(key_name))
+    {
+        Some((named, _)) => Key::Named(*named),
+        None if key_name.chars().count() == 1 => Key::Character(key_name.to_lowercase().into()),
+        None => return None,
+    };
+
+    Some(KeyBind { modifiers, key })
+}
+
+/// Text a shortcut is saved as in the config and shown in the settings
+pub fn format_key_bind(key_bind: &KeyBind) -> String {
+    let key_name = match &key_bind.key {
+        Key::Named(named) => NAMED_KEYS
+            .iter()
+            .find(|(key, _)| key == named)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_default(),
+        Key::Character(character) => character.to_uppercase(),
+        Key::Unidentified => String::new(),
+    };
+
+    MODIFIERS
+        .iter()
+        .filter(|(modifier, _)| key_bind.modifiers.contains(modifier))
+        .map(|(_, name)| name.to_string())
+        .chain(std::iter::once(key_name))
+        .collect::<Vec<String>>()
+        .join("+")
+}
+
+/// Shortcut for a key press, none if the key can't be part of a shortcut (like a lone modifier)
+pub fn key_bind_from_press(modifiers: Modifiers, key: &Key) -> Option<KeyBind> {
+    let key = match key {
+        Key::Named(named) if NAMED_KEYS.iter().any(|(key, _)| key == named) => key.clone(),
+        Key::Character(character) => Key::Character(character.to_lowercase().into()),
+        _ => return None,
+    };
+
+    let modifiers = [
+        (modifiers.logo(), Modifier::Super),
+        (modifiers.control(), Modifier::Ctrl),
+        (modifiers.alt(), Modifier::Alt),
+        (modifiers.shift(), Modifier::Shift),
+    ]
+    .into_iter()
+    .filter_map(|(pressed, modifier)| pressed.then_some(modifier))
+    .collect();
+
+    Some(KeyBind { modifiers, key })
+}
@@ -2,14 +2,70 @@
 
 use image_cache::{ImageCache, IMAGE_CACHE};
 
+mod aliases;
 mod api;
 mod app;
+mod backup;
+mod challenges;
+mod cli;
 mod config;
+mod crash_report;
+mod data_dir;
+mod data_packs;
+mod dbus;
+mod diagnostics;
+mod export;
+mod games;
 mod i18n;
 mod image_cache;
+mod key_binds;
+mod natures;
+mod palette;
+mod rankings;
+mod search;
+mod session;
+mod share_card;
+mod sprite_sets;
+mod stat_calculator;
+mod storage;
+mod trivia;
+mod type_chart;
 mod utils;
+mod watchlist;
 
 fn main() -> cosmic::iced::Result {
+    let (action, data_dir) = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{error}\n\n{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    // Where the cache and sprites are kept, the query reads them from there too
+    data_dir::init(data_dir);
+
+    // Crash reports are saved to the data directory, offered for reporting on the next launch
+    crash_report::install_panic_hook();
+
+    let open_request = match action {
+        cli::CliAction::Run(open_request) => open_request,
+        cli::CliAction::Query { search, format } => {
+            match cli::run_query(&search, format) {
+                Ok(output) => println!("{output}"),
+                Err(error) => {
+                    eprintln!("{error}");
+                    std::process::exit(1);
+                }
+            }
+            return Ok(());
+        }
+        cli::CliAction::Help => {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+    };
+
     // Get the system's preferred languages.
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
 
@@ -22,6 +78,7 @@ fn main() -> cosmic::iced::Result {
     // Settings for configuring the application window and iced runtime.
     let settings = cosmic::app::Settings::default();
 
-    // Starts the application's event loop with `()` as the application's flags.
-    cosmic::app::run::<app::StarryDex>(settings, ())
+    // Starts the application's event loop with the Pokémon to open as the application's flags.
+    // If StarryDex is already running, it's activated through D-Bus instead and opens the Pokémon.
+    cosmic::app::run_single_instance::<app::StarryDex>(settings, cli::Flags::new(open_request))
 }
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::fl;
+
+/// A stat a nature can raise or lower (HP is never affected by natures)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatureStat {
+    Attack,
+    Defense,
+    SpAttack,
+    SpDefense,
+    Speed,
+}
+
+impl NatureStat {
+    /// Localized name of the stat
+    pub fn label(&self) -> String {
+        match self {
+            NatureStat::Attack => fl!("attack"),
+            NatureStat::Defense => fl!("defense"),
+            NatureStat::SpAttack => fl!("sp-a"),
+            NatureStat::SpDefense => fl!("sp-d"),
+            NatureStat::Speed => fl!("spd"),
+        }
+    }
+}
+
+/// A nature, raising one stat by 10% and lowering another one by 10%
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nature {
+    /// Name of the nature, as PokéApi names it
+    pub name: &'static str,
+    pub increased: NatureStat,
+    pub decreased: NatureStat,
+}
+
+impl Nature {
+    /// Natures that raise and lower the same stat have no effect
+    pub fn is_neutral(&self) -> bool {
+        self.increased == self.decreased
+    }
+
+    /// Percentage the nature leaves the given stat at (110 raised, 90 lowered, 100 otherwise)
+    pub fn percentage(&self, stat: NatureStat) -> i64 {
+        if self.is_neutral() {
+            100
+        } else if self.increased == stat {
+            110
+        } else if self.decreased == stat {
+            90
+        } else {
+            100
+        }
+    }
+}
+
+const fn nature(name: &'static str, increased: NatureStat, decreased: NatureStat) -> Nature {
+    Nature {
+        name,
+        increased,
+        decreased,
+    }
+}
+
+/// Every nature, in the order of their in-game index
+pub const NATURES: [Nature; 25] = {
+    use NatureStat::*;
+    [
+        nature("hardy", Attack, Attack),
+        nature("lonely", Attack, Defense),
+        nature("brave", Attack, Speed),
+        nature("adamant", Attack, SpAttack),
+        nature("naughty", Attack, SpDefense),
+        nature("bold", Defense, Attack),
+        nature("docile", Defense, Defense),
+        nature("relaxed", Defense, Speed),
+        nature("impish", Defense, SpAttack),
+        nature("lax", Defense, SpDefense),
+        nature("timid", Speed, Attack),
+        nature("hasty", Speed, Defense),
+        nature("serious", Speed, Speed),
+        nature("jolly", Speed, SpAttack),
+        nature("naive", Speed, SpDefense),
+        nature("modest", SpAttack, Attack),
+        nature("mild", SpAttack, Defense),
+        nature("quiet", SpAttack, Speed),
+        nature("bashful", SpAttack, SpAttack),
+        nature("rash", SpAttack, SpDefense),
+        nature("calm", SpDefense, Attack),
+        nature("gentle", SpDefense, Defense),
+        nature("sassy", SpDefense, Speed),
+        nature("careful", SpDefense, SpAttack),
+        nature("quirky", SpDefense, SpDefense),
+    ]
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neutral_natures_affect_no_stat() {
+        let neutral: Vec<&str> = NATURES
+            .iter()
+            .filter(|nature| nature.is_neutral())
+            .map(|nature| nature.name)
+            .collect();
+        assert_eq!(neutral, ["hardy", "docile", "serious", "bashful", "quirky"]);
+
+        for stat in [NatureStat::Attack, NatureStat::Speed] {
+            assert_eq!(NATURES[0].percentage(stat), 100);
+        }
+    }
+
+    #[test]
+    fn natures_raise_one_stat_and_lower_another() {
+        let adamant = NATURES[3];
+        assert_eq!(adamant.percentage(NatureStat::Attack), 110);
+        assert_eq!(adamant.percentage(NatureStat::SpAttack), 90);
+        assert_eq!(adamant.percentage(NatureStat::Speed), 100);
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+/// Most entries the command palette shows at once
+pub const MAX_PALETTE_ENTRIES: usize = 8;
+
+/// Scores how well the query matches the candidate, none if the characters of the query don't
+/// appear in order in the candidate. Matches at the start and consecutive matches score higher.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0;
+    let mut candidate_chars = candidate.chars().enumerate();
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.chars() {
+        let (index, _) = candidate_chars.find(|(_, c)| *c == query_char)?;
+        score += match previous_match {
+            Some(previous) if previous + 1 == index => 5,
+            _ if index == 0 => 10,
+            _ => 1,
+        };
+        previous_match = Some(index);
+    }
+
+    // Shorter candidates are closer to what was typed
+    Some(score * 100 - candidate.chars().count() as i64)
+}
+
+/// Pokédex number typed in the palette ("25" or "#25")
+pub fn parse_number(query: &str) -> Option<i64> {
+    query.trim().trim_start_matches('#').parse().ok()
+}
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    app::{StarryPokemon, StarryPokemonStats},
+    fl,
+};
+
+/// How many Pokémon are listed on the Rankings page
+pub const RANKING_SIZE: usize = 50;
+
+/// Generations the rankings can be filtered by, as PokéApi names them
+pub const GENERATIONS: [&str; 9] = [
+    "generation-i",
+    "generation-ii",
+    "generation-iii",
+    "generation-iv",
+    "generation-v",
+    "generation-vi",
+    "generation-vii",
+    "generation-viii",
+    "generation-ix",
+];
+
+/// What the Pokémon are ranked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankedStat {
+    Total,
+    Hp,
+    Attack,
+    Defense,
+    SpAttack,
+    SpDefense,
+    Speed,
+}
+
+impl RankedStat {
+    pub const ALL: [RankedStat; 7] = [
+        RankedStat::Total,
+        RankedStat::Hp,
+        RankedStat::Attack,
+        RankedStat::Defense,
+        RankedStat::SpAttack,
+        RankedStat::SpDefense,
+        RankedStat::Speed,
+    ];
+
+    /// Localized name of the stat
+    pub fn label(&self) -> String {
+        match self {
+            RankedStat::Total => fl!("total"),
+            RankedStat::Hp => fl!("hp"),
+            RankedStat::Attack => fl!("attack"),
+            RankedStat::Defense => fl!("defense"),
+            RankedStat::SpAttack => fl!("sp-a"),
+            RankedStat::SpDefense => fl!("sp-d"),
+            RankedStat::Speed => fl!("spd"),
+        }
+    }
+
+    /// Value of the stat for the given stats
+    pub fn value(&self, stats: &StarryPokemonStats) -> i64 {
+        match self {
+            RankedStat::Total => {
+                stats.hp
+                    + stats.attack
+                    + stats.defense
+                    + stats.sp_attack
+                    + stats.sp_defense
+                    + stats.speed
+            }
+            RankedStat::Hp => stats.hp,
+            RankedStat::Attack => stats.attack,
+            RankedStat::Defense => stats.defense,
+            RankedStat::SpAttack => stats.sp_attack,
+            RankedStat::SpDefense => stats.sp_defense,
+            RankedStat::Speed => stats.speed,
+        }
+    }
+}
+
+/// The `RANKING_SIZE` Pokémon with the highest value of the stat, optionally only the ones of
+/// the given generation and type, ties are ordered by Pokédex number
+pub fn top_pokemon<'a>(
+    pokemon_list: impl IntoIterator<Item = &'a StarryPokemon>,
+    stat: RankedStat,
+    generation: Option<&str>,
+    type_name: Option<&str>,
+) -> Vec<(&'a StarryPokemon, i64)> {
+    let mut ranking: Vec<(&StarryPokemon, i64)> = pokemon_list
+        .into_iter()
+        .filter(|p| {
+            generation.is_none_or(|generation| {
+                p.specie
+                    .as_ref()
+                    .and_then(|specie| specie.generation.as_deref())
+                    == Some(generation)
+            })
+        })
+        .filter(|p| {
+            type_name.is_none_or(|type_name| p.pokemon.types.iter().any(|t| t == type_name))
+        })
+        .map(|p| (p, stat.value(&p.pokemon.stats)))
+        .collect();
+
+    ranking.sort_by(|(a, a_value), (b, b_value)| {
+        b_value.cmp(a_value).then(a.pokemon.id.cmp(&b.pokemon.id))
+    });
+    ranking.truncate(RANKING_SIZE);
+    ranking
+}
+
+/// For every Pokémon, the top percentage it is in for each of the `RankedStat::ALL` stats
+/// (a 1 means it is on the top 1% of all the Pokémon)
+pub type StatPercentiles = HashMap<i64, [u32; RankedStat::ALL.len()]>;
+
+/// Computes the stat percentiles of every Pokémon of the list
+pub fn stat_percentiles(pokemon_list: &BTreeMap<i64, StarryPokemon>) -> StatPercentiles {
+    let total = pokemon_list.len();
+    let sorted_values: Vec<Vec<i64>> = RankedStat::ALL
+        .iter()
+        .map(|stat| {
+            let mut values: Vec<i64> = pokemon_list
+                .values()
+                .map(|p| stat.value(&p.pokemon.stats))
+                .collect();
+            values.sort_unstable_by(|a, b| b.cmp(a));
+            values
+        })
+        .collect();
+
+    pokemon_list
+        .iter()
+        .map(|(id, p)| {
+            let mut percentiles = [100; RankedStat::ALL.len()];
+            for (index, stat) in RankedStat::ALL.iter().enumerate() {
+                let value = stat.value(&p.pokemon.stats);
+                let rank = sorted_values[index].partition_point(|v| *v > value) + 1;
+                percentiles[index] = (rank * 100).div_ceil(total).max(1) as u32;
+            }
+            (*id, percentiles)
+        })
+        .collect()
+}
@@ -0,0 +1,559 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::{
+    aliases,
+    app::StarryPokemon,
+    fl,
+    rankings::{RankedStat, GENERATIONS},
+    type_chart,
+};
+
+/// Longest substrings of the names kept in the search index, longer searches are narrowed down
+/// with them and then checked against the names
+const SEARCH_INDEX_GRAM_LENGTH: usize = 3;
+/// How the stats are written on the stat operator
+const STAT_NAMES: [(&str, RankedStat); 7] = [
+    ("hp", RankedStat::Hp),
+    ("atk", RankedStat::Attack),
+    ("def", RankedStat::Defense),
+    ("spa", RankedStat::SpAttack),
+    ("spd", RankedStat::SpDefense),
+    ("spe", RankedStat::Speed),
+    ("bst", RankedStat::Total),
+];
+
+/// A single condition of a search query
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTerm {
+    /// Name contains the given text
+    Name(String),
+    /// Name is exactly the given text
+    ExactName(String),
+    /// Id is between the given bounds (both inclusive)
+    IdRange(i64, i64),
+    /// Has the given type
+    Type(String),
+    /// Has an ability that contains the given text
+    Ability(String),
+    /// Takes super effective damage from the given type
+    WeakTo(String),
+    /// Can be found in the wild in the given game
+    Game(String),
+    /// Was introduced in the given generation, as PokéApi names it
+    Generation(String),
+    /// Has a base stat (or total) that compares to the value as given
+    Stat(RankedStat, Comparison, i64),
+}
+
+/// How a stat is compared on a stat term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl Comparison {
+    /// Operators as they are written, the two character ones first so they aren't read as one
+    const OPERATORS: [(&'static str, Comparison); 5] = [
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+        ("=", Comparison::Equal),
+    ];
+
+    fn compare(&self, value: i64, to: i64) -> bool {
+        match self {
+            Comparison::Less => value < to,
+            Comparison::LessOrEqual => value <= to,
+            Comparison::Equal => value == to,
+            Comparison::GreaterOrEqual => value >= to,
+            Comparison::Greater => value > to,
+        }
+    }
+}
+
+/// An operator understood by the search parser
+pub struct SearchOperator {
+    /// Text a search token has to start with in order to use this operator
+    pub prefix: &'static str,
+    /// How the operator is written, shown on the search help
+    pub syntax: &'static str,
+    /// Example usage of the operator, shown on the search help
+    pub example: &'static str,
+    /// Localized explanation of the operator, shown on the search help
+    pub description: fn() -> String,
+    /// Parses the token (without the prefix) into a search term
+    parse: fn(&str) -> Option<SearchTerm>,
+}
+
+/// Every operator the search parser knows about, in the order they are tried
+pub const SEARCH_OPERATORS: &[SearchOperator] = &[
+    SearchOperator {
+        prefix: "\"",
+        syntax: "\"name\"",
+        example: "\"mew\"",
+        description: || fl!("search-op-exact"),
+        parse: |value| {
+            let value = value.trim_end_matches('"').trim();
+            (!value.is_empty()).then(|| SearchTerm::ExactName(normalize(value)))
+        },
+    },
+    SearchOperator {
+        prefix: "type:",
+        syntax: "type:<type>",
+        example: "type:fire",
+        description: || fl!("search-op-type"),
+        parse: |value| (!value.is_empty()).then(|| SearchTerm::Type(normalize(value))),
+    },
+    SearchOperator {
+        prefix: "ability:",
+        syntax: "ability:<ability>",
+        example: "ability:levitate",
+        description: || fl!("search-op-ability"),
+        parse: |value| (!value.is_empty()).then(|| SearchTerm::Ability(normalize(value))),
+    },
+    SearchOperator {
+        prefix: "weak-to:",
+        syntax: "weak-to:<type>",
+        example: "weak-to:ice",
+        description: || fl!("search-op-weak-to"),
+        parse: |value| (!value.is_empty()).then(|| SearchTerm::WeakTo(normalize(value))),
+    },
+    SearchOperator {
+        prefix: "game:",
+        syntax: "game:<game>",
+        example: "game:emerald",
+        description: || fl!("search-op-game"),
+        parse: |value| (!value.is_empty()).then(|| SearchTerm::Game(normalize(value))),
+    },
+    SearchOperator {
+        prefix: "gen:",
+        syntax: "gen:<generation>",
+        example: "gen:1",
+        description: || fl!("search-op-generation"),
+        parse: |value| {
+            let value = value.trim().to_lowercase();
+            let generation = match value.parse::<usize>() {
+                Ok(number) => GENERATIONS.get(number.checked_sub(1)?)?,
+                Err(_) => GENERATIONS.iter().find(|generation| {
+                    generation.strip_prefix("generation-") == Some(value.as_str())
+                })?,
+            };
+            Some(SearchTerm::Generation(generation.to_string()))
+        },
+    },
+    SearchOperator {
+        prefix: "#",
+        syntax: "#<id>",
+        example: "#25",
+        description: || fl!("search-op-id"),
+        parse: |value| value.parse().ok().map(|id| SearchTerm::IdRange(id, id)),
+    },
+    SearchOperator {
+        prefix: "",
+        syntax: "<stat><comparison><value>",
+        example: "spe>=100",
+        description: || fl!("search-op-stat"),
+        parse: |value| {
+            let value = value.to_lowercase();
+            let (rest, stat) = STAT_NAMES
+                .iter()
+                .find_map(|(name, stat)| Some((value.strip_prefix(name)?, *stat)))?;
+            let (rest, comparison) =
+                Comparison::OPERATORS
+                    .iter()
+                    .find_map(|(operator, comparison)| {
+                        Some((rest.strip_prefix(operator)?, *comparison))
+                    })?;
+            Some(SearchTerm::Stat(stat, comparison, rest.parse().ok()?))
+        },
+    },
+    SearchOperator {
+        prefix: "",
+        syntax: "<from>-<to>",
+        example: "1-151",
+        description: || fl!("search-op-id-range"),
+        parse: |value| {
+            let (from, to) = value.split_once('-')?;
+            let (from, to): (i64, i64) = (from.parse().ok()?, to.parse().ok()?);
+            Some(SearchTerm::IdRange(from.min(to), from.max(to)))
+        },
+    },
+    SearchOperator {
+        prefix: "",
+        syntax: "<name>",
+        example: "saur",
+        description: || fl!("search-op-name"),
+        parse: |value| Some(SearchTerm::Name(normalize(value))),
+    },
+];
+
+/// A parsed search query, every term has to match for a Pokémon to match
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+}
+
+impl SearchQuery {
+    /// Parses the given search input into a query, names that are a known alias search for the Pokémon they stand for
+    pub fn parse(input: &str, custom_aliases: &BTreeMap<String, String>) -> SearchQuery {
+        let terms = tokenize(input)
+            .into_iter()
+            .filter_map(|token| {
+                SEARCH_OPERATORS.iter().find_map(|operator| {
+                    match token.get(..operator.prefix.len()) {
+                        Some(prefix) if prefix.eq_ignore_ascii_case(operator.prefix) => {
+                            (operator.parse)(&token[operator.prefix.len()..])
+                        }
+                        _ => None,
+                    }
+                })
+            })
+            .map(|term| {
+                let aliased_name = match &term {
+                    SearchTerm::Name(name) | SearchTerm::ExactName(name) => {
+                        aliases::resolve(name, custom_aliases)
+                    }
+                    _ => None,
+                };
+                aliased_name.map_or(term, SearchTerm::ExactName)
+            })
+            .collect();
+
+        SearchQuery { terms }
+    }
+
+    /// Checks if the given Pokémon matches every term of the query
+    pub fn matches(&self, starry_pokemon: &StarryPokemon) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term_matches(term, starry_pokemon))
+    }
+
+    /// The Pokémon of the list matching every term of the query, the name terms are looked up in
+    /// the search index instead of checking every name
+    pub fn search<'a>(
+        &self,
+        pokemon_list: &'a BTreeMap<i64, StarryPokemon>,
+        search_index: &SearchIndex,
+    ) -> Vec<&'a StarryPokemon> {
+        let mut candidates: Option<BTreeSet<i64>> = None;
+        for term in &self.terms {
+            let Some(ids) = search_index.name_matches(term) else {
+                continue;
+            };
+            candidates = Some(match candidates {
+                Some(candidates) => candidates.intersection(&ids).copied().collect(),
+                None => ids,
+            });
+        }
+
+        let other_terms_match = |starry_pokemon: &&StarryPokemon| {
+            self.terms
+                .iter()
+                .filter(|term| !matches!(term, SearchTerm::Name(_) | SearchTerm::ExactName(_)))
+                .all(|term| term_matches(term, starry_pokemon))
+        };
+
+        match candidates {
+            Some(ids) => ids
+                .iter()
+                .filter_map(|id| pokemon_list.get(id))
+                .filter(other_terms_match)
+                .collect(),
+            None => pokemon_list.values().filter(other_terms_match).collect(),
+        }
+    }
+}
+
+/// Normalized names of the Pokémon (PokéApi and localized ones), built once so the search
+/// doesn't lowercase every name on every keystroke. It's saved next to the Pokémon cache.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// Hash of the names the index was built from, to know when it has to be built again
+    source: u64,
+    /// Every name with the id of its Pokémon, sorted by name for exact and prefix lookups
+    sorted_names: Vec<(String, i64)>,
+    /// Ids of the Pokémon with a name that contains each substring of up to
+    /// `SEARCH_INDEX_GRAM_LENGTH` characters
+    grams: HashMap<String, BTreeSet<i64>>,
+}
+
+impl SearchIndex {
+    /// Normalized names of every Pokémon of the list, what the index is built from
+    pub fn names_of(pokemon_list: &BTreeMap<i64, StarryPokemon>) -> Vec<(String, i64)> {
+        pokemon_list
+            .values()
+            .flat_map(|starry_pokemon| {
+                let id = starry_pokemon.pokemon.id;
+                std::iter::once((starry_pokemon.pokemon.name.to_lowercase(), id)).chain(
+                    starry_pokemon
+                        .localized_name()
+                        .map(|localized| (normalize(localized), id)),
+                )
+            })
+            .collect()
+    }
+
+    /// Hash of the names (FNV-1a, stable between builds unlike the std hasher)
+    pub fn source_of(names: &[(String, i64)]) -> u64 {
+        names
+            .iter()
+            .flat_map(|(name, id)| name.bytes().chain(id.to_le_bytes()))
+            .fold(0xcbf29ce484222325, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            })
+    }
+
+    /// Builds the index from the names given by `names_of`
+    pub fn build(mut names: Vec<(String, i64)>) -> SearchIndex {
+        let source = SearchIndex::source_of(&names);
+
+        let mut grams: HashMap<String, BTreeSet<i64>> = HashMap::new();
+        for (name, id) in &names {
+            let chars: Vec<char> = name.chars().collect();
+            for length in 1..=SEARCH_INDEX_GRAM_LENGTH {
+                for gram in chars.windows(length) {
+                    grams.entry(gram.iter().collect()).or_default().insert(*id);
+                }
+            }
+        }
+
+        names.sort();
+        names.dedup();
+
+        SearchIndex {
+            source,
+            sorted_names: names,
+            grams,
+        }
+    }
+
+    /// Whether the index was built from the given names
+    pub fn is_built_from(&self, names: &[(String, i64)]) -> bool {
+        self.source == SearchIndex::source_of(names)
+    }
+
+    /// Names (with the id of their Pokémon) starting with the prefix
+    fn prefix_matches(&self, prefix: &str) -> impl Iterator<Item = &(String, i64)> {
+        let start = self
+            .sorted_names
+            .partition_point(|(name, _)| name.as_str() < prefix);
+        self.sorted_names[start..]
+            .iter()
+            .take_while(move |(name, _)| name.starts_with(prefix))
+    }
+
+    /// Ids of the Pokémon matching a name term, none for the other terms
+    fn name_matches(&self, term: &SearchTerm) -> Option<BTreeSet<i64>> {
+        match term {
+            SearchTerm::ExactName(text) => Some(
+                self.prefix_matches(text)
+                    .filter(|(name, _)| name == text)
+                    .map(|(_, id)| *id)
+                    .collect(),
+            ),
+            SearchTerm::Name(text) if text.chars().count() <= SEARCH_INDEX_GRAM_LENGTH => {
+                Some(self.grams.get(text).cloned().unwrap_or_default())
+            }
+            SearchTerm::Name(text) => {
+                // Every Pokémon whose name contains the text has all its substrings, the
+                // candidates are then checked against their names
+                let chars: Vec<char> = text.chars().collect();
+                let candidates = chars
+                    .windows(SEARCH_INDEX_GRAM_LENGTH)
+                    .map(|gram| {
+                        self.grams
+                            .get(&gram.iter().collect::<String>())
+                            .cloned()
+                            .unwrap_or_default()
+                    })
+                    .reduce(|a, b| a.intersection(&b).copied().collect())
+                    .unwrap_or_default();
+
+                Some(
+                    self.sorted_names
+                        .iter()
+                        .filter(|(name, id)| {
+                            candidates.contains(id) && name.contains(text.as_str())
+                        })
+                        .map(|(_, id)| *id)
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+fn term_matches(term: &SearchTerm, starry_pokemon: &StarryPokemon) -> bool {
+    let pokemon = &starry_pokemon.pokemon;
+
+    match term {
+        SearchTerm::Name(name) => {
+            pokemon.name.to_lowercase().contains(name)
+                || starry_pokemon
+                    .localized_name()
+                    .is_some_and(|localized| normalize(localized).contains(name))
+        }
+        SearchTerm::ExactName(name) => {
+            pokemon.name.to_lowercase() == *name
+                || starry_pokemon
+                    .localized_name()
+                    .is_some_and(|localized| normalize(localized) == *name)
+        }
+        SearchTerm::IdRange(from, to) => (*from..=*to).contains(&pokemon.id),
+        SearchTerm::Type(type_name) => pokemon.types.iter().any(|t| t == type_name),
+        SearchTerm::Ability(ability) => pokemon
+            .abilities
+            .iter()
+            .any(|a| a.trim_end_matches(" (HIDDEN)").contains(ability.as_str())),
+        SearchTerm::WeakTo(type_name) => {
+            type_chart::effectiveness_against(type_name, &pokemon.types) > 1.0
+        }
+        SearchTerm::Game(game) => pokemon
+            .available_games
+            .as_ref()
+            .is_some_and(|games| games.contains(game)),
+        SearchTerm::Generation(generation) => starry_pokemon
+            .specie
+            .as_ref()
+            .and_then(|specie| specie.generation.as_ref())
+            .is_some_and(|g| g == generation),
+        SearchTerm::Stat(stat, comparison, value) => {
+            comparison.compare(stat.value(&pokemon.stats), *value)
+        }
+    }
+}
+
+/// Lowercases the value and uses the PokéApi separator between words
+fn normalize(value: &str) -> String {
+    value.trim().to_lowercase().replace(' ', "-")
+}
+
+/// Splits the input on whitespace, keeping quoted text (with its quotes) as a single token
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Vec<SearchTerm> {
+        SearchQuery::parse(input, &BTreeMap::new()).terms
+    }
+
+    fn pokemon(generation: &str, speed: i64) -> StarryPokemon {
+        serde_json::from_value(serde_json::json!({
+            "pokemon": {
+                "id": 149,
+                "name": "dragonite",
+                "weight": 2100,
+                "height": 22,
+                "types": ["dragon", "flying"],
+                "abilities": ["inner-focus"],
+                "stats": {
+                    "hp": 91,
+                    "attack": 134,
+                    "defense": 95,
+                    "sp_attack": 100,
+                    "sp_defense": 100,
+                    "speed": speed
+                },
+                "ev_yield": null,
+                "base_experience": null,
+                "available_games": null
+            },
+            "specie": {
+                "genus": null,
+                "capture_rate": 45,
+                "base_happiness": null,
+                "growth_rate": "slow",
+                "gender_rate": null,
+                "hatch_counter": null,
+                "generation": generation,
+                "egg_groups": null,
+                "names": null,
+                "genera": null
+            },
+            "sprite_path": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_generations() {
+        let generation_i = vec![SearchTerm::Generation(String::from("generation-i"))];
+        assert_eq!(parse("gen:1"), generation_i);
+        assert_eq!(parse("GEN:i"), generation_i);
+        assert_eq!(
+            parse("gen:9"),
+            [SearchTerm::Generation(String::from("generation-ix"))]
+        );
+        assert!(parse("gen:0").is_empty());
+        assert!(parse("gen:10").is_empty());
+    }
+
+    #[test]
+    fn parses_stats() {
+        assert_eq!(
+            parse("spe>=100"),
+            [SearchTerm::Stat(
+                RankedStat::Speed,
+                Comparison::GreaterOrEqual,
+                100
+            )]
+        );
+        assert_eq!(
+            parse("bst<300"),
+            [SearchTerm::Stat(RankedStat::Total, Comparison::Less, 300)]
+        );
+        assert_eq!(
+            parse("hp=1"),
+            [SearchTerm::Stat(RankedStat::Hp, Comparison::Equal, 1)]
+        );
+        // Without a comparison it's a name
+        assert_eq!(parse("atk"), [SearchTerm::Name(String::from("atk"))]);
+    }
+
+    #[test]
+    fn matches_type_generation_and_stats() {
+        let dragonite = pokemon("generation-i", 80);
+
+        assert!(SearchQuery::parse("type:dragon gen:1", &BTreeMap::new()).matches(&dragonite));
+        assert!(!SearchQuery::parse("gen:2", &BTreeMap::new()).matches(&dragonite));
+        assert!(SearchQuery::parse("spe<=80 bst>=600", &BTreeMap::new()).matches(&dragonite));
+        assert!(!SearchQuery::parse("spe>80", &BTreeMap::new()).matches(&dragonite));
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::{app::DexMode, data_dir::app_data_dir};
+
+const SESSION_FILE: &str = "session.json";
+
+/// What the user was looking at, saved periodically while the app runs and removed when it
+/// exits cleanly, so finding it on startup means the previous run crashed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub dex_mode: DexMode,
+    pub search: String,
+    pub selected_types: Vec<String>,
+    pub open_pokemon: Option<i64>,
+}
+
+fn session_file() -> PathBuf {
+    app_data_dir().join(SESSION_FILE)
+}
+
+/// Session left behind by a run that didn't exit cleanly, if any
+pub fn load_unclean_session() -> Option<Session> {
+    let session_data = std::fs::read(session_file()).ok()?;
+    serde_json::from_slice(&session_data).ok()
+}
+
+/// Saves the current session, replacing the previous one
+pub fn save_session(session: &Session) {
+    let session_file = session_file();
+    let tmp_session_file = session_file.with_extension("json.part");
+
+    let result = serde_json::to_vec(session)
+        .map_err(std::io::Error::from)
+        .and_then(|session_data| std::fs::write(&tmp_session_file, session_data))
+        .and_then(|()| std::fs::rename(&tmp_session_file, &session_file));
+
+    if let Err(e) = result {
+        eprintln!("Failed to save the session: {}", e);
+    }
+}
+
+/// Removes the saved session, called when the app exits cleanly
+pub fn clear_session() {
+    let _ = std::fs::remove_file(session_file());
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use image::{imageops, Rgba, RgbaImage};
+use std::path::PathBuf;
+
+use crate::{app::StarryPokemon, type_chart::type_color};
+
+const CARD_WIDTH: u32 = 480;
+const CARD_HEIGHT: u32 = 240;
+const CARD_BACKGROUND: Rgba<u8> = Rgba([38, 38, 42, 255]);
+const BAR_BACKGROUND: Rgba<u8> = Rgba([64, 64, 70, 255]);
+const TYPE_BAND_HEIGHT: u32 = 16;
+const SPRITE_SIZE: u32 = 192;
+const STAT_BAR_X: u32 = 232;
+const STAT_BAR_WIDTH: u32 = 224;
+const STAT_BAR_HEIGHT: u32 = 18;
+const STAT_BAR_SPACING: u32 = 12;
+/// Stat value that fills a whole bar
+const MAX_BAR_STAT: i64 = 200;
+
+/// Fills a rectangle of the image with the given color, clipped to the image bounds
+fn fill_rect(image: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: Rgba<u8>) {
+    for py in y..(y + height).min(image.height()) {
+        for px in x..(x + width).min(image.width()) {
+            image.put_pixel(px, py, color);
+        }
+    }
+}
+
+/// Color of a stat bar, from red for low stats to green for high ones
+fn stat_color(value: i64) -> Rgba<u8> {
+    match value {
+        ..=49 => Rgba([230, 74, 61, 255]),
+        50..=79 => Rgba([240, 160, 50, 255]),
+        80..=99 => Rgba([245, 210, 60, 255]),
+        100..=129 => Rgba([130, 200, 80, 255]),
+        _ => Rgba([60, 180, 120, 255]),
+    }
+}
+
+/// Draws the share card of a Pokémon: its types as colored bands, its sprite and its stats as bars
+fn render_card(starry_pokemon: &StarryPokemon) -> RgbaImage {
+    let mut card = RgbaImage::from_pixel(CARD_WIDTH, CARD_HEIGHT, CARD_BACKGROUND);
+    let pokemon = &starry_pokemon.pokemon;
+
+    let band_width = CARD_WIDTH / pokemon.types.len().max(1) as u32;
+    for (index, type_name) in pokemon.types.iter().enumerate() {
+        let [r, g, b] = type_color(type_name);
+        fill_rect(
+            &mut card,
+            index as u32 * band_width,
+            0,
+            band_width,
+            TYPE_BAND_HEIGHT,
+            Rgba([r, g, b, 255]),
+        );
+    }
+
+    if let Some(sprite) = starry_pokemon
+        .sprite_path
+        .as_ref()
+        .and_then(|path| image::open(path).ok())
+    {
+        // Sprites are pixel art, nearest keeps them sharp when scaled
+        let sprite = imageops::resize(
+            &sprite.to_rgba8(),
+            SPRITE_SIZE,
+            SPRITE_SIZE,
+            imageops::FilterType::Nearest,
+        );
+        imageops::overlay(&mut card, &sprite, 24, (TYPE_BAND_HEIGHT + 16) as i64);
+    }
+
+    let stats = [
+        pokemon.stats.hp,
+        pokemon.stats.attack,
+        pokemon.stats.defense,
+        pokemon.stats.sp_attack,
+        pokemon.stats.sp_defense,
+        pokemon.stats.speed,
+    ];
+    for (index, value) in stats.into_iter().enumerate() {
+        let y = TYPE_BAND_HEIGHT + 32 + index as u32 * (STAT_BAR_HEIGHT + STAT_BAR_SPACING);
+        let filled = (value.clamp(0, MAX_BAR_STAT) as u32 * STAT_BAR_WIDTH) / MAX_BAR_STAT as u32;
+
+        fill_rect(
+            &mut card,
+            STAT_BAR_X,
+            y,
+            STAT_BAR_WIDTH,
+            STAT_BAR_HEIGHT,
+            BAR_BACKGROUND,
+        );
+        fill_rect(
+            &mut card,
+            STAT_BAR_X,
+            y,
+            filled,
+            STAT_BAR_HEIGHT,
+            stat_color(value),
+        );
+    }
+
+    card
+}
+
+/// Saves the share card of a Pokémon as a PNG in the documents directory and returns its path
+pub async fn save_share_card(starry_pokemon: StarryPokemon) -> Result<PathBuf, String> {
+    let export_dir = dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| String::from("No documents directory"))?
+        .join("StarryDex");
+    let card_file = export_dir.join(format!("{}-card.png", starry_pokemon.pokemon.name));
+
+    tokio::task::spawn_blocking(move || {
+        std::fs::create_dir_all(&export_dir).map_err(|e| e.to_string())?;
+        render_card(&starry_pokemon)
+            .save(&card_file)
+            .map_err(|e| e.to_string())?;
+        Ok(card_file)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use futures::StreamExt;
+use std::path::PathBuf;
+
+use crate::{config::SpriteSet, data_dir::app_data_dir, fl, utils::download_image_to};
+
+/// Where PokéApi hosts its sprites, the sprite sets are folders of it with a sprite per Pokémon id
+const SPRITES_REPOSITORY: &str =
+    "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon";
+
+impl SpriteSet {
+    /// Every sprite set, in the order the settings show them
+    pub const ALL: [SpriteSet; 4] = [
+        SpriteSet::Default,
+        SpriteSet::Emerald,
+        SpriteSet::Home,
+        SpriteSet::OfficialArtwork,
+    ];
+
+    /// Localized name of the sprite set
+    pub fn label(&self) -> String {
+        match self {
+            SpriteSet::Default => fl!("sprite-set-default"),
+            SpriteSet::Emerald => fl!("sprite-set-emerald"),
+            SpriteSet::Home => fl!("sprite-set-home"),
+            SpriteSet::OfficialArtwork => fl!("sprite-set-official-artwork"),
+        }
+    }
+
+    /// Folder of the sprites repository with the sprites of the set, none for the default set
+    /// (downloaded with the Pokémon data)
+    fn repository_folder(&self) -> Option<&'static str> {
+        match self {
+            SpriteSet::Default => None,
+            SpriteSet::Emerald => Some("versions/generation-iii/emerald"),
+            SpriteSet::Home => Some("other/home"),
+            SpriteSet::OfficialArtwork => Some("other/official-artwork"),
+        }
+    }
+
+    /// Where the sprite of the Pokémon in this set is stored, none for the default set
+    pub fn sprite_path(&self, pokemon_name: &str) -> Option<PathBuf> {
+        let folder = self.repository_folder()?.replace('/', "-");
+        Some(
+            app_data_dir()
+                .join("resources")
+                .join("sprite-sets")
+                .join(folder)
+                .join(format!("{}.png", pokemon_name)),
+        )
+    }
+}
+
+/// Folder where users put their own sprites, which take precedence over any sprite set
+pub fn sprite_overrides_dir() -> PathBuf {
+    app_data_dir().join("sprite_overrides")
+}
+
+/// Sprites of the overrides folder (named `<name>_front.png`) for the given Pokémon (id and
+/// name), by Pokémon id
+pub fn sprite_overrides(pokemon: &[(i64, String)]) -> Vec<(i64, String)> {
+    let overrides_dir = sprite_overrides_dir();
+    let Ok(entries) = std::fs::read_dir(&overrides_dir) else {
+        return Vec::new();
+    };
+    let file_names: std::collections::HashSet<String> = entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+
+    pokemon
+        .iter()
+        .filter_map(|(id, name)| {
+            let file_name = format!("{}_front.png", name);
+            if !file_names.contains(&file_name) {
+                return None;
+            }
+            Some((*id, overrides_dir.join(file_name).to_str()?.to_string()))
+        })
+        .collect()
+}
+
+/// Sprites of the set that are already downloaded, by Pokémon id
+pub fn downloaded_sprites(set: SpriteSet, pokemon: &[(i64, String)]) -> Vec<(i64, String)> {
+    pokemon
+        .iter()
+        .filter_map(|(id, name)| {
+            let path = set.sprite_path(name).filter(|path| path.exists())?;
+            Some((*id, path.to_str()?.to_string()))
+        })
+        .collect()
+}
+
+/// Downloads the sprites of the set for the given Pokémon (id and name) in the background,
+/// sending the id and path of each sprite downloaded. Pokémon the set has no sprite for are
+/// skipped, they keep using the default sprite.
+pub fn download_sprite_set(
+    set: SpriteSet,
+    pokemon: Vec<(i64, String)>,
+) -> futures::channel::mpsc::UnboundedReceiver<(i64, String)> {
+    let (sender, receiver) = futures::channel::mpsc::unbounded();
+
+    tokio::spawn(async move {
+        let Some(folder) = set.repository_folder() else {
+            return;
+        };
+        let client = reqwest::Client::builder()
+            .pool_max_idle_per_host(10)
+            .build()
+            .unwrap_or_default();
+
+        futures::stream::iter(pokemon)
+            .map(|(id, name)| {
+                let client = client.clone();
+                async move {
+                    let path = set.sprite_path(&name)?;
+                    let url = format!("{}/{}/{}.png", SPRITES_REPOSITORY, folder, id);
+                    download_image_to(&client, url, &path).await.ok()?;
+                    Some((id, path.to_str()?.to_string()))
+                }
+            })
+            .buffer_unordered(20)
+            .for_each(|downloaded| {
+                if let Some(sprite) = downloaded {
+                    let _ = sender.unbounded_send(sprite);
+                }
+                futures::future::ready(())
+            })
+            .await;
+    });
+
+    receiver
+}
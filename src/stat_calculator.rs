@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::{
+    app::StarryPokemonStats,
+    natures::{Nature, NatureStat},
+};
+
+pub const MAX_LEVEL: u8 = 100;
+pub const MAX_IV: u8 = 31;
+/// Most EVs a single stat can have
+pub const MAX_STAT_EVS: u16 = 252;
+/// Most EVs a Pokémon can have between all its stats
+pub const MAX_TOTAL_EVS: u16 = 510;
+
+/// The stats in the order the calculator shows them, `None` is HP (natures never affect it)
+pub const CALCULATOR_STATS: [Option<NatureStat>; 6] = [
+    None,
+    Some(NatureStat::Attack),
+    Some(NatureStat::Defense),
+    Some(NatureStat::SpAttack),
+    Some(NatureStat::SpDefense),
+    Some(NatureStat::Speed),
+];
+
+/// Values set on the stat calculator, IVs and EVs follow the `CALCULATOR_STATS` order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatCalculator {
+    pub level: u8,
+    pub ivs: [u8; 6],
+    pub evs: [u16; 6],
+}
+
+impl Default for StatCalculator {
+    fn default() -> Self {
+        Self {
+            level: 50,
+            ivs: [MAX_IV; 6],
+            evs: [0; 6],
+        }
+    }
+}
+
+impl StatCalculator {
+    /// Sets the IVs of a stat, no higher than the most a stat can have
+    pub fn set_iv(&mut self, index: usize, iv: u8) {
+        self.ivs[index] = iv.min(MAX_IV);
+    }
+
+    /// Sets the EVs of a stat, lowering them if they would go over the total limit
+    pub fn set_evs(&mut self, index: usize, evs: u16) {
+        let others: u16 = self
+            .evs
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, evs)| *evs)
+            .sum();
+        self.evs[index] = evs
+            .min(MAX_STAT_EVS)
+            .min(MAX_TOTAL_EVS.saturating_sub(others));
+    }
+
+    /// Actual stats of a Pokémon with the given base stats, following the `CALCULATOR_STATS` order
+    pub fn stats(&self, base_stats: &StarryPokemonStats, nature: Option<&Nature>) -> [i64; 6] {
+        let base_values = [
+            base_stats.hp,
+            base_stats.attack,
+            base_stats.defense,
+            base_stats.sp_attack,
+            base_stats.sp_defense,
+            base_stats.speed,
+        ];
+
+        std::array::from_fn(|index| {
+            let (iv, evs) = (self.ivs[index] as i64, self.evs[index] as i64);
+            match CALCULATOR_STATS[index] {
+                None => hp_stat(base_values[index], iv, evs, self.level as i64),
+                Some(stat) => other_stat(
+                    base_values[index],
+                    iv,
+                    evs,
+                    self.level as i64,
+                    nature.map_or(100, |nature| nature.percentage(stat)),
+                ),
+            }
+        })
+    }
+}
+
+/// HP from the third generation onwards. Pokémon with a base HP of 1 (Shedinja) always have 1 HP.
+fn hp_stat(base: i64, iv: i64, evs: i64, level: i64) -> i64 {
+    if base == 1 {
+        return 1;
+    }
+    (2 * base + iv + evs / 4) * level / 100 + level + 10
+}
+
+/// Any stat other than HP from the third generation onwards, the nature percentage is applied last
+fn other_stat(base: i64, iv: i64, evs: i64, level: i64, nature_percentage: i64) -> i64 {
+    ((2 * base + iv + evs / 4) * level / 100 + 5) * nature_percentage / 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::natures::NATURES;
+
+    fn nature(name: &str) -> &'static Nature {
+        NATURES.iter().find(|nature| nature.name == name).unwrap()
+    }
+
+    fn garchomp() -> StarryPokemonStats {
+        StarryPokemonStats {
+            hp: 108,
+            attack: 130,
+            defense: 95,
+            sp_attack: 80,
+            sp_defense: 85,
+            speed: 102,
+        }
+    }
+
+    #[test]
+    fn hp_formula() {
+        assert_eq!(hp_stat(108, 31, 0, 100), 357);
+        assert_eq!(hp_stat(108, 31, 252, 50), 215);
+        assert_eq!(hp_stat(108, 0, 0, 1), 13);
+    }
+
+    #[test]
+    fn shedinja_always_has_one_hp() {
+        for level in [1, 50, 100] {
+            assert_eq!(hp_stat(1, 31, 252, level), 1);
+        }
+    }
+
+    #[test]
+    fn natures_at_level_100() {
+        let calculator = StatCalculator {
+            level: 100,
+            ..StatCalculator::default()
+        };
+
+        let neutral = calculator.stats(&garchomp(), Some(nature("hardy")));
+        assert_eq!(neutral, [357, 296, 226, 196, 206, 240]);
+        assert_eq!(calculator.stats(&garchomp(), None), neutral);
+
+        // Adamant raises the Attack and lowers the Sp. Attack
+        let adamant = calculator.stats(&garchomp(), Some(nature("adamant")));
+        assert_eq!(adamant, [357, 325, 226, 176, 206, 240]);
+    }
+
+    #[test]
+    fn natures_at_level_50() {
+        let mut calculator = StatCalculator::default();
+        calculator.set_evs(0, 252);
+        calculator.set_evs(5, 252);
+
+        let neutral = calculator.stats(&garchomp(), Some(nature("serious")));
+        assert_eq!(neutral[0], 215);
+        assert_eq!(neutral[5], 154);
+
+        // Jolly raises the Speed, Modest lowers the Attack
+        assert_eq!(calculator.stats(&garchomp(), Some(nature("jolly")))[5], 169);
+        assert_eq!(
+            calculator.stats(&garchomp(), Some(nature("modest")))[1],
+            135
+        );
+    }
+
+    #[test]
+    fn evs_are_clamped_per_stat() {
+        let mut calculator = StatCalculator::default();
+        calculator.set_evs(1, 300);
+        assert_eq!(calculator.evs[1], MAX_STAT_EVS);
+    }
+
+    #[test]
+    fn evs_are_clamped_to_the_total() {
+        let mut calculator = StatCalculator::default();
+        calculator.set_evs(0, 252);
+        calculator.set_evs(1, 252);
+        calculator.set_evs(2, 252);
+        assert_eq!(calculator.evs[2], MAX_TOTAL_EVS - 2 * MAX_STAT_EVS);
+        assert_eq!(calculator.evs.iter().sum::<u16>(), MAX_TOTAL_EVS);
+
+        // Lowering a stat frees its EVs for the others
+        calculator.set_evs(0, 0);
+        calculator.set_evs(2, 252);
+        assert_eq!(calculator.evs[2], MAX_STAT_EVS);
+    }
+
+    #[test]
+    fn ivs_are_clamped() {
+        let mut calculator = StatCalculator::default();
+        calculator.set_iv(3, 40);
+        assert_eq!(calculator.ivs[3], MAX_IV);
+        calculator.set_iv(3, 12);
+        assert_eq!(calculator.ivs[3], 12);
+    }
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use crate::fl;
+
+/// Parts of what StarryDex stores on disk, each of them can be cleared on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageComponent {
+    /// The Pokémon list, their details and the search index
+    PokemonData,
+    /// Moves, items, abilities and berries, with the item sprites
+    ExtraData,
+    /// Pokémon sprites, downloaded with the Pokémon data
+    Sprites,
+    /// Sprites of the sprite sets other than the default one
+    SpriteSets,
+}
+
+impl StorageComponent {
+    /// Every storage component, in the order the settings show them
+    pub const ALL: [StorageComponent; 4] = [
+        StorageComponent::PokemonData,
+        StorageComponent::ExtraData,
+        StorageComponent::Sprites,
+        StorageComponent::SpriteSets,
+    ];
+
+    /// Localized name of the storage component
+    pub fn label(&self) -> String {
+        match self {
+            StorageComponent::PokemonData => fl!("storage-pokemon-data"),
+            StorageComponent::ExtraData => fl!("storage-extra-data"),
+            StorageComponent::Sprites => fl!("storage-sprites"),
+            StorageComponent::SpriteSets => fl!("storage-sprite-sets"),
+        }
+    }
+}
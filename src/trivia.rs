@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{app::StarryPokemon, challenges::next_random, rankings::RankedStat};
+
+/// Which of the two Pokémon of a round was picked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaSide {
+    Left,
+    Right,
+}
+
+/// A question: which of the two Pokémon has the higher value of the stat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriviaRound {
+    pub left: i64,
+    pub right: i64,
+    pub stat: RankedStat,
+    /// The side the player picked, once the round is answered
+    pub answer: Option<TriviaSide>,
+}
+
+/// State of the stat guessing game
+#[derive(Debug, Clone)]
+pub struct TriviaGame {
+    random_state: u64,
+    pub round: Option<TriviaRound>,
+    pub streak: usize,
+    pub best_streak: usize,
+}
+
+impl Default for TriviaGame {
+    fn default() -> Self {
+        Self {
+            random_state: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or_default(),
+            round: None,
+            streak: 0,
+            best_streak: 0,
+        }
+    }
+}
+
+impl TriviaGame {
+    /// Starts a new round with two random Pokémon that don't share the value of the stat
+    pub fn next_round(&mut self, pokemon_list: &BTreeMap<i64, StarryPokemon>) {
+        let ids: Vec<i64> = pokemon_list.keys().copied().collect();
+        if ids.len() < 2 {
+            self.round = None;
+            return;
+        }
+
+        let stat = RankedStat::ALL
+            [(next_random(&mut self.random_state) % RankedStat::ALL.len() as u64) as usize];
+
+        // Ties have no right answer, give up after a few tries and take the tie
+        let mut pick = || ids[(next_random(&mut self.random_state) % ids.len() as u64) as usize];
+        let (mut left, mut right) = (pick(), pick());
+        for _ in 0..10 {
+            let values = (
+                stat.value(&pokemon_list[&left].pokemon.stats),
+                stat.value(&pokemon_list[&right].pokemon.stats),
+            );
+            if left != right && values.0 != values.1 {
+                break;
+            }
+            (left, right) = (pick(), pick());
+        }
+
+        self.round = Some(TriviaRound {
+            left,
+            right,
+            stat,
+            answer: None,
+        });
+    }
+
+    /// Answers the current round, updating the streak
+    pub fn answer(&mut self, side: TriviaSide, pokemon_list: &BTreeMap<i64, StarryPokemon>) {
+        let Some(round) = self.round.as_mut().filter(|round| round.answer.is_none()) else {
+            return;
+        };
+        round.answer = Some(side);
+
+        if round.is_correct(pokemon_list) == Some(true) {
+            self.streak += 1;
+            self.best_streak = self.best_streak.max(self.streak);
+        } else {
+            self.streak = 0;
+        }
+    }
+}
+
+impl TriviaRound {
+    /// Whether the answer was right, ties count as right, none if the round isn't answered
+    pub fn is_correct(&self, pokemon_list: &BTreeMap<i64, StarryPokemon>) -> Option<bool> {
+        let value = |id: i64| {
+            pokemon_list
+                .get(&id)
+                .map(|p| self.stat.value(&p.pokemon.stats))
+                .unwrap_or_default()
+        };
+        let (left, right) = (value(self.left), value(self.right));
+
+        self.answer.map(|side| match side {
+            TriviaSide::Left => left >= right,
+            TriviaSide::Right => right >= left,
+        })
+    }
+}
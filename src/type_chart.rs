@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+/// All the Pokémon types, named as PokéApi names them
+pub const ALL_TYPES: [&str; 18] = [
+    "normal", "fire", "water", "electric", "grass", "ice", "fighting", "poison", "ground",
+    "flying", "psychic", "bug", "rock", "ghost", "dragon", "dark", "steel", "fairy",
+];
+
+/// Attacking type matchups: (attacking type, super effective against, not very effective against, no effect against)
+const MATCHUPS: [(&str, &[&str], &[&str], &[&str]); 18] = [
+    ("normal", &[], &["rock", "steel"], &["ghost"]),
+    (
+        "fire",
+        &["grass", "ice", "bug", "steel"],
+        &["fire", "water", "rock", "dragon"],
+        &[],
+    ),
+    (
+        "water",
+        &["fire", "ground", "rock"],
+        &["water", "grass", "dragon"],
+        &[],
+    ),
+    (
+        "electric",
+        &["water", "flying"],
+        &["electric", "grass", "dragon"],
+        &["ground"],
+    ),
+    (
+        "grass",
+        &["water", "ground", "rock"],
+        &[
+            "fire", "grass", "poison", "flying", "bug", "dragon", "steel",
+        ],
+        &[],
+    ),
+    (
+        "ice",
+        &["grass", "ground", "flying", "dragon"],
+        &["fire", "water", "ice", "steel"],
+        &[],
+    ),
+    (
+        "fighting",
+        &["normal", "ice", "rock", "dark", "steel"],
+        &["poison", "flying", "psychic", "bug", "fairy"],
+        &["ghost"],
+    ),
+    (
+        "poison",
+        &["grass", "fairy"],
+        &["poison", "ground", "rock", "ghost"],
+        &["steel"],
+    ),
+    (
+        "ground",
+        &["fire", "electric", "poison", "rock", "steel"],
+        &["grass", "bug"],
+        &["flying"],
+    ),
+    (
+        "flying",
+        &["grass", "fighting", "bug"],
+        &["electric", "rock", "steel"],
+        &[],
+    ),
+    (
+        "psychic",
+        &["fighting", "poison"],
+        &["psychic", "steel"],
+        &["dark"],
+    ),
+    (
+        "bug",
+        &["grass", "psychic", "dark"],
+        &[
+            "fire", "fighting", "poison", "flying", "ghost", "steel", "fairy",
+        ],
+        &[],
+    ),
+    (
+        "rock",
+        &["fire", "ice", "flying", "bug"],
+        &["fighting", "ground", "steel"],
+        &[],
+    ),
+    ("ghost", &["psychic", "ghost"], &["dark"], &["normal"]),
+    ("dragon", &["dragon"], &["steel"], &["fairy"]),
+    (
+        "dark",
+        &["psychic", "ghost"],
+        &["fighting", "dark", "fairy"],
+        &[],
+    ),
+    (
+        "steel",
+        &["ice", "rock", "fairy"],
+        &["fire", "water", "electric", "steel"],
+        &[],
+    ),
+    (
+        "fairy",
+        &["fighting", "dragon", "dark"],
+        &["fire", "poison", "steel"],
+        &[],
+    ),
+];
+
+/// Damage multiplier of an attacking type against a single defending type
+pub fn effectiveness(attacking: &str, defending: &str) -> f32 {
+    let attacking = attacking.to_lowercase();
+    let defending = defending.to_lowercase();
+
+    match MATCHUPS.iter().find(|(name, ..)| *name == attacking) {
+        Some((_, super_effective, not_very_effective, no_effect)) => {
+            if no_effect.contains(&defending.as_str()) {
+                0.0
+            } else if super_effective.contains(&defending.as_str()) {
+                2.0
+            } else if not_very_effective.contains(&defending.as_str()) {
+                0.5
+            } else {
+                1.0
+            }
+        }
+        None => 1.0,
+    }
+}
+
+/// Damage multiplier of an attacking type against a Pokémon with the given types
+pub fn effectiveness_against(attacking: &str, defending: &[String]) -> f32 {
+    defending
+        .iter()
+        .map(|defending_type| effectiveness(attacking, defending_type))
+        .product()
+}
+
+/// Usual color of each type as RGB, the same colors the games use on their type labels
+const TYPE_COLORS: [(&str, [u8; 3]); 18] = [
+    ("normal", [168, 167, 122]),
+    ("fire", [238, 129, 48]),
+    ("water", [99, 144, 240]),
+    ("electric", [247, 208, 44]),
+    ("grass", [122, 199, 76]),
+    ("ice", [150, 217, 214]),
+    ("fighting", [194, 46, 40]),
+    ("poison", [163, 62, 161]),
+    ("ground", [226, 191, 101]),
+    ("flying", [169, 143, 243]),
+    ("psychic", [249, 85, 135]),
+    ("bug", [166, 185, 26]),
+    ("rock", [182, 161, 54]),
+    ("ghost", [115, 87, 151]),
+    ("dragon", [111, 53, 252]),
+    ("dark", [112, 87, 70]),
+    ("steel", [183, 183, 206]),
+    ("fairy", [214, 133, 173]),
+];
+
+/// Color of the given type as RGB, gray for unknown types
+pub fn type_color(type_name: &str) -> [u8; 3] {
+    TYPE_COLORS
+        .iter()
+        .find(|(name, _)| *name == type_name)
+        .map(|(_, color)| *color)
+        .unwrap_or([128, 128, 128])
+}
@@ -1,10 +1,13 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 use std::fs;
+use std::path::Path;
 
-use crate::app::StarryPokemonStats;
-
-const APP_ID: &str = "dev.mariinkys.StarryDex";
+use crate::{
+    app::{EvolutionRequirement, StarryEvolutionStage, StarryPokemonStats},
+    config::MeasurementUnits,
+    data_dir::app_data_dir,
+};
 
 pub fn capitalize_string(input: &str) -> String {
     let words: Vec<&str> = input.split('-').collect();
@@ -24,11 +27,47 @@ pub fn capitalize_string(input: &str) -> String {
     capitalized_words.join(" ")
 }
 
-pub fn scale_numbers(num: i64) -> f64 {
-    (num as f64) / 10.0
+/// Height in the given units, from the decimeters PokéApi gives
+pub fn format_height(decimeters: i64, units: MeasurementUnits) -> String {
+    let metric = format!("{} m", decimeters as f64 / 10.0);
+
+    // 1 dm = 3.937 in, rounded to whole inches as heights are usually written
+    let total_inches = (decimeters as f64 * 3.937_007_874).round() as i64;
+    let imperial = format!("{}' {}\"", total_inches / 12, total_inches % 12);
+
+    match units {
+        MeasurementUnits::Metric => metric,
+        MeasurementUnits::Imperial => imperial,
+        MeasurementUnits::Both => format!("{metric} ({imperial})"),
+    }
+}
+
+/// Weight in the given units, from the hectograms PokéApi gives
+pub fn format_weight(hectograms: i64, units: MeasurementUnits) -> String {
+    let metric = format!("{} kg", hectograms as f64 / 10.0);
+    let imperial = format!("{:.1} lbs", hectograms as f64 * 0.220_462_26);
+
+    match units {
+        MeasurementUnits::Metric => metric,
+        MeasurementUnits::Imperial => imperial,
+        MeasurementUnits::Both => format!("{metric} ({imperial})"),
+    }
 }
 
 pub fn parse_pokemon_stats(stats: &[rustemon::model::pokemon::PokemonStat]) -> StarryPokemonStats {
+    parse_pokemon_stat_values(stats, |stat| stat.base_stat)
+}
+
+pub fn parse_pokemon_ev_yield(
+    stats: &[rustemon::model::pokemon::PokemonStat],
+) -> StarryPokemonStats {
+    parse_pokemon_stat_values(stats, |stat| stat.effort)
+}
+
+fn parse_pokemon_stat_values(
+    stats: &[rustemon::model::pokemon::PokemonStat],
+    value: impl Fn(&rustemon::model::pokemon::PokemonStat) -> i64,
+) -> StarryPokemonStats {
     let mut starry_stats = StarryPokemonStats {
         hp: 0,
         attack: 0,
@@ -40,12 +79,12 @@ pub fn parse_pokemon_stats(stats: &[rustemon::model::pokemon::PokemonStat]) -> S
 
     for stat in stats {
         match stat.stat.name.as_str() {
-            "hp" => starry_stats.hp = stat.base_stat,
-            "attack" => starry_stats.attack = stat.base_stat,
-            "defense" => starry_stats.defense = stat.base_stat,
-            "special-attack" => starry_stats.sp_attack = stat.base_stat,
-            "special-defense" => starry_stats.sp_defense = stat.base_stat,
-            "speed" => starry_stats.speed = stat.base_stat,
+            "hp" => starry_stats.hp = value(stat),
+            "attack" => starry_stats.attack = value(stat),
+            "defense" => starry_stats.defense = value(stat),
+            "special-attack" => starry_stats.sp_attack = value(stat),
+            "special-defense" => starry_stats.sp_defense = value(stat),
+            "speed" => starry_stats.speed = value(stat),
             _ => {} // Ignore any unknown stats
         }
     }
@@ -58,11 +97,7 @@ pub async fn download_image(
     image_url: String,
     pokemon_name: String,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let resources_path = dirs::data_dir()
-        .unwrap()
-        .join(APP_ID)
-        .join("resources")
-        .join("sprites");
+    let resources_path = app_data_dir().join("resources").join("sprites");
 
     if !resources_path.exists() {
         fs::create_dir_all(&resources_path).expect("Failed to create the resources path");
@@ -71,19 +106,30 @@ pub async fn download_image(
     let image_filename = format!("{}_front.png", pokemon_name);
     let image_path = resources_path.join(&pokemon_name).join(&image_filename);
 
+    download_image_to(client, image_url, &image_path).await
+}
+
+/// Downloads the image to the given path, unless it has already been downloaded
+pub async fn download_image_to(
+    client: &reqwest::Client,
+    image_url: String,
+    image_path: &std::path::Path,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Check if file already exists
-    if tokio::fs::metadata(&image_path).await.is_ok() {
+    if tokio::fs::metadata(image_path).await.is_ok() {
         return Ok(());
     }
 
     let response = client.get(&image_url).send().await?;
     if response.status().is_success() {
         let bytes = response.bytes().await?;
-        let path = std::path::PathBuf::from(&image_path);
-        if let Some(parent) = path.parent() {
+        if let Some(parent) = image_path.parent() {
             tokio::fs::create_dir_all(parent).await?;
         }
-        tokio::fs::write(&image_path, &bytes).await?;
+        // Write to a temporary file first so an interrupted download never looks complete
+        let part_path = image_path.with_extension("png.part");
+        tokio::fs::write(&part_path, &bytes).await?;
+        tokio::fs::rename(&part_path, image_path).await?;
         Ok(())
     } else {
         Err(Box::new(std::io::Error::new(
@@ -93,14 +139,198 @@ pub async fn download_image(
     }
 }
 
-pub fn remove_dir_contents<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
+/// Id at the end of a PokéApi resource url ("https://pokeapi.co/api/v2/pokemon-species/133/" is 133)
+pub fn resource_id(url: &str) -> Option<i64> {
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+/// Flattens an evolution chain into its stages, parents before their evolutions
+pub fn parse_evolution_chain(
+    chain: &rustemon::model::evolution::ChainLink,
+) -> Vec<StarryEvolutionStage> {
+    fn walk(
+        link: &rustemon::model::evolution::ChainLink,
+        evolves_from: Option<&str>,
+        depth: usize,
+        stages: &mut Vec<StarryEvolutionStage>,
+    ) {
+        // The items come from the same ways to evolve as the conditions shown, so the stages
+        // listed under an item mention it
+        let mut needs_to_evolve: Vec<Vec<EvolutionRequirement>> = Vec::new();
+        let mut item_ids: Vec<i64> = Vec::new();
+        for detail in &link.evolution_details {
+            // PokéApi repeats a way to evolve once for each game that has it
+            let requirements = extract_evolution_requirements(detail);
+            if requirements.is_empty() || needs_to_evolve.contains(&requirements) {
+                continue;
+            }
+            needs_to_evolve.push(requirements);
+
+            for item_id in [&detail.item, &detail.held_item]
+                .into_iter()
+                .flatten()
+                .filter_map(|item| resource_id(&item.url))
+            {
+                if !item_ids.contains(&item_id) {
+                    item_ids.push(item_id);
+                }
+            }
+        }
+
+        stages.push(StarryEvolutionStage {
+            species_id: resource_id(&link.species.url).unwrap_or_default(),
+            species: link.species.name.clone(),
+            evolves_from: evolves_from.map(String::from),
+            depth,
+            needs_to_evolve,
+            item_ids,
+        });
+
+        for evolution in &link.evolves_to {
+            walk(evolution, Some(&link.species.name), depth + 1, stages);
+        }
+    }
+
+    let mut stages = Vec::new();
+    walk(chain, None, 0, &mut stages);
+    stages
+}
+
+/// Every condition needed to evolve in one of the ways a stage can evolve
+pub fn extract_evolution_requirements(
+    detail: &rustemon::model::evolution::EvolutionDetail,
+) -> Vec<EvolutionRequirement> {
+    let mut requirements = Vec::new();
+
+    match detail.trigger.name.as_str() {
+        "level-up" => requirements.push(match detail.min_level {
+            Some(level) => EvolutionRequirement::Level(level),
+            None => EvolutionRequirement::LevelUp,
+        }),
+        "use-item" => {}
+        trigger => requirements.push(EvolutionRequirement::Trigger(trigger.to_string())),
+    }
+    if let Some(item) = &detail.item {
+        requirements.push(EvolutionRequirement::UseItem(item.name.clone()));
+    }
+    if let Some(held_item) = &detail.held_item {
+        requirements.push(EvolutionRequirement::HoldItem(held_item.name.clone()));
+    }
+    if let Some(known_move) = &detail.known_move {
+        requirements.push(EvolutionRequirement::KnowMove(known_move.name.clone()));
+    }
+    if let Some(known_move_type) = &detail.known_move_type {
+        requirements.push(EvolutionRequirement::KnowMoveType(
+            known_move_type.name.clone(),
+        ));
+    }
+    if detail.min_happiness.is_some() {
+        requirements.push(EvolutionRequirement::Friendship);
+    }
+    if detail.min_affection.is_some() {
+        requirements.push(EvolutionRequirement::Affection);
+    }
+    if detail.min_beauty.is_some() {
+        requirements.push(EvolutionRequirement::Beauty);
+    }
+    if let Some(location) = &detail.location {
+        requirements.push(EvolutionRequirement::Location(location.name.clone()));
+    }
+    if !detail.time_of_day.is_empty() {
+        requirements.push(EvolutionRequirement::TimeOfDay(detail.time_of_day.clone()));
+    }
+    match detail.gender {
+        Some(1) => requirements.push(EvolutionRequirement::Female),
+        Some(2) => requirements.push(EvolutionRequirement::Male),
+        _ => {}
+    }
+    if let Some(party_species) = &detail.party_species {
+        requirements.push(EvolutionRequirement::PartySpecies(
+            party_species.name.clone(),
+        ));
+    }
+    if let Some(party_type) = &detail.party_type {
+        requirements.push(EvolutionRequirement::PartyType(party_type.name.clone()));
+    }
+    if let Some(trade_species) = &detail.trade_species {
+        requirements.push(EvolutionRequirement::TradeFor(trade_species.name.clone()));
+    }
+    match detail.relative_physical_stats {
+        Some(1) => requirements.push(EvolutionRequirement::AttackHigher),
+        Some(0) => requirements.push(EvolutionRequirement::AttackEqualsDefense),
+        Some(-1) => requirements.push(EvolutionRequirement::DefenseHigher),
+        _ => {}
+    }
+    if detail.needs_overworld_rain {
+        requirements.push(EvolutionRequirement::Rain);
+    }
+    if detail.turn_upside_down {
+        requirements.push(EvolutionRequirement::UpsideDown);
+    }
+
+    requirements
+}
+
+/// Size in bytes of a file, or of every file inside a directory, 0 if it doesn't exist
+pub fn disk_usage<P: AsRef<Path>>(path: P) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(&path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| disk_usage(entry.path()))
+                .sum()
+        })
+        .unwrap_or_default()
+}
+
+/// Removes a file, or a directory with everything inside it
+pub fn remove_path<P: AsRef<Path>>(path: P) -> std::io::Result<()> {
+    if path.as_ref().is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Formats a size in bytes to be shown, like "12.3 MB"
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} {}", bytes, UNITS[0]),
+        _ => format!("{:.1} {}", size, UNITS[unit]),
+    }
+}
+
+/// Recursively removes the `.part` files left behind by interrupted downloads
+pub fn remove_partial_downloads<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<()> {
+    if !path.as_ref().exists() {
+        return Ok(());
+    }
+
     for entry in fs::read_dir(path)? {
-        let entry = entry?;
-        let path = entry.path();
+        let path = entry?.path();
 
         if path.is_dir() {
-            fs::remove_dir_all(path)?;
-        } else {
+            remove_partial_downloads(&path)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "part")
+        {
             fs::remove_file(path)?;
         }
     }
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    api::Api,
+    app::{StarryPokemon, StarryPokemonDetails},
+    fl,
+    utils::capitalize_string,
+};
+
+/// Data of a watched Pokémon at a given moment, compared after the data is refreshed
+#[derive(Debug, Clone)]
+pub struct WatchedSnapshot {
+    pub pokemon: StarryPokemon,
+    pub details: StarryPokemonDetails,
+    /// Names of the other forms of the Pokémon (megas, regional forms...)
+    pub forms: BTreeSet<String>,
+}
+
+/// What changed on a watched Pokémon after the data was refreshed
+#[derive(Debug, Clone)]
+pub struct WatchedChanges {
+    pub name: String,
+    pub changes: Vec<String>,
+}
+
+/// Takes a snapshot of the watched Pokémon that are in the given list
+pub async fn snapshot(
+    api: Api,
+    watchlist: BTreeSet<i64>,
+    pokemon_list: BTreeMap<i64, StarryPokemon>,
+) -> Vec<WatchedSnapshot> {
+    let mut snapshots = Vec::new();
+
+    for id in watchlist {
+        let Some(starry_pokemon) = pokemon_list.get(&id) else {
+            continue;
+        };
+
+        let form_prefix = format!("{}-", starry_pokemon.pokemon.name);
+        let forms = pokemon_list
+            .values()
+            .filter(|p| p.pokemon.name.starts_with(&form_prefix))
+            .map(|p| p.pokemon.name.clone())
+            .collect();
+
+        snapshots.push(WatchedSnapshot {
+            pokemon: starry_pokemon.clone(),
+            details: api.get_pokemon_details(id).await,
+            forms,
+        });
+    }
+
+    snapshots
+}
+
+/// Compares the snapshots taken before and after refreshing the data, only Pokémon with changes are returned
+pub fn compare(old: &[WatchedSnapshot], new: &[WatchedSnapshot]) -> Vec<WatchedChanges> {
+    old.iter()
+        .filter_map(|old_snapshot| {
+            let new_snapshot = new
+                .iter()
+                .find(|s| s.pokemon.pokemon.id == old_snapshot.pokemon.pokemon.id)?;
+            let changes = snapshot_changes(old_snapshot, new_snapshot);
+
+            (!changes.is_empty()).then(|| WatchedChanges {
+                name: new_snapshot.pokemon.display_name(),
+                changes,
+            })
+        })
+        .collect()
+}
+
+fn snapshot_changes(old: &WatchedSnapshot, new: &WatchedSnapshot) -> Vec<String> {
+    let mut changes = Vec::new();
+    let (old_data, new_data) = (&old.pokemon.pokemon, &new.pokemon.pokemon);
+
+    let stats = [
+        (fl!("hp"), old_data.stats.hp, new_data.stats.hp),
+        (fl!("attack"), old_data.stats.attack, new_data.stats.attack),
+        (
+            fl!("defense"),
+            old_data.stats.defense,
+            new_data.stats.defense,
+        ),
+        (
+            fl!("sp-a"),
+            old_data.stats.sp_attack,
+            new_data.stats.sp_attack,
+        ),
+        (
+            fl!("sp-d"),
+            old_data.stats.sp_defense,
+            new_data.stats.sp_defense,
+        ),
+        (fl!("spd"), old_data.stats.speed, new_data.stats.speed),
+    ];
+    for (stat, old_value, new_value) in stats {
+        if old_value != new_value {
+            changes.push(fl!(
+                "watch-stat-changed",
+                stat = stat,
+                old = old_value,
+                new = new_value
+            ));
+        }
+    }
+
+    if old_data.types != new_data.types {
+        changes.push(fl!(
+            "watch-types-changed",
+            types = join_capitalized(&new_data.types)
+        ));
+    }
+
+    if old_data.abilities != new_data.abilities {
+        changes.push(fl!(
+            "watch-abilities-changed",
+            abilities = join_capitalized(&new_data.abilities)
+        ));
+    }
+
+    let new_encounters = new
+        .details
+        .encounter_info
+        .iter()
+        .filter(|encounter| {
+            !old.details
+                .encounter_info
+                .iter()
+                .any(|old_encounter| old_encounter.city == encounter.city)
+        })
+        .count();
+    if new_encounters > 0 {
+        changes.push(fl!("watch-new-encounters", count = new_encounters));
+    }
+
+    for form in new.forms.difference(&old.forms) {
+        changes.push(fl!("watch-new-form", form = capitalize_string(form)));
+    }
+
+    changes
+}
+
+fn join_capitalized(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| capitalize_string(value))
+        .collect::<Vec<String>>()
+        .join(", ")
+}